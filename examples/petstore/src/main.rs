@@ -24,8 +24,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let client = PetstoreApi::new("https://petstore3.swagger.io/api/v3");
 
     // Add a new pet to the store
-    // The API expects a JSON object conforming to the Pet schema
-    let new_pet = json!({
+    // The request body is typed as the generated Pet struct
+    let new_pet: Pet = serde_json::from_value(json!({
         "id": 12345,
         "name": "Buddy",
         "category": {
@@ -40,7 +40,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
         ],
         "status": "available"
-    });
+    }))?;
 
     // POST request to add a pet
     // Returns a strongly-typed Pet struct on success
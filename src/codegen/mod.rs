@@ -3,8 +3,12 @@
 //! This module provides utilities for generating specific code patterns
 //! like parameter handling, type conversions, and URL building.
 
+pub mod multipart;
+pub mod operations;
 pub mod params;
 pub mod types;
 
+pub use multipart::*;
+pub use operations::*;
 pub use params::*;
 pub use types::*;
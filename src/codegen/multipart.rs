@@ -0,0 +1,179 @@
+use heck::{ToPascalCase, ToSnakeCase};
+use openapiv3::{
+    MediaType, ObjectType, Operation, ReferenceOr, Schema, SchemaKind, StringFormat, Type,
+    VariantOrUnknownOrEmpty,
+};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+
+use crate::codegen::{reference_or_schema_to_rust_type, schema_to_rust_type};
+use crate::utils::create_rust_safe_ident;
+
+/// How a multipart form field is encoded as a part
+enum PartKind {
+    /// `format: binary` strings, sent as raw bytes
+    Binary,
+    /// Primitive values, sent as plain text
+    Text,
+    /// Objects, arrays and references, sent as JSON
+    Json,
+}
+
+/// Get the `multipart/form-data` media type of an operation's request body
+///
+/// Only inline object schemas with properties are supported, as every property
+/// becomes a part of the form.
+pub fn multipart_body(operation: &Operation) -> Option<(&MediaType, &ObjectType)> {
+    let ReferenceOr::Item(request_body) = operation.request_body.as_ref()? else {
+        return None;
+    };
+    if request_body.content.contains_key("application/json") {
+        return None;
+    }
+
+    let media_type = request_body.content.get("multipart/form-data")?;
+    match media_type.schema.as_ref()? {
+        ReferenceOr::Item(Schema {
+            schema_kind: SchemaKind::Type(Type::Object(obj)),
+            ..
+        }) if !obj.properties.is_empty() => Some((media_type, obj)),
+        _ => None,
+    }
+}
+
+/// Name of the struct generated for a multipart request body
+pub fn multipart_body_struct_name(operation_id: &str) -> String {
+    format!("{}MultipartBody", operation_id.to_pascal_case())
+}
+
+/// Generate the struct holding the fields of a multipart request body
+pub fn generate_multipart_body_struct(
+    operation: &Operation,
+    operation_id: &str,
+) -> Result<TokenStream2, String> {
+    let Some((_, obj)) = multipart_body(operation) else {
+        return Ok(quote! {});
+    };
+
+    let struct_name = format_ident!("{}", multipart_body_struct_name(operation_id));
+    let doc = format!(
+        "Multipart form fields of the `{}` request body",
+        operation_id
+    );
+
+    let mut fields = Vec::new();
+    for (field_name, field_schema_ref) in &obj.properties {
+        let field_ident = create_rust_safe_ident(&field_name.to_snake_case());
+        let field_type = match part_kind(field_schema_ref) {
+            PartKind::Binary => quote! { Vec<u8> },
+            _ => match field_schema_ref {
+                ReferenceOr::Item(schema) => schema_to_rust_type(schema)?,
+                ReferenceOr::Reference { reference } => {
+                    reference_or_schema_to_rust_type(&ReferenceOr::Reference {
+                        reference: reference.clone(),
+                    })?
+                }
+            },
+        };
+        let field_type = if obj.required.contains(field_name) {
+            field_type
+        } else {
+            quote! { Option<#field_type> }
+        };
+
+        fields.push(quote! { pub #field_ident: #field_type, });
+    }
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone)]
+        pub struct #struct_name {
+            #(#fields)*
+        }
+    })
+}
+
+/// Generate the code attaching a multipart request body to `request`
+///
+/// Each part gets the content type declared in the `encoding` object, falling
+/// back to `application/octet-stream` for binary and `application/json` for
+/// JSON encoded parts.
+pub fn generate_multipart_form(
+    operation: &Operation,
+    is_blocking: bool,
+) -> Result<TokenStream2, String> {
+    let Some((media_type, obj)) = multipart_body(operation) else {
+        return Ok(quote! {});
+    };
+
+    let multipart = if is_blocking {
+        quote! { reqwest::blocking::multipart }
+    } else {
+        quote! { reqwest::multipart }
+    };
+
+    let mut parts = Vec::new();
+    for (field_name, field_schema_ref) in &obj.properties {
+        let field_ident = create_rust_safe_ident(&field_name.to_snake_case());
+        let kind = part_kind(field_schema_ref);
+
+        let encoded_content_type = media_type
+            .encoding
+            .get(field_name)
+            .and_then(|encoding| encoding.content_type.as_deref());
+        let content_type = encoded_content_type.or(match kind {
+            PartKind::Binary => Some("application/octet-stream"),
+            PartKind::Json => Some("application/json"),
+            PartKind::Text => None,
+        });
+
+        let part = match kind {
+            PartKind::Binary => quote! {
+                #multipart::Part::bytes(value).file_name(#field_name)
+            },
+            PartKind::Text => quote! { #multipart::Part::text(value.to_string()) },
+            PartKind::Json => quote! { #multipart::Part::text(serde_json::to_string(&value)?) },
+        };
+        let part = match content_type {
+            Some(content_type) => quote! { #part.mime_str(#content_type)? },
+            None => part,
+        };
+
+        parts.push(if obj.required.contains(field_name) {
+            quote! {
+                let value = body.#field_ident;
+                form = form.part(#field_name, #part);
+            }
+        } else {
+            quote! {
+                if let Some(value) = body.#field_ident {
+                    form = form.part(#field_name, #part);
+                }
+            }
+        });
+    }
+
+    Ok(quote! {
+        let mut form = #multipart::Form::new();
+        #(#parts)*
+        request = request.multipart(form);
+    })
+}
+
+/// Determine how a multipart field is encoded from its schema
+fn part_kind(schema_ref: &ReferenceOr<Box<Schema>>) -> PartKind {
+    let ReferenceOr::Item(schema) = schema_ref else {
+        return PartKind::Json;
+    };
+
+    match &schema.schema_kind {
+        SchemaKind::Type(Type::String(string_schema)) => match &string_schema.format {
+            VariantOrUnknownOrEmpty::Item(StringFormat::Binary) => PartKind::Binary,
+            _ => PartKind::Text,
+        },
+        SchemaKind::Type(Type::Integer(_))
+        | SchemaKind::Type(Type::Number(_))
+        | SchemaKind::Type(Type::Boolean(_)) => PartKind::Text,
+        _ => PartKind::Json,
+    }
+}
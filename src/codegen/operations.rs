@@ -0,0 +1,229 @@
+use heck::ToPascalCase;
+use openapiv3::{
+    Components, OpenAPI, Operation, PathItem, ReferenceOr, Schema, SchemaKind, StringFormat,
+    StringType, Type, VariantOrUnknownOrEmpty,
+};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+
+use crate::codegen::reference_or_schema_to_rust_type;
+
+/// Collect all operations in the spec with their path and HTTP method
+///
+/// Operations are sorted by path and method so the generated code doesn't
+/// depend on the order of the spec's maps.
+pub fn spec_operations(spec: &OpenAPI) -> Result<Vec<(&str, &'static str, &Operation)>, String> {
+    let mut operations = Vec::new();
+
+    for (path, path_item_ref) in spec.paths.iter() {
+        let path_item = match path_item_ref {
+            ReferenceOr::Reference { reference } => {
+                return Err(format!("Path item references not supported: {}", reference));
+            }
+            ReferenceOr::Item(item) => item,
+        };
+
+        for (method, operation) in path_item_operations(path_item) {
+            operations.push((path.as_str(), method, operation));
+        }
+    }
+
+    operations.sort_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)));
+
+    Ok(operations)
+}
+
+/// Collect the component schemas sorted by name
+pub fn sorted_schemas(components: &Components) -> Vec<(&String, &ReferenceOr<Schema>)> {
+    let mut schemas: Vec<_> = components.schemas.iter().collect();
+    schemas.sort_by(|a, b| a.0.cmp(b.0));
+    schemas
+}
+
+/// Iterate over the operations defined on a path item with their HTTP method
+pub fn path_item_operations(
+    path_item: &PathItem,
+) -> impl Iterator<Item = (&'static str, &Operation)> {
+    [
+        ("get", &path_item.get),
+        ("post", &path_item.post),
+        ("put", &path_item.put),
+        ("delete", &path_item.delete),
+        ("patch", &path_item.patch),
+        ("head", &path_item.head),
+        ("options", &path_item.options),
+        ("trace", &path_item.trace),
+    ]
+    .into_iter()
+    .filter_map(|(method, operation)| operation.as_ref().map(|op| (method, op)))
+}
+
+/// Get the operation ID, or derive one from the method and path
+pub fn operation_id_or_default(operation: &Operation, method: &str, path: &str) -> String {
+    if let Some(operation_id) = &operation.operation_id {
+        return operation_id.clone();
+    }
+
+    // Convert path to camelCase operation name
+    let path_parts: Vec<&str> = path
+        .split('/')
+        .filter(|s| !s.is_empty() && !s.starts_with('{'))
+        .collect();
+
+    if path_parts.is_empty() {
+        method.to_string()
+    } else {
+        format!("{}{}", method, path_parts.join("_").to_pascal_case())
+    }
+}
+
+/// Client-side rate limit hint declared on an operation
+pub struct RateLimit {
+    pub limit: u32,
+    pub window_secs: u64,
+}
+
+/// Read the `x-ratelimit-limit`/`x-ratelimit-window` hints of an operation
+///
+/// The window is in seconds and defaults to one second when only a limit is
+/// declared. The `x-rate-limit-*` spelling is accepted as well.
+pub fn operation_rate_limit(operation: &Operation) -> Result<Option<RateLimit>, String> {
+    let extension = |name: &str| {
+        operation
+            .extensions
+            .get(&format!("x-ratelimit-{}", name))
+            .or_else(|| operation.extensions.get(&format!("x-rate-limit-{}", name)))
+    };
+
+    let Some(limit) = extension("limit") else {
+        return Ok(None);
+    };
+    let limit = limit
+        .as_u64()
+        .and_then(|limit| u32::try_from(limit).ok())
+        .filter(|limit| *limit > 0)
+        .ok_or_else(|| format!("Invalid x-ratelimit-limit: {}", limit))?;
+
+    let window_secs = match extension("window") {
+        Some(window) => window
+            .as_u64()
+            .filter(|window| *window > 0)
+            .ok_or_else(|| format!("Invalid x-ratelimit-window: {}", window))?,
+        None => 1,
+    };
+
+    Ok(Some(RateLimit { limit, window_secs }))
+}
+
+/// Read the `x-success-codes` of an operation
+///
+/// The listed status codes are the only ones whose response is parsed as the
+/// method's return value, replacing the default of any 2xx status.
+pub fn operation_success_codes(operation: &Operation) -> Result<Option<Vec<u16>>, String> {
+    let Some(extension) = operation.extensions.get("x-success-codes") else {
+        return Ok(None);
+    };
+
+    extension
+        .as_array()
+        .filter(|codes| !codes.is_empty())
+        .and_then(|codes| {
+            codes
+                .iter()
+                .map(|code| {
+                    code.as_u64()
+                        .and_then(|code| u16::try_from(code).ok())
+                        .filter(|code| (100..600).contains(code))
+                })
+                .collect::<Option<Vec<_>>>()
+        })
+        .map(Some)
+        .ok_or_else(|| format!("Invalid x-success-codes: {}", extension))
+}
+
+/// Read the `x-json-rpc-method` of an operation tunnelling a JSON-RPC method
+pub fn operation_json_rpc_method(operation: &Operation) -> Result<Option<String>, String> {
+    match operation.extensions.get("x-json-rpc-method") {
+        Some(serde_json::Value::String(method)) if !method.is_empty() => Ok(Some(method.clone())),
+        Some(method) => Err(format!("Invalid x-json-rpc-method: {}", method)),
+        None => Ok(None),
+    }
+}
+
+/// Get the `application/json` schema of an operation's request body
+pub fn request_body_schema(operation: &Operation) -> Option<&ReferenceOr<Schema>> {
+    match operation.request_body.as_ref()? {
+        ReferenceOr::Reference { .. } => None,
+        ReferenceOr::Item(request_body) => request_body
+            .content
+            .get("application/json")?
+            .schema
+            .as_ref(),
+    }
+}
+
+/// Get the content type of a binary request body
+///
+/// Matches `application/octet-stream` and other media types whose schema is a
+/// `format: binary` string.
+pub fn binary_request_body(operation: &Operation) -> Option<&str> {
+    let ReferenceOr::Item(request_body) = operation.request_body.as_ref()? else {
+        return None;
+    };
+
+    request_body
+        .content
+        .iter()
+        .find(|(content_type, media_type)| {
+            *content_type == "application/octet-stream"
+                || matches!(
+                    &media_type.schema,
+                    Some(ReferenceOr::Item(Schema {
+                        schema_kind: SchemaKind::Type(Type::String(StringType {
+                            format: VariantOrUnknownOrEmpty::Item(StringFormat::Binary),
+                            ..
+                        })),
+                        ..
+                    }))
+                )
+        })
+        .map(|(content_type, _)| content_type.as_str())
+}
+
+/// Get the inline object schema of a request body that needs a generated struct
+pub fn inline_request_body_object(operation: &Operation) -> Option<&Schema> {
+    match request_body_schema(operation)? {
+        ReferenceOr::Item(schema) => match &schema.schema_kind {
+            SchemaKind::Type(Type::Object(obj)) if !obj.properties.is_empty() => Some(schema),
+            _ => None,
+        },
+        ReferenceOr::Reference { .. } => None,
+    }
+}
+
+/// Name of the struct generated for an inline request body object
+pub fn request_body_struct_name(operation_id: &str) -> String {
+    format!("{}RequestBody", operation_id.to_pascal_case())
+}
+
+/// Determine the Rust type of an operation's request body
+///
+/// Bodies referencing a component schema use that type, inline objects use the
+/// generated request body struct and anything else falls back to
+/// `serde_json::Value`.
+pub fn request_body_type(
+    operation: &Operation,
+    operation_id: &str,
+) -> Result<TokenStream2, String> {
+    if inline_request_body_object(operation).is_some() {
+        let struct_name = format_ident!("{}", request_body_struct_name(operation_id));
+        return Ok(quote! { #struct_name });
+    }
+
+    match request_body_schema(operation) {
+        Some(schema_ref @ ReferenceOr::Reference { .. }) => {
+            reference_or_schema_to_rust_type(schema_ref)
+        }
+        _ => Ok(quote! { serde_json::Value }),
+    }
+}
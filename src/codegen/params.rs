@@ -1,7 +1,7 @@
 use heck::ToSnakeCase;
-use openapiv3::{ReferenceOr, SchemaKind, Type};
+use openapiv3::{ReferenceOr, SchemaKind, StringFormat, StringType, Type, VariantOrUnknownOrEmpty};
 use proc_macro2::{Ident, TokenStream as TokenStream2};
-use quote::quote;
+use quote::{format_ident, quote};
 
 use crate::codegen::reference_or_schema_to_rust_type;
 use crate::utils::create_rust_safe_ident;
@@ -14,6 +14,50 @@ pub struct ParameterInfo {
     pub location: ParameterLocation,
     pub is_array: bool,
     pub required: bool,
+    /// Allowed values when the parameter is a string enumeration
+    pub enum_values: Vec<String>,
+    /// How the value, or the items of arrays, are formatted in requests
+    pub format: ValueFormat,
+}
+
+impl ParameterInfo {
+    /// Expression formatting the parameter's value, held by `value`, as a `String`
+    ///
+    /// Array items are joined with commas, and formatted according to the
+    /// parameter's [`ValueFormat`].
+    pub fn value_to_string(&self, value: &Ident) -> TokenStream2 {
+        let format = |item: TokenStream2| match &self.format {
+            ValueFormat::Display => quote! { #item.to_string() },
+            ValueFormat::Rfc3339 => {
+                quote! { #item.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true) }
+            }
+            ValueFormat::UnixSeconds => quote! { #item.timestamp().to_string() },
+            ValueFormat::UnixMillis => quote! { #item.timestamp_millis().to_string() },
+            ValueFormat::Pattern(pattern) => quote! { #item.format(#pattern).to_string() },
+        };
+
+        if self.is_array {
+            let item = format(quote! { v });
+            quote! { #value.iter().map(|v| #item).collect::<Vec<String>>().join(",") }
+        } else {
+            format(quote! { #value })
+        }
+    }
+}
+
+/// Format of a parameter value in a request
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValueFormat {
+    /// The `Display` output of the value's type
+    Display,
+    /// RFC 3339, for `chrono::DateTime` values, whose `Display` output isn't
+    Rfc3339,
+    /// Seconds since the Unix epoch, for `x-date-format: unix`
+    UnixSeconds,
+    /// Milliseconds since the Unix epoch, for `x-date-format: unix-millis`
+    UnixMillis,
+    /// A `chrono` format string like `%Y%m%d`, given as `x-date-format`
+    Pattern(String),
 }
 
 /// Location where the parameter is used
@@ -25,6 +69,54 @@ pub enum ParameterLocation {
     Cookie,
 }
 
+/// Process all parameters of an operation, in declaration order
+pub fn operation_parameters(
+    operation: &openapiv3::Operation,
+) -> Result<Vec<ParameterInfo>, String> {
+    let mut all_params = Vec::new();
+
+    for param_ref in &operation.parameters {
+        let param = match param_ref {
+            ReferenceOr::Reference { reference } => {
+                return Err(format!("Parameter references not supported: {}", reference));
+            }
+            ReferenceOr::Item(item) => item,
+        };
+
+        let (param_name, param_schema, location, required) = match param {
+            openapiv3::Parameter::Query { parameter_data, .. } => (
+                &parameter_data.name,
+                &parameter_data.format,
+                ParameterLocation::Query,
+                parameter_data.required,
+            ),
+            openapiv3::Parameter::Path { parameter_data, .. } => (
+                &parameter_data.name,
+                &parameter_data.format,
+                ParameterLocation::Path,
+                parameter_data.required,
+            ),
+            openapiv3::Parameter::Header { parameter_data, .. } => (
+                &parameter_data.name,
+                &parameter_data.format,
+                ParameterLocation::Header,
+                parameter_data.required,
+            ),
+            openapiv3::Parameter::Cookie { parameter_data, .. } => (
+                &parameter_data.name,
+                &parameter_data.format,
+                ParameterLocation::Cookie,
+                parameter_data.required,
+            ),
+        };
+
+        let param_info = process_parameter(param_name, param_schema, location, required)?;
+        all_params.push(param_info);
+    }
+
+    Ok(all_params)
+}
+
 /// Process a parameter and return its information for code generation
 pub fn process_parameter(
     param_name: &str,
@@ -59,12 +151,9 @@ pub fn process_parameter(
 
     // Check if this is an array parameter
     let is_array = match param_schema {
-        openapiv3::ParameterSchemaOrContent::Schema(schema_ref) => match schema_ref {
-            ReferenceOr::Item(schema) => {
-                matches!(schema.schema_kind, SchemaKind::Type(Type::Array(_)))
-            }
-            _ => false,
-        },
+        openapiv3::ParameterSchemaOrContent::Schema(ReferenceOr::Item(schema)) => {
+            matches!(schema.schema_kind, SchemaKind::Type(Type::Array(_)))
+        }
         _ => false,
     };
 
@@ -75,9 +164,97 @@ pub fn process_parameter(
         location,
         is_array,
         required,
+        enum_values: parameter_enum_values(param_schema),
+        format: parameter_value_format(param_schema)
+            .map_err(|e| format!("Parameter {}: {}", param_name, e))?,
     })
 }
 
+/// Get the allowed values of a string enumeration parameter, or of its array items
+pub fn parameter_enum_values(param_schema: &openapiv3::ParameterSchemaOrContent) -> Vec<String> {
+    let openapiv3::ParameterSchemaOrContent::Schema(ReferenceOr::Item(schema)) = param_schema
+    else {
+        return Vec::new();
+    };
+
+    let string_schema = match &schema.schema_kind {
+        SchemaKind::Type(Type::String(string_schema)) => string_schema,
+        SchemaKind::Type(Type::Array(array)) => match array.items.as_ref() {
+            Some(ReferenceOr::Item(items)) => match &items.schema_kind {
+                SchemaKind::Type(Type::String(string_schema)) => string_schema,
+                _ => return Vec::new(),
+            },
+            _ => return Vec::new(),
+        },
+        _ => return Vec::new(),
+    };
+
+    string_schema
+        .enumeration
+        .iter()
+        .flatten()
+        .cloned()
+        .collect()
+}
+
+/// Get the format of a parameter's value, or of its array items
+///
+/// Date and date-time strings are `chrono` types with the `chrono` feature.
+/// Date-times are formatted as RFC 3339 unless their schema has an
+/// `x-date-format` extension: `unix` or `unix-millis` for a Unix timestamp, or
+/// a `chrono` format string. Dates only accept a format string.
+pub fn parameter_value_format(
+    param_schema: &openapiv3::ParameterSchemaOrContent,
+) -> Result<ValueFormat, String> {
+    let openapiv3::ParameterSchemaOrContent::Schema(ReferenceOr::Item(schema)) = param_schema
+    else {
+        return Ok(ValueFormat::Display);
+    };
+
+    let schema = match &schema.schema_kind {
+        SchemaKind::Type(Type::Array(array)) => match array.items.as_ref() {
+            Some(ReferenceOr::Item(items)) => items.as_ref(),
+            _ => return Ok(ValueFormat::Display),
+        },
+        _ => schema,
+    };
+
+    let SchemaKind::Type(Type::String(StringType {
+        format:
+            VariantOrUnknownOrEmpty::Item(string_format @ (StringFormat::Date | StringFormat::DateTime)),
+        ..
+    })) = &schema.schema_kind
+    else {
+        return Ok(ValueFormat::Display);
+    };
+    if !cfg!(feature = "chrono") {
+        return Ok(ValueFormat::Display);
+    }
+
+    let is_date_time = *string_format == StringFormat::DateTime;
+    let date_format = match schema.schema_data.extensions.get("x-date-format") {
+        Some(date_format) => Some(
+            date_format
+                .as_str()
+                .ok_or_else(|| format!("Invalid x-date-format: {}", date_format))?,
+        ),
+        None => None,
+    };
+
+    match date_format {
+        None | Some("rfc3339") if is_date_time => Ok(ValueFormat::Rfc3339),
+        None => Ok(ValueFormat::Display),
+        Some("unix") if is_date_time => Ok(ValueFormat::UnixSeconds),
+        Some("unix-millis") if is_date_time => Ok(ValueFormat::UnixMillis),
+        Some(pattern) if pattern.contains('%') => Ok(ValueFormat::Pattern(pattern.to_string())),
+        Some(date_format) => Err(format!(
+            "Unsupported x-date-format `{}` for a {} value",
+            date_format,
+            if is_date_time { "date-time" } else { "date" }
+        )),
+    }
+}
+
 /// Generate URL building code for path and query parameters
 pub fn generate_url_building(
     path: &str,
@@ -86,20 +263,20 @@ pub fn generate_url_building(
 ) -> TokenStream2 {
     let mut url_building = if path_params.is_empty() {
         quote! {
-            let mut url = format!("{}{}", self.base_url, #path);
+            let mut url = format!("{}{}", self.base_url.trim_end_matches('/'), #path);
         }
     } else {
         // Handle path parameters
         let path_replacements = path_params.iter().map(|param| {
             let placeholder = format!("{{{}}}", param.name);
-            let param_ident = &param.ident;
+            let value_expr = param.value_to_string(&param.ident);
             quote! {
-                .replace(#placeholder, &#param_ident.to_string())
+                .replace(#placeholder, &#value_expr)
             }
         });
 
         quote! {
-            let mut url = format!("{}{}", self.base_url, #path) #(#path_replacements)*;
+            let mut url = format!("{}{}", self.base_url.trim_end_matches('/'), #path) #(#path_replacements)*;
         }
     };
 
@@ -110,11 +287,7 @@ pub fn generate_url_building(
             let param_ident = &param.ident;
 
             // Generate the appropriate value expression
-            let value_expr = if param.is_array {
-                generate_array_value_expr(param_ident)
-            } else {
-                generate_single_value_expr(param_ident)
-            };
+            let value_expr = param.value_to_string(param_ident);
 
             // Generate the append code
             let append_code = generate_param_append_code(param_name, value_expr);
@@ -140,29 +313,80 @@ pub fn generate_url_building(
     url_building
 }
 
-/// Helper function to generate the core parameter append logic
-fn generate_param_append_code(param_name: &str, value_expr: TokenStream2) -> TokenStream2 {
-    quote! {
-        parsed_url.query_pairs_mut().append_pair(#param_name, &#value_expr);
-    }
+/// Generate code setting the header parameters of a request
+///
+/// `set_header` generates the statement setting a header from its name and
+/// `String` value. Arrays are sent comma-separated, and optional parameters
+/// only when they are set. With parameter structs, values are read from the
+/// extracted `<name>_value` variables.
+pub fn generate_header_params(
+    header_params: &[&ParameterInfo],
+    use_param_structs: bool,
+    set_header: impl Fn(&str, TokenStream2) -> TokenStream2,
+) -> TokenStream2 {
+    let headers = header_params.iter().map(|param| {
+        let value_ident = if use_param_structs {
+            format_ident!("{}_value", param.ident)
+        } else {
+            param.ident.clone()
+        };
+        let value_expr = param.value_to_string(&value_ident);
+        let set_code = set_header(&param.name, value_expr);
+
+        if param.required {
+            set_code
+        } else {
+            wrap_optional_code(set_code, &value_ident)
+        }
+    });
+
+    quote! { #(#headers)* }
 }
 
-/// Helper function to generate array value expression
-fn generate_array_value_expr(param_ident: &Ident) -> TokenStream2 {
+/// Generate code setting the cookie parameters of a request as one `Cookie` header
+///
+/// Cookies are sent as `name=value` pairs joined with `; `, leaving out unset
+/// optional parameters. `set_header` is used like for header parameters.
+pub fn generate_cookie_params(
+    cookie_params: &[&ParameterInfo],
+    use_param_structs: bool,
+    set_header: impl Fn(&str, TokenStream2) -> TokenStream2,
+) -> TokenStream2 {
+    if cookie_params.is_empty() {
+        return quote! {};
+    }
+
+    let cookies = cookie_params.iter().map(|param| {
+        let param_name = &param.name;
+        let value_ident = if use_param_structs {
+            format_ident!("{}_value", param.ident)
+        } else {
+            param.ident.clone()
+        };
+        let value_expr = param.value_to_string(&value_ident);
+        let cookie = quote! { format!("{}={}", #param_name, #value_expr) };
+
+        if param.required {
+            quote! { Some(#cookie) }
+        } else {
+            quote! { #value_ident.as_ref().map(|#value_ident| #cookie) }
+        }
+    });
+    let set_code = set_header("Cookie", quote! { cookies.join("; ") });
+
     quote! {
-        {
-            let param_value = #param_ident.iter()
-                .map(|n| n.to_string())
-                .collect::<Vec<String>>()
-                .join(",");
-            param_value
+        let cookies: Vec<String> = [#(#cookies),*].into_iter().flatten().collect();
+        if !cookies.is_empty() {
+            #set_code
         }
     }
 }
 
-/// Helper function to generate single value expression
-fn generate_single_value_expr(param_ident: &Ident) -> TokenStream2 {
-    quote! { #param_ident.to_string() }
+/// Helper function to generate the core parameter append logic
+fn generate_param_append_code(param_name: &str, value_expr: TokenStream2) -> TokenStream2 {
+    quote! {
+        parsed_url.query_pairs_mut().append_pair(#param_name, &#value_expr);
+    }
 }
 
 /// Helper function to wrap code for optional parameters using variable shadowing
@@ -1,13 +1,52 @@
 use heck::ToPascalCase;
-use openapiv3::{ReferenceOr, Schema, SchemaKind, Type};
+use openapiv3::{AdditionalProperties, ObjectType, ReferenceOr, Schema, SchemaKind, Type};
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{format_ident, quote};
 
 /// Convert an OpenAPI schema to a Rust type
 pub fn schema_to_rust_type(schema: &Schema) -> Result<TokenStream2, String> {
     match &schema.schema_kind {
+        SchemaKind::Type(Type::String(_)) | SchemaKind::Type(Type::Number(_))
+            if cfg!(feature = "decimal") && is_decimal_schema(schema) =>
+        {
+            Ok(quote! { rust_decimal::Decimal })
+        }
+        SchemaKind::Type(Type::String(string_schema))
+            if cfg!(feature = "uri-template")
+                && matches!(
+                    &string_schema.format,
+                    openapiv3::VariantOrUnknownOrEmpty::Unknown(format) if format == "uri-template"
+                ) =>
+        {
+            Ok(quote! { UriTemplate })
+        }
+        SchemaKind::Type(Type::String(string_schema))
+            if cfg!(feature = "chrono") && is_date_schema(schema) =>
+        {
+            match string_schema.format {
+                openapiv3::VariantOrUnknownOrEmpty::Item(openapiv3::StringFormat::Date) => {
+                    Ok(quote! { chrono::NaiveDate })
+                }
+                _ => Ok(quote! { chrono::DateTime<chrono::Utc> }),
+            }
+        }
+        SchemaKind::Type(Type::String(string_schema))
+            if cfg!(feature = "uuid")
+                && matches!(
+                    &string_schema.format,
+                    openapiv3::VariantOrUnknownOrEmpty::Unknown(format) if format == "uuid"
+                ) =>
+        {
+            Ok(quote! { uuid::Uuid })
+        }
         SchemaKind::Type(Type::String(_)) => Ok(quote! { String }),
         SchemaKind::Type(Type::Integer(int_schema)) => match int_schema.format {
+            // Non-negative 64-bit integers may exceed `i64`, e.g. unsigned IDs
+            openapiv3::VariantOrUnknownOrEmpty::Item(openapiv3::IntegerFormat::Int64)
+                if int_schema.minimum.is_some_and(|minimum| minimum >= 0) =>
+            {
+                Ok(quote! { u64 })
+            }
             openapiv3::VariantOrUnknownOrEmpty::Item(openapiv3::IntegerFormat::Int64) => {
                 Ok(quote! { i64 })
             }
@@ -21,6 +60,9 @@ pub fn schema_to_rust_type(schema: &Schema) -> Result<TokenStream2, String> {
         },
         SchemaKind::Type(Type::Boolean(_)) => Ok(quote! { bool }),
         SchemaKind::Type(Type::Array(array_schema)) => {
+            if let Some(prefix_items) = schema.schema_data.extensions.get("x-prefixItems") {
+                return prefix_items_to_rust_type(prefix_items, array_schema.items.is_some());
+            }
             if let Some(items) = &array_schema.items {
                 let item_type = match items {
                     ReferenceOr::Reference { reference } => {
@@ -38,11 +80,76 @@ pub fn schema_to_rust_type(schema: &Schema) -> Result<TokenStream2, String> {
                 Ok(quote! { Vec<serde_json::Value> })
             }
         }
-        SchemaKind::Type(Type::Object(_)) => Ok(quote! { HashMap<String, serde_json::Value> }),
+        SchemaKind::Type(Type::Object(obj)) => {
+            let value_type =
+                additional_properties_type(obj)?.unwrap_or_else(|| quote! { serde_json::Value });
+            Ok(quote! { HashMap<String, #value_type> })
+        }
+        // `allOf` with a single `$ref`, the OpenAPI 3.0 idiom for a nullable reference
+        SchemaKind::AllOf { .. } => match single_ref_all_of(schema) {
+            Some(referenced) => {
+                let rust_type = reference_or_schema_to_rust_type(referenced)?;
+                if schema.schema_data.nullable {
+                    Ok(quote! { Option<#rust_type> })
+                } else {
+                    Ok(rust_type)
+                }
+            }
+            None => Ok(quote! { serde_json::Value }),
+        },
         _ => Ok(quote! { serde_json::Value }),
     }
 }
 
+/// Get the type of the additional properties of an object, if it allows them
+///
+/// `additionalProperties: true` allows values of any type.
+pub fn additional_properties_type(obj: &ObjectType) -> Result<Option<TokenStream2>, String> {
+    match &obj.additional_properties {
+        Some(AdditionalProperties::Schema(schema)) => {
+            reference_or_schema_to_rust_type(schema).map(Some)
+        }
+        Some(AdditionalProperties::Any(true)) => Ok(Some(quote! { serde_json::Value })),
+        _ => Ok(None),
+    }
+}
+
+/// Get the reference of an `allOf` schema wrapping a single `$ref`
+pub fn single_ref_all_of(schema: &Schema) -> Option<&ReferenceOr<Schema>> {
+    match &schema.schema_kind {
+        SchemaKind::AllOf { all_of } => match all_of.as_slice() {
+            [reference @ ReferenceOr::Reference { .. }] => Some(reference),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Convert the positional `prefixItems` schemas of an array to a tuple type
+///
+/// Falls back to `Vec<serde_json::Value>` when items beyond the positional ones
+/// have a schema of their own, or when the positions can't be typed as a tuple.
+fn prefix_items_to_rust_type(
+    prefix_items: &serde_json::Value,
+    has_additional_items: bool,
+) -> Result<TokenStream2, String> {
+    let fallback = quote! { Vec<serde_json::Value> };
+    let Ok(item_schemas) = serde_json::from_value::<Vec<ReferenceOr<Schema>>>(prefix_items.clone())
+    else {
+        return Ok(fallback);
+    };
+    // Standard library traits are only implemented for tuples of up to 12 elements
+    if has_additional_items || item_schemas.is_empty() || item_schemas.len() > 12 {
+        return Ok(fallback);
+    }
+
+    let item_types = item_schemas
+        .iter()
+        .map(reference_or_schema_to_rust_type)
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(quote! { (#(#item_types,)*) })
+}
+
 /// Convert a reference or schema to a Rust type
 pub fn reference_or_schema_to_rust_type(
     schema_ref: &ReferenceOr<Schema>,
@@ -59,3 +166,45 @@ pub fn reference_or_schema_to_rust_type(
         ReferenceOr::Item(schema) => schema_to_rust_type(schema),
     }
 }
+
+/// Check if a schema is a string with the `date` or `date-time` format
+fn is_date_schema(schema: &Schema) -> bool {
+    matches!(
+        &schema.schema_kind,
+        SchemaKind::Type(Type::String(openapiv3::StringType {
+            format: openapiv3::VariantOrUnknownOrEmpty::Item(
+                openapiv3::StringFormat::Date | openapiv3::StringFormat::DateTime
+            ),
+            ..
+        }))
+    )
+}
+
+/// Check if a schema describes a fixed-point decimal value
+///
+/// Matches `format: decimal`/`format: money` on string and number schemas, as
+/// well as the `x-type: money` vendor extension.
+fn is_decimal_schema(schema: &Schema) -> bool {
+    let format = match &schema.schema_kind {
+        SchemaKind::Type(Type::String(string_schema)) => match &string_schema.format {
+            openapiv3::VariantOrUnknownOrEmpty::Unknown(format) => Some(format.as_str()),
+            _ => None,
+        },
+        SchemaKind::Type(Type::Number(num_schema)) => match &num_schema.format {
+            openapiv3::VariantOrUnknownOrEmpty::Unknown(format) => Some(format.as_str()),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    if matches!(format, Some("decimal") | Some("money")) {
+        return true;
+    }
+
+    schema
+        .schema_data
+        .extensions
+        .get("x-type")
+        .and_then(|value| value.as_str())
+        == Some("money")
+}
@@ -0,0 +1,82 @@
+use openapiv3::OpenAPI;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+
+use crate::codegen::{operation_parameters, spec_operations};
+use crate::generator::methods::client_method_name;
+use crate::parser::OpenApiInput;
+
+/// Generate the `verify_credentials` method of the `auth_check_operation` option
+///
+/// The method calls the configured operation, which must be callable without
+/// arguments, and reports a `401 Unauthorized` response as
+/// `ApiError::Unauthorized`.
+pub fn generate_verify_credentials(
+    spec: &OpenAPI,
+    input: &OpenApiInput,
+    is_blocking: bool,
+) -> Result<TokenStream2, String> {
+    let Some(operation_id) = &input.auth_check_operation else {
+        return Ok(quote! {});
+    };
+    if input.operation_errors {
+        return Err(
+            "The `auth_check_operation` option is not supported with `operation_errors`"
+                .to_string(),
+        );
+    }
+
+    let (path, method, operation) = spec_operations(spec)?
+        .into_iter()
+        .find(|(_, _, operation)| operation.operation_id.as_deref() == Some(operation_id))
+        .ok_or_else(|| {
+            format!(
+                "Operation `{}` of the `auth_check_operation` option not found",
+                operation_id
+            )
+        })?;
+
+    let params = operation_parameters(operation)?;
+    if operation.request_body.is_some() || params.iter().any(|param| param.required) {
+        return Err(format!(
+            "Operation `{}` of the `auth_check_operation` option must not have a request body or required parameters",
+            operation_id
+        ));
+    }
+
+    // Optional parameters are left out
+    let args = if params.is_empty() {
+        Vec::new()
+    } else if input.use_param_structs {
+        vec![quote! { Default::default() }]
+    } else {
+        params.iter().map(|_| quote! { None }).collect()
+    };
+    let method_name = client_method_name(path, method, operation);
+    let (method_async, call) = if is_blocking {
+        (quote! {}, quote! { self.#method_name(#(#args),*) })
+    } else {
+        (
+            quote! { async },
+            quote! { self.#method_name(#(#args),*).await },
+        )
+    };
+    let doc = format!(
+        "Check that the server accepts the configured credentials by calling `{}`",
+        operation_id
+    );
+
+    Ok(quote! {
+        #[doc = #doc]
+        ///
+        /// The response is discarded. A `401 Unauthorized` response results in
+        /// `ApiError::Unauthorized`, other failures in their usual error.
+        pub #method_async fn verify_credentials(&self) -> ApiResult<()> {
+            match #call {
+                Ok(_) => Ok(()),
+                Err(e) if e.is_unauthorized() => Err(ApiError::Unauthorized),
+                Err(e) => Err(e),
+            }
+        }
+    })
+}
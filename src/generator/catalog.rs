@@ -0,0 +1,133 @@
+use heck::ToPascalCase;
+use openapiv3::OpenAPI;
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+
+use crate::codegen::{operation_id_or_default, spec_operations};
+use crate::generator::methods::client_method_name;
+
+/// Get the name of a type generated for the client, avoiding the types of the spec's schemas
+///
+/// This is `name`, unless the spec has a schema generating a type by that
+/// name. The type is prefixed with `Schema` then.
+fn client_type_name(spec: &OpenAPI, name: &str) -> Ident {
+    let taken = spec.components.as_ref().is_some_and(|components| {
+        components
+            .schemas
+            .keys()
+            .any(|schema| schema.to_pascal_case() == name)
+    });
+
+    if taken {
+        format_ident!("Schema{}", name)
+    } else {
+        format_ident!("{}", name)
+    }
+}
+
+/// Generate the `ApiInfo` struct and the `API_INFO` constant from the spec's `info`
+///
+/// The struct is named `SchemaApiInfo` if the spec has an `ApiInfo` schema.
+pub fn generate_api_info(spec: &OpenAPI) -> TokenStream2 {
+    let api_info_name = client_type_name(spec, "ApiInfo");
+    let optional = |value: Option<&str>| match value.map(str::trim) {
+        Some(value) if !value.is_empty() => quote! { Some(#value) },
+        _ => quote! { None },
+    };
+
+    let info = &spec.info;
+    let title = info.title.trim();
+    let version = info.version.trim();
+    let description = optional(info.description.as_deref());
+    let contact_email = optional(
+        info.contact
+            .as_ref()
+            .and_then(|contact| contact.email.as_deref()),
+    );
+    let license_name = optional(info.license.as_ref().map(|license| license.name.as_str()));
+
+    quote! {
+        /// Metadata of the API from the `info` section of the spec
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct #api_info_name {
+            /// Title of the API
+            pub title: &'static str,
+            /// Version of the API, not of the spec format
+            pub version: &'static str,
+            /// Description of the API
+            pub description: Option<&'static str>,
+            /// Email address of the API's contact
+            pub contact_email: Option<&'static str>,
+            /// Name of the API's license
+            pub license_name: Option<&'static str>,
+        }
+
+        /// Metadata of the API from the `info` section of the spec
+        pub const API_INFO: #api_info_name = #api_info_name {
+            title: #title,
+            version: #version,
+            description: #description,
+            contact_email: #contact_email,
+            license_name: #license_name,
+        };
+    }
+}
+
+/// Generate the `OperationInfo` struct describing the operations of the spec
+///
+/// The struct is named `SchemaOperationInfo` if the spec has an
+/// `OperationInfo` schema.
+pub fn generate_operation_info_type(spec: &OpenAPI) -> TokenStream2 {
+    let operation_info_name = client_type_name(spec, "OperationInfo");
+    quote! {
+        /// Metadata of an operation in the spec, see the `OPERATIONS` of the client
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct #operation_info_name {
+            /// The `operationId`, or the one derived from the method and path
+            pub operation_id: &'static str,
+            /// Name of the client method sending the operation
+            pub method_name: &'static str,
+            /// HTTP method in upper case, e.g. `GET`
+            pub http_method: &'static str,
+            /// Path template, e.g. `/users/{id}`
+            pub path: &'static str,
+            /// Tags of the operation
+            pub tags: &'static [&'static str],
+        }
+    }
+}
+
+/// Generate the `OPERATIONS` catalog and tag lookup of a client
+pub fn generate_operation_catalog(spec: &OpenAPI) -> Result<TokenStream2, String> {
+    let operation_info_name = client_type_name(spec, "OperationInfo");
+    let mut operations = Vec::new();
+    for (path, method, operation) in spec_operations(spec)? {
+        let operation_id = operation_id_or_default(operation, method, path);
+        let method_name = client_method_name(path, method, operation).to_string();
+        let method_name = method_name.trim_start_matches("r#");
+        let http_method = method.to_uppercase();
+        let tags = &operation.tags;
+
+        operations.push(quote! {
+            #operation_info_name {
+                operation_id: #operation_id,
+                method_name: #method_name,
+                http_method: #http_method,
+                path: #path,
+                tags: &[#(#tags),*],
+            }
+        });
+    }
+
+    Ok(quote! {
+        /// All operations of the spec, sorted by path and HTTP method
+        pub const OPERATIONS: &'static [#operation_info_name] = &[#(#operations),*];
+
+        /// Get the operations tagged with the given tag
+        pub fn operations_tagged(tag: &str) -> impl Iterator<Item = &'static #operation_info_name> + '_ {
+            Self::OPERATIONS
+                .iter()
+                .filter(move |operation| operation.tags.contains(&tag))
+        }
+    })
+}
@@ -1,58 +1,103 @@
-use openapiv3::{OpenAPI, ReferenceOr};
+use openapiv3::OpenAPI;
 use proc_macro2::{Ident, TokenStream as TokenStream2};
-use quote::quote;
+use quote::{format_ident, quote};
 
-use crate::generator::methods::{generate_blocking_client_method, generate_client_method};
+use crate::codegen::{operation_id_or_default, operation_rate_limit, spec_operations};
+use crate::generator::methods::{
+    client_method_name, generate_blocking_client_method, generate_client_method,
+    generate_error_body_helper,
+};
+use crate::generator::ureq::{check_ureq_support, generate_ureq_client_method};
+use crate::generator::{
+    generate_operation_catalog, generate_pagination_method, generate_send_with_retry,
+    generate_verify_credentials,
+};
+use crate::parser::{AuthScheme, OpenApiInput, default_server_url};
 
 /// Generate the complete client implementation
+///
+/// In `dual` mode the blocking methods are left out, as they are generated on
+/// a separate client by [`generate_blocking_client_impl`].
 pub fn generate_client_impl(
     spec: &OpenAPI,
     client_name: &Ident,
-    use_param_structs: bool,
+    input: &OpenApiInput,
+    env: &ClientEnv,
 ) -> Result<TokenStream2, String> {
-    let mut api_methods = TokenStream2::new();
-    let mut blocking_api_methods = TokenStream2::new();
+    if cfg!(feature = "ureq") {
+        return generate_ureq_client_impl(spec, client_name, input, env);
+    }
 
-    // Generate methods from paths
-    for (path, path_item_ref) in spec.paths.iter() {
-        let path_item = match path_item_ref {
-            ReferenceOr::Reference { reference } => {
-                return Err(format!("Path item references not supported: {}", reference));
-            }
-            ReferenceOr::Item(item) => item,
-        };
+    let fields = generate_client_fields(spec, input)?;
+    let copies = &fields.copies;
+    let api_methods = generate_api_methods(spec, input, false)?;
 
-        for (method, operation) in [
-            ("get", &path_item.get),
-            ("post", &path_item.post),
-            ("put", &path_item.put),
-            ("delete", &path_item.delete),
-            ("patch", &path_item.patch),
-            ("head", &path_item.head),
-            ("options", &path_item.options),
-            ("trace", &path_item.trace),
-        ] {
-            if let Some(op) = operation {
-                // Generate async methods
-                let method_tokens = generate_client_method(path, method, op, use_param_structs)?;
-                api_methods.extend(method_tokens);
-
-                // Generate blocking methods if feature is enabled
-                if cfg!(feature = "blocking") {
-                    let blocking_method_tokens =
-                        generate_blocking_client_method(path, method, op, use_param_structs)?;
-                    blocking_api_methods.extend(blocking_method_tokens);
+    // Convert to the separate blocking client in `dual` mode
+    let to_blocking = if cfg!(feature = "blocking") && input.dual {
+        let blocking_client_name = format_ident!("{}Blocking", client_name);
+        quote! {
+            /// Create a blocking client with the same base URL and configuration
+            ///
+            /// The timeout, credentials and other settings are copied, while
+            /// caches and rate limiters are shared with this client. Requests are
+            /// sent with the given `reqwest::blocking::Client`, as the settings of
+            /// this client's HTTP client, like its proxies and default headers,
+            /// can't be carried over. Note that a `reqwest::blocking::Client`
+            /// can't be created in an async context.
+            pub fn to_blocking(&self, client: reqwest::blocking::Client) -> #blocking_client_name {
+                #blocking_client_name {
+                    base_url: self.base_url.clone(),
+                    client,
+                    #copies
                 }
             }
         }
-    }
+    } else {
+        quote! {}
+    };
+    let constructors = generate_constructors(
+        spec,
+        client_name,
+        input,
+        env,
+        &fields,
+        HttpBackend::Async,
+        to_blocking,
+    )?;
+
+    let request_signer_method = generate_request_signer_method(false);
+    let blocking_request_signer_method = generate_request_signer_method(true);
+    let send = generate_send_with_retry(
+        input,
+        quote! { self.client.execute(request).await },
+        quote! { e },
+        false,
+    );
+    let middleware_send = generate_send_with_retry(
+        input,
+        quote! { self.client.execute(request).await },
+        quote! { reqwest_middleware::Error::Reqwest(e) },
+        false,
+    );
+    let blocking_send = generate_send_with_retry(
+        input,
+        quote! { self.client.execute(request) },
+        quote! { e },
+        true,
+    );
 
     // Generate middleware implementation only if the feature is enabled
     let middleware_impl = if cfg!(feature = "middleware") {
         quote! {
             impl #client_name<reqwest_middleware::ClientWithMiddleware> {
-                async fn send_request(request: reqwest_middleware::RequestBuilder) -> ApiResult<reqwest::Response> {
-                    request.send().await.map_err(|e| match e {
+                #request_signer_method
+
+                async fn send_request(&self, request: reqwest_middleware::RequestBuilder) -> ApiResult<reqwest::Response> {
+                    let mut request = request.build()?;
+                    if let Some(sign) = &self.request_signer {
+                        sign(&mut request);
+                    }
+                    #middleware_send.map_err(|e| match e {
                         reqwest_middleware::Error::Reqwest(e) => ApiError::Http(e),
                         e => ApiError::Middleware(e.to_string()),
                     })
@@ -66,11 +111,23 @@ pub fn generate_client_impl(
     };
 
     // Generate blocking implementation only if the feature is enabled
-    let blocking_impl = if cfg!(feature = "blocking") {
+    let has_blocking_impl = cfg!(feature = "blocking") && !input.dual;
+    let blocking_api_methods = if has_blocking_impl {
+        generate_api_methods(spec, input, true)?
+    } else {
+        quote! {}
+    };
+    let blocking_impl = if has_blocking_impl {
         quote! {
             impl #client_name<reqwest::blocking::Client> {
-                fn send_request(request: reqwest::blocking::RequestBuilder) -> ApiResult<reqwest::blocking::Response> {
-                    request.send().map_err(ApiError::Http)
+                #blocking_request_signer_method
+
+                fn send_request(&self, request: reqwest::blocking::RequestBuilder) -> ApiResult<reqwest::blocking::Response> {
+                    let mut request = request.build()?;
+                    if let Some(sign) = &self.blocking_request_signer {
+                        sign(&mut request);
+                    }
+                    #blocking_send.map_err(ApiError::Http)
                 }
 
                 #blocking_api_methods
@@ -80,44 +137,1261 @@ pub fn generate_client_impl(
         quote! {}
     };
 
+    let client_builder = generate_client_builder(client_name, input);
+
+    // Generate traits with the operation methods if requested
+    let client_traits = if input.client_trait {
+        let mut client_types = vec![quote! { #client_name<reqwest::Client> }];
+        if cfg!(feature = "middleware") {
+            client_types.push(quote! { #client_name<reqwest_middleware::ClientWithMiddleware> });
+        }
+        let mut traits = generate_client_trait(
+            spec,
+            &format_ident!("{}Api", client_name),
+            &client_types,
+            &api_methods,
+            false,
+        )?;
+        if has_blocking_impl {
+            traits.extend(generate_client_trait(
+                spec,
+                &format_ident!("{}BlockingApi", client_name),
+                &[quote! { #client_name<reqwest::blocking::Client> }],
+                &blocking_api_methods,
+                true,
+            )?);
+        }
+        traits
+    } else {
+        quote! {}
+    };
+
     // Build complete impl block
     Ok(quote! {
-        // Default implementation with reqwest::Client
+        #constructors
+
+        // Helper trait for sending requests
+        impl #client_name<reqwest::Client> {
+            #request_signer_method
+
+            async fn send_request(&self, request: reqwest::RequestBuilder) -> ApiResult<reqwest::Response> {
+                let mut request = request.build()?;
+                if let Some(sign) = &self.request_signer {
+                    sign(&mut request);
+                }
+                #send.map_err(ApiError::Http)
+            }
+
+            #api_methods
+        }
+
+        // Helper for middleware client - only generate if middleware feature is enabled
+        #middleware_impl
+
+        // Helper for blocking client - only generate if blocking feature is enabled
+        #blocking_impl
+
+        #client_builder
+
+        #client_traits
+    })
+}
+
+/// Generate the implementation of the standalone blocking client used in `dual` mode
+pub fn generate_blocking_client_impl(
+    spec: &OpenAPI,
+    client_name: &Ident,
+    async_client_name: &Ident,
+    input: &OpenApiInput,
+    env: &ClientEnv,
+) -> Result<TokenStream2, String> {
+    if !cfg!(feature = "blocking") {
+        return Err("The `dual` option requires the `blocking` feature".to_string());
+    }
+
+    let fields = generate_client_fields(spec, input)?;
+    let copies = &fields.copies;
+    let to_async = quote! {
+        /// Create an async client with the same base URL and configuration
+        ///
+        /// The timeout, credentials and other settings are copied, while
+        /// caches and rate limiters are shared with this client. Requests are
+        /// sent with the given HTTP client, as the settings of this client's
+        /// HTTP client, like its proxies and default headers, can't be carried
+        /// over.
+        pub fn to_async<D>(&self, client: D) -> #async_client_name<D> {
+            #async_client_name {
+                base_url: self.base_url.clone(),
+                client,
+                #copies
+            }
+        }
+    };
+    let constructors = generate_constructors(
+        spec,
+        client_name,
+        input,
+        env,
+        &fields,
+        HttpBackend::Blocking,
+        to_async,
+    )?;
+    let blocking_api_methods = generate_api_methods(spec, input, true)?;
+    let request_signer_method = generate_request_signer_method(true);
+    let blocking_send = generate_send_with_retry(
+        input,
+        quote! { self.client.execute(request) },
+        quote! { e },
+        true,
+    );
+    let client_trait = if input.client_trait {
+        generate_client_trait(
+            spec,
+            &format_ident!("{}Api", client_name),
+            &[quote! { #client_name<reqwest::blocking::Client> }],
+            &blocking_api_methods,
+            true,
+        )?
+    } else {
+        quote! {}
+    };
+
+    Ok(quote! {
+        #constructors
+
+        impl #client_name<reqwest::blocking::Client> {
+            #request_signer_method
+
+            fn send_request(&self, request: reqwest::blocking::RequestBuilder) -> ApiResult<reqwest::blocking::Response> {
+                let mut request = request.build()?;
+                if let Some(sign) = &self.blocking_request_signer {
+                    sign(&mut request);
+                }
+                #blocking_send.map_err(ApiError::Http)
+            }
+
+            #blocking_api_methods
+        }
+
+        #client_trait
+    })
+}
+
+/// Generate the implementation of a blocking client built on `ureq` instead of `reqwest`
+///
+/// Used instead of [`generate_client_impl`] when the `ureq` feature is enabled.
+fn generate_ureq_client_impl(
+    spec: &OpenAPI,
+    client_name: &Ident,
+    input: &OpenApiInput,
+    env: &ClientEnv,
+) -> Result<TokenStream2, String> {
+    check_ureq_support(input)?;
+
+    let fields = generate_client_fields(spec, input)?;
+    let constructors = generate_constructors(
+        spec,
+        client_name,
+        input,
+        env,
+        &fields,
+        HttpBackend::Ureq,
+        quote! {},
+    )?;
+
+    let mut api_methods = TokenStream2::new();
+    for (path, method, op) in spec_operations(spec)? {
+        api_methods.extend(generate_ureq_client_method(spec, path, method, op, input)?);
+    }
+    api_methods.extend(generate_verify_credentials(spec, input, true)?);
+
+    Ok(quote! {
+        #constructors
+
+        impl #client_name<ureq::Agent> {
+            /// Get the response of a request, including error statuses, which `ureq` reports as errors
+            fn send_request(result: Result<ureq::Response, ureq::Error>) -> ApiResult<ureq::Response> {
+                match result {
+                    Ok(response) | Err(ureq::Error::Status(_, response)) => Ok(response),
+                    Err(ureq::Error::Transport(e)) => Err(ApiError::Http(Box::new(e))),
+                }
+            }
+
+            #api_methods
+        }
+    })
+}
+
+/// HTTP client a client implementation sends its requests with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HttpBackend {
+    /// `reqwest::Client`
+    Async,
+    /// `reqwest::blocking::Client` of the separate blocking client in `dual` mode
+    Blocking,
+    /// `ureq::Agent`, with the `ureq` feature
+    Ureq,
+}
+
+/// Generate the constructors and configuration methods of a client
+///
+/// These are the same for every HTTP client, apart from how the HTTP client
+/// itself is created. The `conversion` methods to the other client of `dual`
+/// mode are added to the configuration methods.
+fn generate_constructors(
+    spec: &OpenAPI,
+    client_name: &Ident,
+    input: &OpenApiInput,
+    env: &ClientEnv,
+    fields: &ClientFields,
+    backend: HttpBackend,
+    conversion: TokenStream2,
+) -> Result<TokenStream2, String> {
+    let from_env = generate_from_env_constructor(spec, input, env);
+    let default_server = generate_default_server_constructor(spec);
+    let error_body_helpers = generate_error_body_helpers(spec)?;
+    let operation_catalog = generate_operation_catalog(spec)?;
+    let etag_cache_methods = generate_etag_cache_methods(input);
+    let auth_methods = generate_auth_methods(input);
+    let retry_policy_method = generate_retry_policy_method(input);
+    let field_inits = &fields.initializers;
+    let testing_overrides = &fields.testing_overrides;
+
+    // The separate blocking client of `dual` mode is told apart in the docs
+    let description = match backend {
+        HttpBackend::Blocking => "blocking API client",
+        HttpBackend::Async | HttpBackend::Ureq => "API client",
+    };
+    let new_doc = format!("Create a new {} with the specified base URL", description);
+    let user_agent_doc = format!(
+        "Create a new {} sending the given `User-Agent` header",
+        description
+    );
+    let testing_doc = format!(
+        "Create a new {} suited to tests against a local mock server",
+        description
+    );
+
+    let client_type = match backend {
+        HttpBackend::Async => quote! { reqwest::Client },
+        HttpBackend::Blocking => quote! { reqwest::blocking::Client },
+        HttpBackend::Ureq => quote! { ureq::Agent },
+    };
+    let user_agent_client = if backend == HttpBackend::Ureq {
+        quote! {
+            ureq::AgentBuilder::new()
+                .user_agent(&user_agent.into())
+                .build()
+        }
+    } else {
+        quote! {
+            #client_type::builder()
+                .user_agent(user_agent.into())
+                .build()?
+        }
+    };
+
+    // `ureq` agents don't read proxy settings and only support TCP
+    let backend_constructors = if backend == HttpBackend::Ureq {
+        quote! {
+            #[doc = #testing_doc]
+            ///
+            /// Same as [`Self::new`], as `ureq` agents don't read proxy
+            /// settings from the environment.
+            pub fn for_testing(base_url: impl Into<String>) -> Self {
+                Self::new(base_url)
+            }
+        }
+    } else {
+        let redirect_doc = format!(
+            "Create a new {} following redirects according to the given policy",
+            description
+        );
+        let unix_socket = generate_unix_socket_constructor(backend == HttpBackend::Blocking);
+        quote! {
+            #[doc = #redirect_doc]
+            ///
+            /// Use `reqwest::redirect::Policy::none()` to receive 3xx responses as
+            /// `ApiError::Redirect` with their `Location` header.
+            pub fn with_redirect_policy(
+                base_url: impl Into<String>,
+                policy: reqwest::redirect::Policy,
+            ) -> ApiResult<Self> {
+                let client = #client_type::builder()
+                    .redirect(policy)
+                    .build()?;
+                Ok(Self::with_client(base_url, client))
+            }
+
+            #unix_socket
+
+            #[doc = #testing_doc]
+            ///
+            /// Ignores proxy settings from the environment and disables
+            /// client-side throttling and automatic retries, so every call
+            /// results in exactly one request.
+            pub fn for_testing(base_url: impl Into<String>) -> Self {
+                let http_client = #client_type::builder()
+                    .no_proxy()
+                    .build()
+                    .expect("Failed to build HTTP client");
+                #[allow(unused_mut)]
+                let mut client = Self::with_client(base_url, http_client);
+                #testing_overrides
+                client
+            }
+        }
+    };
+
+    let (with_client_doc, into_inner_doc) = if backend == HttpBackend::Ureq {
+        (
+            "Create a new API client with a custom `ureq` agent".to_string(),
+            quote! {
+                /// Consume the API client, returning the underlying `ureq` agent
+                ///
+                /// Allows reusing the connection pool of the agent after the API
+                /// client is no longer needed.
+            },
+        )
+    } else {
+        (
+            format!("Create a new {} with a custom HTTP client", description),
+            quote! {
+                /// Consume the API client, returning the underlying HTTP client
+                ///
+                /// Allows reusing the connection pool of the HTTP client after
+                /// the API client is no longer needed.
+            },
+        )
+    };
+
+    Ok(quote! {
         impl #client_name {
-            /// Create a new API client with the specified base URL
+            #[doc = #new_doc]
             pub fn new(base_url: impl Into<String>) -> Self {
-                Self {
-                    base_url: base_url.into(),
-                    client: reqwest::Client::new(),
-                }
+                Self::with_client(base_url, #client_type::new())
             }
+
+            #from_env
+
+            #default_server
+
+            #[doc = #user_agent_doc]
+            pub fn with_user_agent(
+                base_url: impl Into<String>,
+                user_agent: impl Into<String>,
+            ) -> ApiResult<Self> {
+                let client = #user_agent_client;
+                Ok(Self::with_client(base_url, client))
+            }
+
+            #backend_constructors
+
+            #error_body_helpers
+
+            #operation_catalog
         }
 
-        // Generic implementation for any HTTP client
         impl<C> #client_name<C> {
-            /// Create a new API client with a custom HTTP client
+            #[doc = #with_client_doc]
             pub fn with_client(base_url: impl Into<String>, client: C) -> Self {
                 Self {
                     base_url: base_url.into(),
                     client,
+                    #field_inits
                 }
             }
+
+            #into_inner_doc
+            pub fn into_inner(self) -> C {
+                self.client
+            }
+
+            /// Fail requests that don't complete within the given duration
+            ///
+            /// The timeout applies to each request on its own, from sending it
+            /// until the response body has been read.
+            pub fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
+                self.timeout = Some(timeout);
+                self
+            }
+
+            #etag_cache_methods
+
+            #auth_methods
+
+            #retry_policy_method
+
+            #conversion
         }
+    })
+}
 
-        // Helper trait for sending requests
-        impl #client_name<reqwest::Client> {
-            async fn send_request(request: reqwest::RequestBuilder) -> ApiResult<reqwest::Response> {
-                request.send().await.map_err(ApiError::Http)
+/// Environment variables read by `from_env`
+pub struct ClientEnv {
+    /// Variable holding the base URL
+    pub base_url: String,
+    /// Variable holding the credentials of the `auth` option
+    pub token: String,
+}
+
+/// Generate the methods setting and clearing the credentials of the `auth` option
+///
+/// Clients with `shared_auth` store the credentials behind a lock shared
+/// between clones, and get a `set_` method replacing them through `&self`.
+fn generate_auth_methods(input: &OpenApiInput) -> TokenStream2 {
+    let Some(auth) = input.auth else {
+        return quote! {};
+    };
+
+    let (field, _) = auth_field(auth);
+    let clear_auth = generate_clear_auth(std::slice::from_ref(&field), input.shared_auth);
+    let (params, credentials) = credentials_params(auth);
+    let (builder, setter, doc) = match auth {
+        AuthScheme::Bearer => (
+            format_ident!("with_bearer_token"),
+            format_ident!("set_bearer_token"),
+            "Send an `Authorization: Bearer` header with the given token on every request",
+        ),
+        AuthScheme::ApiKey => (
+            format_ident!("with_api_key"),
+            format_ident!("set_api_key"),
+            "Send the given key of the spec's `apiKey` security scheme with every request",
+        ),
+        AuthScheme::Basic => (
+            format_ident!("with_basic_auth"),
+            format_ident!("set_basic_auth"),
+            "Send an `Authorization: Basic` header with the given credentials on every request",
+        ),
+    };
+
+    if !input.shared_auth {
+        return quote! {
+            #[doc = #doc]
+            pub fn #builder(mut self, #params) -> Self {
+                self.#field = Some(#credentials);
+                self
             }
 
-            #api_methods
+            #clear_auth
+        };
+    }
+
+    quote! {
+        #[doc = #doc]
+        ///
+        /// The credentials are shared with the clones of the client.
+        pub fn #builder(self, #params) -> Self {
+            *self.#field.write().unwrap() = Some(#credentials);
+            self
         }
 
-        // Helper for middleware client - only generate if middleware feature is enabled
-        #middleware_impl
+        /// Replace the credentials sent with every request, e.g. after refreshing a token
+        ///
+        /// The client and all of its clones send the new credentials from the
+        /// next request on.
+        pub fn #setter(&self, #params) {
+            *self.#field.write().unwrap() = Some(#credentials);
+        }
 
-        // Helper for blocking client - only generate if blocking feature is enabled
-        #blocking_impl
+        #clear_auth
+    }
+}
 
+/// Get the parameters of the methods setting the credentials of an auth scheme, and the credentials they give
+fn credentials_params(auth: AuthScheme) -> (TokenStream2, TokenStream2) {
+    match auth {
+        AuthScheme::Bearer => (quote! { token: impl Into<String> }, quote! { token.into() }),
+        AuthScheme::ApiKey => (quote! { key: impl Into<String> }, quote! { key.into() }),
+        AuthScheme::Basic => (
+            quote! { user: impl Into<String>, password: impl Into<String> },
+            quote! { (user.into(), password.into()) },
+        ),
+    }
+}
+
+/// Get the type of the credentials of an auth scheme, stored in an `Option`
+fn credentials_type(auth: AuthScheme) -> TokenStream2 {
+    match auth {
+        AuthScheme::Bearer | AuthScheme::ApiKey => quote! { String },
+        AuthScheme::Basic => quote! { (String, String) },
+    }
+}
+
+/// Get an expression borrowing the credentials of the `auth` option as an `&Option`
+///
+/// Credentials shared between clones are borrowed through their lock, which
+/// is held until the end of the statement using them.
+pub fn stored_credentials(input: &OpenApiInput) -> TokenStream2 {
+    let Some(auth) = input.auth else {
+        return quote! {};
+    };
+
+    let (field, _) = auth_field(auth);
+    if input.shared_auth {
+        quote! { &*self.#field.read().unwrap() }
+    } else {
+        quote! { &self.#field }
+    }
+}
+
+/// Get a statement storing credentials held by `value` in a constructed `client`
+fn store_credentials(input: &OpenApiInput, field: &Ident, value: TokenStream2) -> TokenStream2 {
+    if input.shared_auth {
+        quote! { *client.#field.write().unwrap() = #value; }
+    } else {
+        quote! { client.#field = #value; }
+    }
+}
+
+/// Generate the function encoding basic auth credentials into an `Authorization` header
+///
+/// Only needed to build requests without `reqwest`, i.e. with the `http` or
+/// `ureq` feature, as `reqwest` encodes the credentials itself.
+pub fn generate_basic_auth_header(input: &OpenApiInput) -> TokenStream2 {
+    if input.auth != Some(AuthScheme::Basic) || !(cfg!(feature = "http") || cfg!(feature = "ureq"))
+    {
+        return quote! {};
+    }
+
+    quote! {
+        /// Value of an `Authorization: Basic` header with the given credentials
+        fn basic_auth_header(user: &str, password: &str) -> String {
+            const ALPHABET: &[u8; 64] =
+                b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+            let credentials = format!("{}:{}", user, password);
+            let mut header = String::from("Basic ");
+            for chunk in credentials.as_bytes().chunks(3) {
+                let bytes = [
+                    chunk[0],
+                    chunk.get(1).copied().unwrap_or(0),
+                    chunk.get(2).copied().unwrap_or(0),
+                ];
+                let group = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+                // A chunk of n bytes gives n + 1 characters, padded to 4 with `=`
+                for i in 0..4 {
+                    if i <= chunk.len() {
+                        header.push(ALPHABET[(group >> (18 - 6 * i) & 0x3f) as usize] as char);
+                    } else {
+                        header.push('=');
+                    }
+                }
+            }
+            header
+        }
+    }
+}
+
+/// Generate `clear_auth`, setting the given fields holding credentials to `None`
+///
+/// Clients without credentials don't get the method. With `shared_auth` the
+/// fields are locks shared between clones, so the credentials of all clones
+/// are cleared.
+fn generate_clear_auth(credential_fields: &[Ident], shared: bool) -> TokenStream2 {
+    if credential_fields.is_empty() {
+        return quote! {};
+    }
+
+    if !shared {
+        return quote! {
+            /// Remove the stored credentials, e.g. when the user logs out
+            ///
+            /// Requests are sent without authentication until new credentials are set.
+            pub fn clear_auth(&mut self) {
+                #(self.#credential_fields = None;)*
+            }
+        };
+    }
+
+    quote! {
+        /// Remove the stored credentials, e.g. when the user logs out
+        ///
+        /// The client and all of its clones send requests without
+        /// authentication until new credentials are set.
+        pub fn clear_auth(&self) {
+            #(*self.#credential_fields.write().unwrap() = None;)*
+        }
+    }
+}
+
+/// Generate the `<ClientName>Builder` type configuring a client step by step
+///
+/// Has a setter for every option of the client, and a `builder` constructor
+/// on the client itself.
+fn generate_client_builder(client_name: &Ident, input: &OpenApiInput) -> TokenStream2 {
+    let builder_name = format_ident!("{}Builder", client_name);
+    let builder_doc = format!("Builder for a configured [`{}`]", client_name);
+    let mut fields = vec![quote! { timeout: Option<std::time::Duration>, }];
+    let mut defaults = vec![quote! { timeout: None, }];
+    let mut setters = vec![quote! {
+        /// Fail requests that don't complete within the given duration
+        pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+            self.timeout = Some(timeout);
+            self
+        }
+    }];
+    let mut field_names = vec![format_ident!("timeout")];
+    let mut configure = vec![quote! { client.timeout = self.timeout; }];
+
+    if let Some(auth) = input.auth {
+        let (field, description) = auth_field(auth);
+        let doc = format!("Authenticate every request with the given {}", description);
+        let (params, credentials) = credentials_params(auth);
+        let credentials_type = credentials_type(auth);
+        fields.push(quote! { #field: Option<#credentials_type>, });
+        defaults.push(quote! { #field: None, });
+        setters.push(quote! {
+            #[doc = #doc]
+            pub fn #field(mut self, #params) -> Self {
+                self.#field = Some(#credentials);
+                self
+            }
+        });
+        field_names.push(field.clone());
+        configure.push(store_credentials(input, &field, quote! { self.#field }));
+    }
+
+    if input.retry.is_some() {
+        fields.push(quote! { retry_policy: Option<RetryPolicy>, });
+        defaults.push(quote! { retry_policy: None, });
+        setters.push(quote! {
+            /// Retry failed idempotent requests according to the given policy
+            pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+                self.retry_policy = Some(retry_policy);
+                self
+            }
+        });
+        field_names.push(format_ident!("retry_policy"));
+        configure.push(quote! {
+            if let Some(retry_policy) = self.retry_policy {
+                client.retry_policy = retry_policy;
+            }
+        });
+    }
+
+    quote! {
+        #[doc = #builder_doc]
+        ///
+        /// The base URL is required, while the HTTP client defaults to a new
+        /// client of its type.
+        pub struct #builder_name<C = reqwest::Client> {
+            base_url: Option<String>,
+            client: Option<C>,
+            #(#fields)*
+        }
+
+        impl<C> Default for #builder_name<C> {
+            fn default() -> Self {
+                Self {
+                    base_url: None,
+                    client: None,
+                    #(#defaults)*
+                }
+            }
+        }
+
+        impl<C> #builder_name<C> {
+            /// Send requests to the given base URL
+            pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+                self.base_url = Some(base_url.into());
+                self
+            }
+
+            /// Send requests with the given HTTP client
+            pub fn client<D>(self, client: D) -> #builder_name<D> {
+                #builder_name {
+                    base_url: self.base_url,
+                    client: Some(client),
+                    #(#field_names: self.#field_names,)*
+                }
+            }
+
+            #(#setters)*
+
+            /// Build the configured client sending requests with the given HTTP client
+            ///
+            /// Unlike `build`, this works with HTTP clients without a default,
+            /// like `ClientWithMiddleware`. The client replaces one set with
+            /// `client`. Fails with an `ApiError::Config` error when no base URL
+            /// was set.
+            pub fn build_with<D>(self, http_client: D) -> ApiResult<#client_name<D>> {
+                let base_url = self
+                    .base_url
+                    .ok_or_else(|| ApiError::Config("No base URL set".to_string()))?;
+                let mut client = #client_name::with_client(base_url, http_client);
+                #(#configure)*
+                Ok(client)
+            }
+        }
+
+        impl<C: Default> #builder_name<C> {
+            /// Build the configured client
+            ///
+            /// Sends requests with the client set with `client`, or a new client
+            /// of its type. Fails with an `ApiError::Config` error when no base
+            /// URL was set.
+            pub fn build(mut self) -> ApiResult<#client_name<C>> {
+                let http_client = self.client.take().unwrap_or_default();
+                self.build_with(http_client)
+            }
+        }
+
+        impl #client_name {
+            /// Start building a configured client
+            pub fn builder() -> #builder_name {
+                #builder_name::default()
+            }
+        }
+    }
+}
+
+/// Generate the `trait` option's trait with the operation methods, and its implementations
+///
+/// The signatures and docs are taken from the generated `api_methods`, leaving
+/// out their variants. The trait of async clients uses `async_trait`, so it can
+/// be used as `dyn` trait; the trait of blocking clients is a plain trait.
+fn generate_client_trait(
+    spec: &OpenAPI,
+    trait_name: &Ident,
+    client_types: &[TokenStream2],
+    api_methods: &TokenStream2,
+    is_blocking: bool,
+) -> Result<TokenStream2, String> {
+    let method_names: Vec<Ident> = spec_operations(spec)?
+        .into_iter()
+        .map(|(path, method, op)| client_method_name(path, method, op))
+        .collect();
+    let methods: syn::ItemImpl = syn::parse2(quote! { impl Client { #api_methods } })
+        .map_err(|e| format!("Failed to parse generated methods: {}", e))?;
+
+    let mut declarations = Vec::new();
+    let mut forwards = Vec::new();
+    for item in methods.items {
+        let syn::ImplItem::Fn(method) = item else {
+            continue;
+        };
+        let sig = &method.sig;
+        let name = &sig.ident;
+        if !method_names.contains(name) {
+            continue;
+        }
+
+        let docs = method
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("doc"));
+        let args = sig.inputs.iter().filter_map(|arg| match arg {
+            syn::FnArg::Typed(arg) => Some(&arg.pat),
+            syn::FnArg::Receiver(_) => None,
+        });
+        let call = if is_blocking {
+            quote! { Self::#name(self, #(#args),*) }
+        } else {
+            quote! { Self::#name(self, #(#args),*).await }
+        };
+        declarations.push(quote! {
+            #(#docs)*
+            #sig;
+        });
+        forwards.push(quote! {
+            #sig {
+                #call
+            }
+        });
+    }
+
+    let (async_trait, supertraits) = if is_blocking {
+        (quote! {}, quote! {})
+    } else {
+        (
+            quote! { #[async_trait::async_trait] },
+            quote! { : Send + Sync },
+        )
+    };
+    let impls = client_types.iter().map(|client_type| {
+        quote! {
+            #async_trait
+            impl #trait_name for #client_type {
+                #(#forwards)*
+            }
+        }
+    });
+
+    Ok(quote! {
+        /// Operations of the API, implemented by the client
+        ///
+        /// Code depending on the client can take the trait instead, so tests
+        /// can pass an implementation returning canned responses.
+        #async_trait
+        pub trait #trait_name #supertraits {
+            #(#declarations)*
+        }
+
+        #(#impls)*
+    })
+}
+
+/// Get the client field storing the credentials of an auth scheme, and their description
+fn auth_field(auth: AuthScheme) -> (Ident, &'static str) {
+    match auth {
+        AuthScheme::Bearer => (format_ident!("bearer_token"), "bearer token"),
+        AuthScheme::ApiKey => (format_ident!("api_key"), "API key"),
+        AuthScheme::Basic => (format_ident!("basic_auth"), "basic auth credentials"),
+    }
+}
+
+/// Generate the methods managing the `ETag` cache of the `etag_cache` option
+fn generate_etag_cache_methods(input: &OpenApiInput) -> TokenStream2 {
+    if !input.etag_cache {
+        return quote! {};
+    }
+
+    quote! {
+        /// Remove all responses from the `ETag` cache
+        ///
+        /// The cache is shared between clones of the client.
+        pub fn clear_etag_cache(&self) {
+            self.etag_cache.lock().unwrap().clear();
+        }
+    }
+}
+
+/// Fields added to the client struct by enabled features
+pub struct ClientFields {
+    /// Field definitions for the client struct
+    pub definitions: TokenStream2,
+    /// Field initializers for the constructors
+    pub initializers: TokenStream2,
+    /// Statements adjusting a constructed `client` for deterministic tests
+    pub testing_overrides: TokenStream2,
+    /// Field initializers copying the fields of `self`, another client with the same fields
+    pub copies: TokenStream2,
+}
+
+/// Generate the fields added to the client struct by enabled features
+pub fn generate_client_fields(
+    spec: &OpenAPI,
+    input: &OpenApiInput,
+) -> Result<ClientFields, String> {
+    let mut field_defs = TokenStream2::new();
+    let mut field_inits = TokenStream2::new();
+    let mut testing_overrides = TokenStream2::new();
+    let mut copies = TokenStream2::new();
+
+    // Per-operation rate limiters, shared between clones of the client
+    if cfg!(feature = "rate-limit") {
+        let mut limiter_inserts = Vec::new();
+        for (path, method, operation) in spec_operations(spec)? {
+            if let Some(rate_limit) = operation_rate_limit(operation)? {
+                let operation_id = operation_id_or_default(operation, method, path);
+                let limit = rate_limit.limit;
+                let period_nanos =
+                    u64::try_from(rate_limit.window_secs as u128 * 1_000_000_000 / limit as u128)
+                        .map_err(|_| format!("Rate limit window too large for {}", operation_id))?;
+                limiter_inserts.push(quote! {
+                    limiters.insert(
+                        #operation_id,
+                        governor::RateLimiter::direct(
+                            governor::Quota::with_period(std::time::Duration::from_nanos(#period_nanos))
+                                .expect("rate limit period is non-zero")
+                                .allow_burst(std::num::NonZeroU32::new(#limit).expect("rate limit is non-zero")),
+                        ),
+                    );
+                });
+            }
+        }
+
+        if !limiter_inserts.is_empty() {
+            field_defs.extend(quote! {
+                rate_limiters: std::sync::Arc<HashMap<&'static str, governor::DefaultDirectRateLimiter>>,
+            });
+            copies.extend(quote! {
+                rate_limiters: self.rate_limiters.clone(),
+            });
+            field_inits.extend(quote! {
+                rate_limiters: std::sync::Arc::new({
+                    let mut limiters = HashMap::new();
+                    #(#limiter_inserts)*
+                    limiters
+                }),
+            });
+            testing_overrides.extend(quote! {
+                client.rate_limiters = std::sync::Arc::new(HashMap::new());
+            });
+        }
+    }
+
+    // Timeout of every request, unset by default
+    field_defs.extend(quote! {
+        timeout: Option<std::time::Duration>,
+    });
+    copies.extend(quote! {
+        timeout: self.timeout,
+    });
+    field_inits.extend(quote! {
+        timeout: None,
+    });
+
+    // Credentials attached to every request
+    if let Some(auth) = input.auth {
+        let (field, _) = auth_field(auth);
+        let credentials_type = credentials_type(auth);
+        // Shared credentials are behind a lock, so refreshing them updates all clones
+        if input.shared_auth {
+            field_defs.extend(quote! {
+                #field: std::sync::Arc<std::sync::RwLock<Option<#credentials_type>>>,
+            });
+            field_inits.extend(quote! {
+                #field: std::sync::Arc::new(std::sync::RwLock::new(None)),
+            });
+        } else {
+            field_defs.extend(quote! {
+                #field: Option<#credentials_type>,
+            });
+            field_inits.extend(quote! {
+                #field: None,
+            });
+        }
+        copies.extend(quote! {
+            #field: self.#field.clone(),
+        });
+    } else if input.shared_auth {
+        return Err("The `shared_auth` option requires the `auth` option".to_string());
+    }
+
+    // Bodies of `GET` responses by URL, revalidated with their `ETag`
+    if input.etag_cache {
+        field_defs.extend(quote! {
+            etag_cache: std::sync::Arc<std::sync::Mutex<HashMap<String, (String, Vec<u8>)>>>,
+        });
+        copies.extend(quote! {
+            etag_cache: self.etag_cache.clone(),
+        });
+        field_inits.extend(quote! {
+            etag_cache: std::sync::Arc::default(),
+        });
+    }
+
+    // `GET` requests in flight, not shared with clones of the client
+    if cfg!(feature = "single-flight") {
+        field_defs.extend(quote! {
+            // Unused by blocking clients
+            #[allow(dead_code)]
+            in_flight: InFlight,
+        });
+        copies.extend(quote! {
+            in_flight: InFlight::default(),
+        });
+        field_inits.extend(quote! {
+            in_flight: InFlight::default(),
+        });
+    }
+
+    // Hooks signing the assembled requests, unset by default
+    if !cfg!(feature = "ureq") {
+        field_defs.extend(quote! {
+            request_signer: Option<std::sync::Arc<dyn Fn(&mut reqwest::Request) + Send + Sync>>,
+        });
+        copies.extend(quote! {
+            request_signer: self.request_signer.clone(),
+        });
+        field_inits.extend(quote! {
+            request_signer: None,
+        });
+    }
+    // Blocking requests are a type of their own, so they get a separate hook
+    if cfg!(feature = "blocking") {
+        field_defs.extend(quote! {
+            blocking_request_signer: Option<
+                std::sync::Arc<dyn Fn(&mut reqwest::blocking::Request) + Send + Sync>,
+            >,
+        });
+        copies.extend(quote! {
+            blocking_request_signer: self.blocking_request_signer.clone(),
+        });
+        field_inits.extend(quote! {
+            blocking_request_signer: None,
+        });
+    }
+
+    // Retries of failed idempotent requests, disabled in tests
+    if let Some(max_retries) = input.retry {
+        field_defs.extend(quote! {
+            retry_policy: RetryPolicy,
+        });
+        copies.extend(quote! {
+            retry_policy: self.retry_policy,
+        });
+        field_inits.extend(quote! {
+            retry_policy: RetryPolicy {
+                max_retries: #max_retries,
+                initial_backoff: std::time::Duration::from_millis(100),
+            },
+        });
+        testing_overrides.extend(quote! {
+            client.retry_policy.max_retries = 0;
+        });
+    }
+
+    // Whether 429 responses with a `Retry-After` header are retried
+    if cfg!(feature = "retry") {
+        field_defs.extend(quote! {
+            retry_after: bool,
+        });
+        copies.extend(quote! {
+            retry_after: self.retry_after,
+        });
+        field_inits.extend(quote! {
+            retry_after: true,
+        });
+        testing_overrides.extend(quote! {
+            client.retry_after = false;
+        });
+    }
+
+    Ok(ClientFields {
+        definitions: field_defs,
+        initializers: field_inits,
+        testing_overrides,
+        copies,
     })
 }
+
+/// Generate the `with_retry_policy` method for the `retry` option
+fn generate_retry_policy_method(input: &OpenApiInput) -> TokenStream2 {
+    if input.retry.is_none() {
+        return quote! {};
+    }
+
+    quote! {
+        /// Retry failed idempotent requests according to the given policy
+        pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+            self.retry_policy = retry_policy;
+            self
+        }
+    }
+}
+
+/// Generate the `with_request_signer` method of async or blocking clients
+fn generate_request_signer_method(is_blocking: bool) -> TokenStream2 {
+    let (field, request_type) = if is_blocking {
+        (
+            format_ident!("blocking_request_signer"),
+            quote! { reqwest::blocking::Request },
+        )
+    } else {
+        (format_ident!("request_signer"), quote! { reqwest::Request })
+    };
+
+    quote! {
+        /// Call the given function on every request right before it is sent
+        ///
+        /// The request is fully assembled, so the function can sign its
+        /// method, URL, headers and body, like AWS SigV4 or HMAC signing
+        /// schemes require, and add the signature as a header.
+        pub fn with_request_signer(
+            mut self,
+            signer: impl Fn(&mut #request_type) + Send + Sync + 'static,
+        ) -> Self {
+            self.#field = Some(std::sync::Arc::new(signer));
+            self
+        }
+    }
+}
+
+/// Generate the `with_unix_socket` constructor when the `unix-socket` feature is enabled
+fn generate_unix_socket_constructor(is_blocking: bool) -> TokenStream2 {
+    if !cfg!(feature = "unix-socket") {
+        return quote! {};
+    }
+
+    let client_builder = if is_blocking {
+        quote! { reqwest::blocking::Client::builder() }
+    } else {
+        quote! { reqwest::Client::builder() }
+    };
+
+    quote! {
+        /// Create a new API client sending all requests over a Unix domain socket
+        ///
+        /// The paths of the spec are appended to `base_url` as usual, while
+        /// its host is only used for the `Host` header, e.g. `http://localhost`.
+        #[cfg(unix)]
+        pub fn with_unix_socket(
+            socket_path: impl AsRef<std::path::Path>,
+            base_url: impl Into<String>,
+        ) -> ApiResult<Self> {
+            let client = #client_builder
+                .unix_socket(socket_path.as_ref())
+                .build()?;
+            Ok(Self::with_client(base_url, client))
+        }
+    }
+}
+
+/// Generate the `new_with_default_server` constructor using the first server in the spec
+///
+/// Specs without an absolute server URL get no constructor.
+fn generate_default_server_constructor(spec: &OpenAPI) -> TokenStream2 {
+    let Some(server_url) = default_server_url(spec) else {
+        return quote! {};
+    };
+    let doc = format!(
+        "Create a new API client for the default server `{}`",
+        server_url
+    );
+
+    quote! {
+        #[doc = #doc]
+        pub fn new_with_default_server() -> Self {
+            Self::new(#server_url)
+        }
+    }
+}
+
+/// Generate the `from_env` constructor reading the base URL from the environment
+///
+/// Falls back to the first server in the spec when the variable isn't set.
+fn generate_from_env_constructor(
+    spec: &OpenAPI,
+    input: &OpenApiInput,
+    env: &ClientEnv,
+) -> TokenStream2 {
+    let base_url_env = env.base_url.as_str();
+    let (mut doc, fallback) = match default_server_url(spec) {
+        Some(server_url) => (
+            format!(
+                "Create a new client with the base URL from the `{}` environment variable, falling back to `{}`",
+                base_url_env, server_url
+            ),
+            quote! { Err(_) => #server_url.to_string(), },
+        ),
+        None => (
+            format!(
+                "Create a new client with the base URL from the `{}` environment variable",
+                base_url_env
+            ),
+            quote! {
+                Err(_) => {
+                    return Err(ApiError::Config(format!(
+                        "Environment variable {} with the base URL is not set",
+                        #base_url_env
+                    )));
+                }
+            },
+        ),
+    };
+
+    // Read the credentials from the environment as well, if the client has any
+    let token_env = env.token.as_str();
+    let auth = match input.auth {
+        Some(auth) => {
+            let (field, description) = auth_field(auth);
+            // Basic auth credentials are given like `user:password`, the password being optional
+            let (format, credentials) = match auth {
+                AuthScheme::Basic => (
+                    ", as `user:password`",
+                    quote! {
+                        let (user, password) = token.split_once(':').unwrap_or((&token, ""));
+                        Some((user.to_string(), password.to_string()))
+                    },
+                ),
+                AuthScheme::Bearer | AuthScheme::ApiKey => ("", quote! { Some(token) }),
+            };
+            doc.push_str(&format!(
+                "\n\nThe {} is read from the `{}` environment variable{}, if set.",
+                description, token_env, format
+            ));
+            let store = store_credentials(input, &field, quote! { { #credentials } });
+            quote! {
+                if let Ok(token) = std::env::var(#token_env) {
+                    #store
+                }
+            }
+        }
+        None => quote! {},
+    };
+
+    quote! {
+        #[doc = #doc]
+        pub fn from_env() -> ApiResult<Self> {
+            let base_url = match std::env::var(#base_url_env) {
+                Ok(base_url) => base_url,
+                #fallback
+            };
+            #[allow(unused_mut)]
+            let mut client = Self::new(base_url);
+            #auth
+            Ok(client)
+        }
+    }
+}
+
+/// Generate the typed error body helpers for all operations in the spec
+fn generate_error_body_helpers(spec: &OpenAPI) -> Result<TokenStream2, String> {
+    let mut helpers = TokenStream2::new();
+
+    for (path, method, op) in spec_operations(spec)? {
+        helpers.extend(generate_error_body_helper(spec, path, method, op)?);
+    }
+
+    Ok(helpers)
+}
+
+/// Generate the API methods for all operations in the spec
+fn generate_api_methods(
+    spec: &OpenAPI,
+    input: &OpenApiInput,
+    is_blocking: bool,
+) -> Result<TokenStream2, String> {
+    let mut api_methods = TokenStream2::new();
+
+    // Cursor pagination streams need the `futures` crate, enabled by the `pagination` feature
+    let pagination = match &input.pagination {
+        Some(_) if !cfg!(feature = "pagination") => {
+            return Err("The `pagination` option requires the `pagination` feature".to_string());
+        }
+        Some(_) if input.use_param_structs => {
+            return Err(
+                "The `pagination` option is not supported with `use_param_structs`".to_string(),
+            );
+        }
+        Some(_) if input.operation_errors => {
+            return Err(
+                "The `pagination` option is not supported with `operation_errors`".to_string(),
+            );
+        }
+        Some(_) if input.include_response_headers => {
+            return Err(
+                "The `pagination` option is not supported with `include_response_headers`"
+                    .to_string(),
+            );
+        }
+        pagination => pagination.as_ref(),
+    };
+
+    // Generate methods from paths
+    for (path, method, op) in spec_operations(spec)? {
+        let method_tokens = if is_blocking {
+            generate_blocking_client_method(spec, path, method, op, input)?
+        } else {
+            generate_client_method(spec, path, method, op, input)?
+        };
+        api_methods.extend(method_tokens);
+
+        if let Some(pagination) = pagination {
+            api_methods.extend(generate_pagination_method(
+                spec,
+                path,
+                method,
+                op,
+                pagination,
+                is_blocking,
+            )?);
+        }
+    }
+    api_methods.extend(generate_verify_credentials(spec, input, is_blocking)?);
+
+    Ok(api_methods)
+}
@@ -1,24 +1,69 @@
-use openapiv3::{OpenAPI, Operation};
-use proc_macro2::TokenStream as TokenStream2;
+use openapiv3::{MediaType, OpenAPI, Operation, ReferenceOr};
+use proc_macro2::{Ident, TokenStream as TokenStream2};
 use quote::quote;
 
+use crate::generator::methods::success_response;
+use crate::parser::resolve_example;
+
 /// Generate documentation comment from description text
+///
+/// Every line of the description becomes a line of the comment, so markdown
+/// like lists and code blocks is kept. Only trailing whitespace is trimmed.
+/// Code blocks without a language are marked as `text`, so rustdoc doesn't
+/// run them as doctests.
 pub fn generate_doc_comment(description: Option<&str>) -> TokenStream2 {
-    if let Some(desc) = description {
-        if !desc.trim().is_empty() {
-            let clean_desc = desc
-                .lines()
-                .map(|line| line.trim())
-                .filter(|line| !line.is_empty())
-                .collect::<Vec<_>>()
-                .join(" ");
-
-            return quote! {
-                #[doc = #clean_desc]
+    let Some(desc) = description.filter(|desc| !desc.trim().is_empty()) else {
+        return quote! {};
+    };
+
+    let mut in_code_block = false;
+    let doc_attrs = desc
+        .trim_end()
+        .lines()
+        .map(str::trim_end)
+        .skip_while(|line| line.is_empty())
+        .map(|line| {
+            let fence = line.trim_start();
+            let line = if fence.starts_with("```") || fence.starts_with("~~~") {
+                in_code_block = !in_code_block;
+                if in_code_block && fence[3..].trim().is_empty() {
+                    format!("{}text", line)
+                } else {
+                    line.to_string()
+                }
+            } else {
+                line.to_string()
             };
-        }
+
+            // Lines are separated from `///` by a space, like in written doc comments
+            let line = if line.is_empty() {
+                line
+            } else {
+                format!(" {}", line)
+            };
+            quote! { #[doc = #line] }
+        });
+
+    quote! {
+        #(#doc_attrs)*
     }
-    quote! {}
+}
+
+/// Generate a `doc(alias)` attribute with the spec's name of a renamed item
+///
+/// Lets the item be found by its original name, e.g. `getUserById` for
+/// `get_user_by_id`. Names rustdoc doesn't accept as aliases are left out.
+pub fn generate_doc_alias(original_name: &str, ident: &Ident) -> TokenStream2 {
+    let name = ident.to_string();
+    let name = name.strip_prefix("r#").unwrap_or(&name);
+    let is_valid_alias = !original_name.is_empty()
+        && original_name.trim() == original_name
+        && !original_name.contains(['"', '\'', '\t', '\n', '\r']);
+
+    if original_name == name || !is_valid_alias {
+        return quote! {};
+    }
+    quote! { #[doc(alias = #original_name)] }
 }
 
 /// Generate documentation comment for the API client
@@ -48,29 +93,29 @@ pub fn generate_client_doc_comment(spec: &OpenAPI, client_name: &str) -> TokenSt
     }
 
     // Add contact information if available
-    if let Some(contact) = &spec.info.contact {
-        if let Some(email) = &contact.email {
-            doc_lines.push(format!("**Contact:** {}", email));
-        }
+    if let Some(contact) = &spec.info.contact
+        && let Some(email) = &contact.email
+    {
+        doc_lines.push(format!("**Contact:** {}", email));
     }
 
     // Add license information if available
-    if let Some(license) = &spec.info.license {
-        if !license.name.trim().is_empty() {
-            let license_info = if let Some(url) = &license.url {
-                format!("**License:** [{}]({})", license.name, url)
-            } else {
-                format!("**License:** {}", license.name)
-            };
-            doc_lines.push(license_info);
-        }
+    if let Some(license) = &spec.info.license
+        && !license.name.trim().is_empty()
+    {
+        let license_info = if let Some(url) = &license.url {
+            format!("**License:** [{}]({})", license.name, url)
+        } else {
+            format!("**License:** {}", license.name)
+        };
+        doc_lines.push(license_info);
     }
 
     // Add terms of service if available
-    if let Some(terms) = &spec.info.terms_of_service {
-        if !terms.trim().is_empty() {
-            doc_lines.push(format!("**Terms of Service:** {}", terms));
-        }
+    if let Some(terms) = &spec.info.terms_of_service
+        && !terms.trim().is_empty()
+    {
+        doc_lines.push(format!("**Terms of Service:** {}", terms));
     }
 
     // Add usage example
@@ -97,17 +142,18 @@ pub fn generate_client_doc_comment(spec: &OpenAPI, client_name: &str) -> TokenSt
 
 /// Generate documentation comment for API methods
 pub fn generate_method_doc_comment(
+    spec: &OpenAPI,
     operation: &Operation,
     path: &str,
     http_method: &str,
-) -> TokenStream2 {
+) -> Result<TokenStream2, String> {
     let mut doc_lines = Vec::new();
 
     // Add summary as the first line
-    if let Some(summary) = &operation.summary {
-        if !summary.trim().is_empty() {
-            doc_lines.push(summary.trim().to_string());
-        }
+    if let Some(summary) = &operation.summary
+        && !summary.trim().is_empty()
+    {
+        doc_lines.push(summary.trim().to_string());
     }
 
     // Add description if available and different from summary
@@ -133,8 +179,28 @@ pub fn generate_method_doc_comment(
         doc_lines.push(format!("**Operation ID:** `{}`", operation_id));
     }
 
+    // Add the JSON request and response examples, if the spec has any
+    let request_media_type = match &operation.request_body {
+        Some(ReferenceOr::Item(request_body)) => request_body.content.get("application/json"),
+        _ => None,
+    };
+    let response_media_type = success_response(spec, operation)?
+        .and_then(|response| response.content.get("application/json"));
+    for (title, media_type) in [
+        ("Example request", request_media_type),
+        ("Example response", response_media_type),
+    ] {
+        if let Some(example) = media_type_example(spec, media_type)? {
+            doc_lines.push("".to_string()); // Empty line separator
+            doc_lines.push(format!("**{}:**", title));
+            doc_lines.push("```json".to_string());
+            doc_lines.extend(example.lines().map(str::to_string));
+            doc_lines.push("```".to_string());
+        }
+    }
+
     if doc_lines.is_empty() {
-        return quote! {};
+        return Ok(quote! {});
     }
 
     // Generate doc attributes for each line
@@ -142,7 +208,34 @@ pub fn generate_method_doc_comment(
         quote! { #[doc = #line] }
     });
 
-    quote! {
+    Ok(quote! {
         #(#doc_attrs)*
-    }
+    })
+}
+
+/// Get the pretty-printed example of a media type
+///
+/// Uses the `example` value, or else the first of the `examples`, resolving
+/// references into `components.examples`. Examples with only an
+/// `externalValue` are skipped.
+fn media_type_example(
+    spec: &OpenAPI,
+    media_type: Option<&MediaType>,
+) -> Result<Option<String>, String> {
+    let Some(media_type) = media_type else {
+        return Ok(None);
+    };
+
+    let value = match &media_type.example {
+        Some(value) => Some(value),
+        None => match media_type.examples.values().next() {
+            Some(example_ref) => resolve_example(spec, example_ref)?.value.as_ref(),
+            None => None,
+        },
+    };
+
+    value
+        .map(serde_json::to_string_pretty)
+        .transpose()
+        .map_err(|e| format!("Failed to serialize example: {}", e))
 }
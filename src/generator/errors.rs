@@ -1,8 +1,13 @@
-use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
+use heck::ToPascalCase;
+use openapiv3::OpenAPI;
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
 
 /// Generate error types for the API client
-pub fn generate_error_types() -> TokenStream2 {
+///
+/// Specs with JSON-RPC operations get an error variant for JSON-RPC errors,
+/// and clients with `verify_credentials` one for rejected credentials.
+pub fn generate_error_types(json_rpc: bool, auth_check: bool) -> TokenStream2 {
     let middleware_error = if cfg!(feature = "middleware") {
         quote! {
             /// Middleware error
@@ -13,21 +18,256 @@ pub fn generate_error_types() -> TokenStream2 {
         quote! {}
     };
 
+    let validation_error = if cfg!(feature = "response-validation") {
+        quote! {
+            /// Response did not match the schema declared in the spec
+            #[error("Response validation failed: {}", .0.join("; "))]
+            ResponseValidation(Vec<String>),
+        }
+    } else {
+        quote! {}
+    };
+
+    let cancelled_error = if cfg!(feature = "cancellation") {
+        quote! {
+            /// Request was cancelled through its cancellation token
+            #[error("Request cancelled")]
+            Cancelled,
+        }
+    } else {
+        quote! {}
+    };
+
+    let shared_request_error = if cfg!(feature = "single-flight") {
+        quote! {
+            /// An identical concurrent request failed without an error response
+            #[error("Shared request failed: {0}")]
+            SharedRequest(String),
+        }
+    } else {
+        quote! {}
+    };
+
+    let json_rpc_error = if json_rpc {
+        quote! {
+            /// JSON-RPC method returned an error object
+            #[error("JSON-RPC error {}: {}", .0.code, .0.message)]
+            JsonRpc(JsonRpcError),
+        }
+    } else {
+        quote! {}
+    };
+
+    let (unauthorized_error, unauthorized_status) = if auth_check {
+        (
+            quote! {
+                /// Credentials were rejected by `verify_credentials`
+                #[error("Unauthorized: the credentials were rejected")]
+                Unauthorized,
+            },
+            quote! { ApiError::Unauthorized => Some(401), },
+        )
+    } else {
+        (quote! {}, quote! {})
+    };
+
+    let metrics_kind = if cfg!(feature = "metrics") {
+        let middleware_kind = if cfg!(feature = "middleware") {
+            quote! { ApiError::Middleware(_) => "middleware", }
+        } else {
+            quote! {}
+        };
+        quote! {
+            impl ApiError {
+                /// Status label of a failed request in the request metrics
+                fn metrics_kind(&self) -> &'static str {
+                    match self {
+                        ApiError::Http(e) if e.is_timeout() => "timeout",
+                        ApiError::Http(e) if e.is_connect() => "connect",
+                        #middleware_kind
+                        _ => "error",
+                    }
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // `ureq` reports transport errors separately, boxed as they're large, and reads bodies with `std::io`
+    let http_error = if cfg!(feature = "ureq") {
+        quote! {
+            #[error("HTTP error: {0}")]
+            Http(#[from] Box<ureq::Transport>),
+
+            /// Response body could not be read
+            #[error("I/O error: {0}")]
+            Io(#[from] std::io::Error),
+        }
+    } else {
+        quote! {
+            #[error("HTTP error: {0}")]
+            Http(#[from] reqwest::Error),
+        }
+    };
+
     quote! {
         #[derive(Debug, thiserror::Error)]
         pub enum ApiError {
-            #[error("HTTP error: {0}")]
-            Http(#[from] reqwest::Error),
+            #http_error
 
             #[error("Serialization error: {0}")]
             Serialization(#[from] serde_json::Error),
 
-            #[error("API error {status}: {message}")]
+            #[error("API error {status}: {}", error_body_summary(message))]
             Api { status: u16, message: String },
 
+            /// Error response with a JSON body
+            #[error("API error {status}: {}", error_value_summary(body).unwrap_or_else(|| body.to_string()))]
+            ApiTyped { status: u16, body: serde_json::Value },
+
+            /// Server answered with a redirect that wasn't followed
+            #[error("Redirected with status {status} to {}", location.as_deref().unwrap_or("an unknown location"))]
+            Redirect { status: u16, location: Option<String> },
+
+            /// Client could not be configured, e.g. from the environment
+            #[error("Configuration error: {0}")]
+            Config(String),
+
             #middleware_error
+
+            #validation_error
+
+            #cancelled_error
+
+            #shared_request_error
+
+            #json_rpc_error
+
+            #unauthorized_error
         }
 
+        impl ApiError {
+            /// Create the error of an error response from its status and body
+            ///
+            /// JSON bodies become an `ApiError::ApiTyped`, other bodies are
+            /// kept as the message of an `ApiError::Api`.
+            fn from_error_response(status: u16, body: String) -> Self {
+                match serde_json::from_str(&body) {
+                    Ok(body) => ApiError::ApiTyped { status, body },
+                    Err(_) => ApiError::Api { status, message: body },
+                }
+            }
+
+            /// Status of the error response, if the server answered with one
+            pub fn status(&self) -> Option<u16> {
+                match self {
+                    ApiError::Api { status, .. } | ApiError::ApiTyped { status, .. } => Some(*status),
+                    #unauthorized_status
+                    _ => None,
+                }
+            }
+
+            /// Whether the server answered `404 Not Found`
+            pub fn is_not_found(&self) -> bool {
+                self.status() == Some(404)
+            }
+
+            /// Whether the server answered `401 Unauthorized`
+            pub fn is_unauthorized(&self) -> bool {
+                self.status() == Some(401)
+            }
+
+            /// Whether the server answered with a 5xx status
+            pub fn is_server_error(&self) -> bool {
+                matches!(self.status(), Some(500..=599))
+            }
+
+            /// Deserialize the body of an error response into the given type
+            ///
+            /// Returns `None` for errors without a response body, or when the
+            /// body doesn't match the type.
+            pub fn error_body<T: serde::de::DeserializeOwned>(&self) -> Option<T> {
+                match self {
+                    ApiError::Api { message, .. } => serde_json::from_str(message).ok(),
+                    ApiError::ApiTyped { body, .. } => serde_json::from_value(body.clone()).ok(),
+                    _ => None,
+                }
+            }
+        }
+
+        /// Summarize an error response body for display
+        ///
+        /// Shows the summary of JSON error bodies, and the raw body otherwise.
+        fn error_body_summary(body: &str) -> std::borrow::Cow<'_, str> {
+            serde_json::from_str(body)
+                .ok()
+                .and_then(|body| error_value_summary(&body))
+                .map_or(body.into(), Into::into)
+        }
+
+        /// Summarize a JSON error body for display
+        ///
+        /// Shows the `title` and `detail` of Problem Details bodies, or the
+        /// `message`/`error` of other JSON error bodies.
+        fn error_value_summary(body: &serde_json::Value) -> Option<String> {
+            let fields = body.as_object()?;
+            let field = |name: &str| fields.get(name).and_then(serde_json::Value::as_str);
+
+            match (field("title"), field("detail")) {
+                (Some(title), Some(detail)) => Some(format!("{}: {}", title, detail)),
+                (Some(summary), None) | (None, Some(summary)) => Some(summary.to_string()),
+                (None, None) => ["message", "error_description", "error"]
+                    .into_iter()
+                    .find_map(field)
+                    .map(str::to_string),
+            }
+        }
+
+        #metrics_kind
+
         pub type ApiResult<T> = Result<T, ApiError>;
     }
 }
+
+/// Generate the error returned by the `validate` methods of structs
+///
+/// Only generated for clients with the `validate` option.
+pub fn generate_validation_error_type(spec: &OpenAPI, validate: bool) -> TokenStream2 {
+    if !validate {
+        return quote! {};
+    }
+
+    let error_name = validation_error_name(spec);
+    quote! {
+        /// Field of a struct violating a constraint of the spec
+        #[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+        #[error("{field}: {message}")]
+        pub struct #error_name {
+            /// Name of the field in the spec, with the path to it for nested structs like `items[0].name`
+            pub field: String,
+            /// Constraint the value violates
+            pub message: String,
+        }
+    }
+}
+
+/// Get the name of the error returned by `validate` methods
+///
+/// This is `ValidationError`, unless the spec has a schema generating a type
+/// by that name, as many specs describe their validation errors. The error is
+/// named `SchemaValidationError` then.
+pub fn validation_error_name(spec: &OpenAPI) -> Ident {
+    let taken = spec.components.as_ref().is_some_and(|components| {
+        components
+            .schemas
+            .keys()
+            .any(|name| name.to_pascal_case() == "ValidationError")
+    });
+
+    if taken {
+        format_ident!("SchemaValidationError")
+    } else {
+        format_ident!("ValidationError")
+    }
+}
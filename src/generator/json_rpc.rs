@@ -0,0 +1,59 @@
+use openapiv3::OpenAPI;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+
+use crate::codegen::{operation_json_rpc_method, spec_operations};
+
+/// Check if any operation of the spec is a JSON-RPC method
+pub fn uses_json_rpc(spec: &OpenAPI) -> Result<bool, String> {
+    for (_, _, operation) in spec_operations(spec)? {
+        if operation_json_rpc_method(operation)?.is_some() {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Generate the types wrapping and unwrapping JSON-RPC 2.0 messages
+///
+/// Only generated when an operation declares `x-json-rpc-method`.
+pub fn generate_json_rpc_helpers(json_rpc: bool) -> TokenStream2 {
+    if !json_rpc {
+        return quote! {};
+    }
+
+    quote! {
+        /// Error object of a JSON-RPC response
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct JsonRpcError {
+            pub code: i64,
+            pub message: String,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            pub data: Option<serde_json::Value>,
+        }
+
+        /// Response of a JSON-RPC method, holding either a result or an error
+        #[derive(Deserialize)]
+        struct JsonRpcResponse {
+            #[serde(default)]
+            result: serde_json::Value,
+            error: Option<JsonRpcError>,
+        }
+
+        impl JsonRpcResponse {
+            /// Get the result of the method, or its error
+            fn into_result<T: serde::de::DeserializeOwned>(self) -> ApiResult<T> {
+                match self.error {
+                    Some(error) => Err(ApiError::JsonRpc(error)),
+                    None => Ok(serde_json::from_value(self.result)?),
+                }
+            }
+        }
+
+        /// Get a unique id for a JSON-RPC request
+        fn next_json_rpc_id() -> u64 {
+            static NEXT_ID: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(1);
+            NEXT_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+        }
+    }
+}
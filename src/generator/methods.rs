@@ -1,102 +1,108 @@
 use heck::{ToPascalCase, ToSnakeCase};
-use openapiv3::ReferenceOr;
-use proc_macro2::TokenStream as TokenStream2;
+use openapiv3::{OpenAPI, ReferenceOr};
+use proc_macro2::{Ident, Literal, TokenStream as TokenStream2};
 use quote::{format_ident, quote};
 
 use crate::codegen::{
-    ParameterLocation, generate_url_building, process_parameter, reference_or_schema_to_rust_type,
+    ParameterInfo, ParameterLocation, binary_request_body, generate_cookie_params,
+    generate_header_params, generate_multipart_form, generate_url_building, multipart_body,
+    multipart_body_struct_name, operation_id_or_default, operation_json_rpc_method,
+    operation_parameters, operation_rate_limit, operation_success_codes,
+    reference_or_schema_to_rust_type, request_body_type,
+};
+use crate::generator::client::stored_credentials;
+use crate::generator::docs::{generate_doc_alias, generate_method_doc_comment};
+use crate::generator::operation_errors::operation_error_name;
+use crate::generator::response_headers::{
+    response_headers, response_headers_struct_name, wrap_api_response,
+};
+use crate::parser::{
+    ApiKeyLocation, ApiKeyScheme, AuthScheme, OpenApiInput, api_key_scheme, resolve_response,
 };
-use crate::generator::docs::generate_method_doc_comment;
 use crate::utils::create_rust_safe_ident;
 
 /// Generate a single API method from an OpenAPI operation
 pub fn generate_client_method(
+    spec: &OpenAPI,
     path: &str,
     http_method: &str,
     operation: &openapiv3::Operation,
-    use_param_structs: bool,
+    input: &OpenApiInput,
 ) -> Result<TokenStream2, String> {
-    generate_client_method_with_mode(path, http_method, operation, false, use_param_structs)
+    generate_client_method_with_mode(spec, path, http_method, operation, false, input)
 }
 
 /// Generate a blocking API method from an OpenAPI operation
 pub fn generate_blocking_client_method(
+    spec: &OpenAPI,
     path: &str,
     http_method: &str,
     operation: &openapiv3::Operation,
-    use_param_structs: bool,
+    input: &OpenApiInput,
 ) -> Result<TokenStream2, String> {
-    generate_client_method_with_mode(path, http_method, operation, true, use_param_structs)
+    generate_client_method_with_mode(spec, path, http_method, operation, true, input)
+}
+
+/// Generate a helper parsing the typed error body of an operation
+///
+/// Only generated when the operation's error responses share a component
+/// schema. The helper is an associated function, so it is generated once per
+/// client rather than for every HTTP client implementation.
+pub fn generate_error_body_helper(
+    spec: &OpenAPI,
+    path: &str,
+    http_method: &str,
+    operation: &openapiv3::Operation,
+) -> Result<TokenStream2, String> {
+    let Some(error_type) = error_response_type(spec, operation)? else {
+        return Ok(quote! {});
+    };
+
+    let method_name = client_method_name(path, http_method, operation);
+    let helper_name = format_ident!("{}_error", method_name);
+    let helper_doc = format!(
+        "Parse the typed error body of a failed [`Self::{}`] call",
+        method_name
+    );
+
+    Ok(quote! {
+        #[doc = #helper_doc]
+        pub fn #helper_name(error: &ApiError) -> Option<#error_type> {
+            error.error_body()
+        }
+    })
 }
 
 /// Generate a single API method from an OpenAPI operation with async/blocking mode
 fn generate_client_method_with_mode(
+    spec: &OpenAPI,
     path: &str,
     http_method: &str,
     operation: &openapiv3::Operation,
     is_blocking: bool,
-    use_param_structs: bool,
+    input: &OpenApiInput,
 ) -> Result<TokenStream2, String> {
-    let method_name = operation
-        .operation_id
-        .as_ref()
-        .map(|id| {
-            let snake_case_id = id.to_snake_case();
-            create_rust_safe_ident(&snake_case_id)
-        })
-        .unwrap_or_else(|| {
-            let clean_path = path
-                .replace(['{', '}', '/'], "_")
-                .trim_matches('_')
-                .to_string();
-            let method_name = format!("{}_{}", http_method, clean_path);
-            create_rust_safe_ident(&method_name)
-        });
+    let use_param_structs = input.use_param_structs;
+    let method_name = client_method_name(path, http_method, operation);
+
+    // Methods sending the request return the operation's error enum in `operation_errors` mode
+    let error_type = input.operation_errors.then(|| {
+        let operation_id = operation_id_or_default(operation, http_method, path);
+        format_ident!("{}", operation_error_name(&operation_id))
+    });
 
     let http_method_upper = http_method.to_uppercase();
     let http_method_ident = format_ident!("{}", http_method_upper);
 
-    // Process all parameters
-    let mut all_params = Vec::new();
-
-    for param_ref in &operation.parameters {
-        let param = match param_ref {
-            ReferenceOr::Reference { reference } => {
-                return Err(format!("Parameter references not supported: {}", reference));
-            }
-            ReferenceOr::Item(item) => item,
-        };
-
-        let (param_name, param_schema, location, required) = match param {
-            openapiv3::Parameter::Query { parameter_data, .. } => (
-                &parameter_data.name,
-                &parameter_data.format,
-                ParameterLocation::Query,
-                parameter_data.required,
-            ),
-            openapiv3::Parameter::Path { parameter_data, .. } => (
-                &parameter_data.name,
-                &parameter_data.format,
-                ParameterLocation::Path,
-                parameter_data.required,
-            ),
-            openapiv3::Parameter::Header { parameter_data, .. } => (
-                &parameter_data.name,
-                &parameter_data.format,
-                ParameterLocation::Header,
-                parameter_data.required,
-            ),
-            openapiv3::Parameter::Cookie { parameter_data, .. } => (
-                &parameter_data.name,
-                &parameter_data.format,
-                ParameterLocation::Cookie,
-                parameter_data.required,
-            ),
-        };
+    // JSON-RPC methods are posted to the path without its fragment, which only tells them apart
+    let json_rpc_method = operation_json_rpc_method(operation)?;
+    let url_path = match json_rpc_method {
+        Some(_) => path.split('#').next().unwrap_or(path),
+        None => path,
+    };
 
-        let param_info = process_parameter(param_name, param_schema, location, required)?;
-        all_params.push(param_info);
-    }
+    // Process all parameters
+    let all_params = operation_parameters(operation)?;
 
     // Separate parameters by location
     let path_params: Vec<_> = all_params
@@ -107,71 +113,29 @@ fn generate_client_method_with_mode(
         .iter()
         .filter(|p| p.location == ParameterLocation::Query)
         .collect();
+    let header_params: Vec<_> = all_params
+        .iter()
+        .filter(|p| p.location == ParameterLocation::Header)
+        .collect();
+    let cookie_params: Vec<_> = all_params
+        .iter()
+        .filter(|p| p.location == ParameterLocation::Cookie)
+        .collect();
 
     // Generate parameter list for function signature
-    let (params, param_access_code) = if use_param_structs {
-        // Use parameter struct approach
-        let method_params: Vec<_> = all_params
-            .iter()
-            .filter(|p| {
-                p.location == ParameterLocation::Path || p.location == ParameterLocation::Query
-            })
-            .collect();
-
-        if method_params.is_empty() {
-            // No parameters - keep empty signature
-            (quote! {}, quote! {})
-        } else {
-            // Generate parameter struct name
-            let operation_id = operation
-                .operation_id
-                .as_ref()
-                .cloned()
-                .unwrap_or_else(|| generate_operation_id_for_struct(http_method, path));
-            let struct_name = format_ident!("{}Params", operation_id.to_pascal_case());
-
-            // Method signature uses parameter struct
-            let params = quote! { params: #struct_name, };
-
-            // Code to extract values from parameter struct
-            let param_extractions = method_params.iter().map(|param| {
-                let field_name = &param.ident;
-                let var_name = format_ident!("{}_value", field_name);
-                quote! {
-                    let #var_name = params.#field_name;
-                }
-            });
-
-            let param_access_code = quote! {
-                #(#param_extractions)*
-            };
-
-            (params, param_access_code)
-        }
-    } else {
-        // Use individual parameters approach (existing behavior)
-        let params = all_params
-            .iter()
-            .filter(|p| {
-                p.location == ParameterLocation::Path || p.location == ParameterLocation::Query
-            })
-            .map(|param| {
-                let param_ident = &param.ident;
-                let param_type = &param.param_type;
-                quote! { #param_ident: #param_type, }
-            });
-        (quote! { #(#params)* }, quote! {})
-    };
+    let (params, param_access_code) =
+        generate_method_params(path, http_method, operation, &all_params, use_param_structs);
 
     // Generate URL building code
     let url_building = if use_param_structs {
-        generate_url_building_with_param_structs(path, &path_params, &query_params)
+        generate_url_building_with_param_structs(url_path, &path_params, &query_params)
     } else {
-        generate_url_building(path, &path_params, &query_params)
+        generate_url_building(url_path, &path_params, &query_params)
     };
 
     // Handle request body
     let mut body_param = TokenStream2::new();
+    let mut json_rpc_envelope = TokenStream2::new();
     let mut request_building = quote! {
         let parsed_url = reqwest::Url::parse(&url).map_err(|e| ApiError::Api {
             status: 400,
@@ -179,163 +143,1043 @@ fn generate_client_method_with_mode(
         })?;
         let mut request = self.client.request(reqwest::Method::#http_method_ident, parsed_url);
     };
+    request_building.extend(request_auth(spec, input)?);
+    request_building.extend(quote! {
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+    });
+    request_building.extend(generate_header_params(
+        &header_params,
+        use_param_structs,
+        |name, value| quote! { request = request.header(#name, #value); },
+    ));
+    request_building.extend(generate_cookie_params(
+        &cookie_params,
+        use_param_structs,
+        |name, value| quote! { request = request.header(#name, #value); },
+    ));
+    let base_request_building = request_building.clone();
 
-    if operation.request_body.is_some() {
-        body_param.extend(quote! { body: serde_json::Value, });
+    if let Some(rpc_method) = &json_rpc_method {
+        // The request body, if any, is sent as the params of the method
+        let params = if operation.request_body.is_some() {
+            let operation_id = operation_id_or_default(operation, http_method, path);
+            let body_type = request_body_type(operation, &operation_id)?;
+            body_param.extend(quote! { body: #body_type, });
+            quote! { "params": body, }
+        } else {
+            quote! {}
+        };
+        json_rpc_envelope.extend(quote! {
+            let body = serde_json::json!({
+                "jsonrpc": "2.0",
+                "method": #rpc_method,
+                #params
+                "id": next_json_rpc_id(),
+            });
+        });
+        request_building.extend(quote! {
+            #json_rpc_envelope
+            request = request.json(&body);
+        });
+    } else if cfg!(feature = "multipart") && multipart_body(operation).is_some() {
+        let operation_id = operation_id_or_default(operation, http_method, path);
+        let body_type = format_ident!("{}", multipart_body_struct_name(&operation_id));
+        body_param.extend(quote! { body: #body_type, });
+        request_building.extend(generate_multipart_form(operation, is_blocking)?);
+    } else if operation.request_body.is_some() {
+        let operation_id = operation_id_or_default(operation, http_method, path);
+        let body_type = request_body_type(operation, &operation_id)?;
+        body_param.extend(quote! { body: #body_type, });
         request_building.extend(quote! {
             request = request.json(&body);
         });
     }
 
     // Determine return type and content type
-    let (return_type, content_type) = determine_return_type_from_operation(operation)
-        .unwrap_or_else(|| (quote! { () }, "application/json".to_string()));
+    let (return_type, content_type) = determine_return_type_from_operation(spec, operation)?
+        .unwrap_or_else(|| (quote! { () }, String::new()));
 
     // Generate documentation
-    let doc_comment = generate_method_doc_comment(operation, path, http_method);
+    let doc_comment = generate_method_doc_comment(spec, operation, path, http_method)?;
+    let doc_alias = match &operation.operation_id {
+        Some(operation_id) => generate_doc_alias(operation_id, &method_name),
+        None => quote! {},
+    };
+
+    // Deserialize JSON responses with `simd-json` if the feature is enabled
+    let json_parse = match (cfg!(feature = "simd-json"), is_blocking) {
+        (true, true) => quote! {{
+            let mut bytes = response.bytes()?.to_vec();
+            simd_json::serde::from_slice(&mut bytes)
+                .map_err(<serde_json::Error as serde::de::Error>::custom)?
+        }},
+        (true, false) => quote! {{
+            let mut bytes = response.bytes().await?.to_vec();
+            simd_json::serde::from_slice(&mut bytes)
+                .map_err(<serde_json::Error as serde::de::Error>::custom)?
+        }},
+        (false, true) => quote! { response.json()? },
+        (false, false) => quote! { response.json().await? },
+    };
+
+    // Only the operation's success codes are parsed as the return value
+    let is_success = match operation_success_codes(operation)? {
+        Some(codes) => {
+            let codes = codes.into_iter().map(Literal::u16_unsuffixed);
+            quote! { matches!(response.status().as_u16(), #(#codes)|*) }
+        }
+        None => quote! { response.status().is_success() },
+    };
+    let error_response = error_response(is_blocking);
 
     // Generate response parsing based on content type
-    let response_parsing = if content_type.starts_with("text/") {
+    let response_parsing = if json_rpc_method.is_some() {
+        // Unwrap the result of the method from the JSON-RPC response, whatever its content type
+        quote! {
+            if #is_success {
+                let response: JsonRpcResponse = #json_parse;
+                response.into_result()
+            } else {
+                #error_response
+            }
+        }
+    } else if content_type.is_empty() {
+        // Responses without content, like `204 No Content`, have no body to parse
+        quote! {
+            if #is_success {
+                Ok(())
+            } else {
+                #error_response
+            }
+        }
+    } else if content_type.starts_with("text/") {
         if is_blocking {
             quote! {
-                if response.status().is_success() {
+                if #is_success {
                     let result: String = response.text()?;
                     Ok(result)
                 } else {
-                    Err(ApiError::Api {
-                        status: response.status().as_u16(),
-                        message: response.text().unwrap_or_else(|_| "Unknown error".to_string()),
-                    })
+                    #error_response
                 }
             }
         } else {
             quote! {
-                if response.status().is_success() {
+                if #is_success {
                     let result: String = response.text().await?;
                     Ok(result)
                 } else {
-                    Err(ApiError::Api {
-                        status: response.status().as_u16(),
-                        message: response.text().await.unwrap_or_else(|_| "Unknown error".to_string()),
-                    })
+                    #error_response
                 }
             }
         }
     } else {
-        if is_blocking {
+        quote! {
+            if #is_success {
+                let result = #json_parse;
+                Ok(result)
+            } else {
+                #error_response
+            }
+        }
+    };
+
+    // Wait for the operation's rate limiter before sending, if the spec declares one
+    let throttle = match operation_rate_limit(operation)? {
+        Some(_) if cfg!(feature = "rate-limit") => {
+            let operation_id = operation_id_or_default(operation, http_method, path);
+            if is_blocking {
+                quote! {
+                    if let Some(limiter) = self.rate_limiters.get(#operation_id) {
+                        let clock = governor::clock::DefaultClock::default();
+                        while let Err(not_until) = limiter.check() {
+                            std::thread::sleep(not_until.wait_time_from(governor::clock::Clock::now(&clock)));
+                        }
+                    }
+                }
+            } else {
+                quote! {
+                    if let Some(limiter) = self.rate_limiters.get(#operation_id) {
+                        limiter.until_ready().await;
+                    }
+                }
+            }
+        }
+        _ => quote! {},
+    };
+
+    // Retry idempotent requests once when the server answers 429 with a `Retry-After` header
+    let is_idempotent = matches!(
+        http_method,
+        "get" | "head" | "put" | "delete" | "options" | "trace"
+    );
+    let retry_after = if cfg!(feature = "retry") && is_idempotent {
+        let (sleep, resend) = if is_blocking {
+            (
+                quote! { std::thread::sleep(delay); },
+                quote! { self.send_request(retry_request)? },
+            )
+        } else {
+            (
+                quote! { tokio::time::sleep(delay).await; },
+                quote! { self.send_request(retry_request).await? },
+            )
+        };
+        quote! {
+            let response = match retry_request {
+                Some(retry_request)
+                    if self.retry_after
+                        && response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS =>
+                {
+                    match retry_after_delay(response.headers()) {
+                        Some(delay) => {
+                            #sleep
+                            #resend
+                        }
+                        None => response,
+                    }
+                }
+                _ => response,
+            };
+        }
+    } else {
+        quote! {}
+    };
+    let clone_request = if retry_after.is_empty() {
+        quote! {}
+    } else {
+        quote! { let retry_request = request.try_clone(); }
+    };
+
+    let send = if is_blocking {
+        quote! { self.send_request(request) }
+    } else {
+        quote! { self.send_request(request).await }
+    };
+
+    // Record the duration and outcome of the request if the feature is enabled
+    let send = if cfg!(feature = "metrics") {
+        let operation_id = operation_id_or_default(operation, http_method, path);
+        quote! {
+            {
+                let started = std::time::Instant::now();
+                let result = #send;
+                let status = match &result {
+                    Ok(response) => response.status().as_str().to_string(),
+                    Err(e) => e.metrics_kind().to_string(),
+                };
+                metrics::histogram!("api_request_duration", "operation" => #operation_id)
+                    .record(started.elapsed().as_secs_f64());
+                metrics::counter!("api_requests_total", "operation" => #operation_id, "status" => status)
+                    .increment(1);
+                result
+            }
+        }
+    } else {
+        send
+    };
+
+    let send_call = quote! {
+        #throttle
+        #clone_request
+        let response = #send?;
+        #retry_after
+    };
+
+    // Revalidate cached `GET` responses with `If-None-Match`, reusing the cached body on 304
+    let (cache_lookup, main_parsing) = if input.etag_cache
+        && http_method == "get"
+        && json_rpc_method.is_none()
+        && json_response_schema(spec, operation)?.is_some()
+    {
+        let read_body = if is_blocking {
+            quote! { response.bytes()?.to_vec() }
+        } else {
+            quote! { response.bytes().await?.to_vec() }
+        };
+        let parse_body = if cfg!(feature = "simd-json") {
             quote! {
-                if response.status().is_success() {
-                    let result = response.json()?;
+                simd_json::serde::from_slice(&mut body.clone())
+                    .map_err(<serde_json::Error as serde::de::Error>::custom)?
+            }
+        } else {
+            quote! { serde_json::from_slice(&body)? }
+        };
+
+        (
+            quote! {
+                let cached = self.etag_cache.lock().unwrap().get(&url).cloned();
+                if let Some((etag, _)) = &cached {
+                    request = request.header(reqwest::header::IF_NONE_MATCH, etag.as_str());
+                }
+            },
+            quote! {
+                if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+                    if let Some((_, body)) = cached {
+                        let result = #parse_body;
+                        return Ok(result);
+                    }
+                }
+
+                if #is_success {
+                    let etag = response
+                        .headers()
+                        .get(reqwest::header::ETAG)
+                        .and_then(|etag| etag.to_str().ok())
+                        .map(str::to_string);
+                    let body = #read_body;
+                    let result = #parse_body;
+                    let mut cache = self.etag_cache.lock().unwrap();
+                    match etag {
+                        Some(etag) => {
+                            cache.insert(url, (etag, body));
+                        }
+                        None => {
+                            cache.remove(&url);
+                        }
+                    }
                     Ok(result)
                 } else {
-                    Err(ApiError::Api {
-                        status: response.status().as_u16(),
-                        message: response.text().unwrap_or_else(|_| "Unknown error".to_string()),
-                    })
+                    if matches!(response.status().as_u16(), 404 | 410) {
+                        self.etag_cache.lock().unwrap().remove(&url);
+                    }
+                    #error_response
+                }
+            },
+        )
+    } else {
+        (quote! {}, response_parsing.clone())
+    };
+
+    // Generate a schema-validating variant for JSON responses if the feature is enabled
+    let validated_method = match json_response_schema(spec, operation)? {
+        Some(schema_ref) if cfg!(feature = "response-validation") && json_rpc_method.is_none() => {
+            let schema_json = serde_json::to_string(schema_ref)
+                .map_err(|e| format!("Failed to serialize response schema: {}", e))?;
+            let validated_name = format_ident!("{}_validated", method_name);
+            let validated_doc = format!(
+                "Same as [`Self::{}`], but validates the response against the spec schema before deserializing",
+                method_name
+            );
+
+            let validated_async = if is_blocking {
+                quote! {}
+            } else {
+                quote! { async }
+            };
+            let (validated_type, validated_parsing) = wrap_api_response(
+                input,
+                &return_type,
+                quote! {
+                    if #is_success {
+                        let value: serde_json::Value = #json_parse;
+                        validate_response_schema(&value, #schema_json)
+                            .map_err(ApiError::ResponseValidation)?;
+                        let result = serde_json::from_value(value)?;
+                        Ok(result)
+                    } else {
+                        #error_response
+                    }
+                },
+                is_blocking,
+            );
+            let (validated_result, validated_body) = sending_method_body(
+                &validated_type,
+                quote! {
+                    #param_access_code
+                    #url_building
+                    #request_building
+
+                    #send_call
+
+                    #validated_parsing
+                },
+                error_type.as_ref(),
+                is_blocking,
+            );
+
+            quote! {
+                #[doc = #validated_doc]
+                pub #validated_async fn #validated_name(&self, #params #body_param) -> #validated_result {
+                    #validated_body
                 }
             }
+        }
+        _ => quote! {},
+    };
+
+    // Generate a variant also returning the documented response headers
+    let headers_method = if response_headers(spec, operation)?.is_empty() {
+        quote! {}
+    } else {
+        let operation_id = operation_id_or_default(operation, http_method, path);
+        let headers_type = format_ident!("{}", response_headers_struct_name(&operation_id));
+        let headers_name = format_ident!("{}_with_headers", method_name);
+        let headers_doc = format!(
+            "Same as [`Self::{}`], but also returns the documented response headers",
+            method_name
+        );
+        let headers_async = if is_blocking {
+            quote! {}
         } else {
+            quote! { async }
+        };
+        let (headers_result, headers_body) = sending_method_body(
+            &quote! { (#return_type, #headers_type) },
             quote! {
-                if response.status().is_success() {
-                    let result = response.json().await?;
-                    Ok(result)
+                #param_access_code
+                #url_building
+                #request_building
+
+                #send_call
+
+                let headers = #headers_type::from_headers(response.headers());
+                let body: ApiResult<#return_type> = #response_parsing;
+                Ok((body?, headers))
+            },
+            error_type.as_ref(),
+            is_blocking,
+        );
+
+        quote! {
+            #[doc = #headers_doc]
+            pub #headers_async fn #headers_name(&self, #params #body_param) -> #headers_result {
+                #headers_body
+            }
+        }
+    };
+
+    // Generate a variant returning the raw body, for responses that don't parse, if requested
+    let text_method = if input.text_methods {
+        let text_name = format_ident!("{}_text", method_name);
+        let text_doc = format!(
+            "Same as [`Self::{}`], but returns the raw body of the response instead of parsing it",
+            method_name
+        );
+        let (text_async, read_text) = if is_blocking {
+            (quote! {}, quote! { response.text()? })
+        } else {
+            (quote! { async }, quote! { response.text().await? })
+        };
+        let (text_result, text_body) = sending_method_body(
+            &quote! { String },
+            quote! {
+                #param_access_code
+                #url_building
+                #request_building
+
+                #send_call
+
+                if #is_success {
+                    let body: String = #read_text;
+                    Ok(body)
                 } else {
-                    Err(ApiError::Api {
-                        status: response.status().as_u16(),
-                        message: response.text().await.unwrap_or_else(|_| "Unknown error".to_string()),
+                    #error_response
+                }
+            },
+            error_type.as_ref(),
+            is_blocking,
+        );
+
+        quote! {
+            #[doc = #text_doc]
+            pub #text_async fn #text_name(&self, #params #body_param) -> #text_result {
+                #text_body
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // Generate a variant building an `http::Request` for custom transports if the feature is enabled
+    let http_request_method = if cfg!(feature = "http")
+        && !(cfg!(feature = "multipart") && multipart_body(operation).is_some())
+    {
+        let http_request_name = format_ident!("{}_http_request", method_name);
+        let http_request_doc = format!(
+            "Build the request of [`Self::{}`] as an `http::Request`, for sending it with another HTTP stack",
+            method_name
+        );
+        let has_body = !body_param.is_empty() || json_rpc_method.is_some();
+        let http_body = if has_body {
+            quote! { serde_json::to_vec(&body)? }
+        } else {
+            quote! { Vec::new() }
+        };
+        let content_type = if !has_body {
+            quote! {}
+        } else {
+            quote! { .header(http::header::CONTENT_TYPE, "application/json") }
+        };
+
+        let set_header = |name: &str, value| quote! { builder = builder.header(#name, #value); };
+        let mut http_headers =
+            generate_header_params(&header_params, use_param_structs, set_header);
+        http_headers.extend(generate_cookie_params(
+            &cookie_params,
+            use_param_structs,
+            set_header,
+        ));
+        let credentials = stored_credentials(input);
+        let (http_auth_url, http_auth) = match input.auth {
+            Some(AuthScheme::Bearer) => (
+                quote! {},
+                quote! {
+                    if let Some(token) = #credentials {
+                        builder = builder.header(http::header::AUTHORIZATION, format!("Bearer {}", token));
+                    }
+                },
+            ),
+            Some(AuthScheme::ApiKey) => match api_key_scheme(spec)? {
+                ApiKeyScheme {
+                    name,
+                    location: ApiKeyLocation::Header,
+                } => (
+                    quote! {},
+                    quote! {
+                        if let Some(key) = #credentials {
+                            builder = builder.header(#name, key);
+                        }
+                    },
+                ),
+                ApiKeyScheme {
+                    name,
+                    location: ApiKeyLocation::Query,
+                } => (
+                    quote! {
+                        let url = match #credentials {
+                            Some(key) => {
+                                let mut parsed_url = reqwest::Url::parse(&url).map_err(|e| ApiError::Api {
+                                    status: 400,
+                                    message: format!("Invalid URL: {}", e)
+                                })?;
+                                parsed_url.query_pairs_mut().append_pair(#name, key);
+                                parsed_url.to_string()
+                            }
+                            None => url,
+                        };
+                    },
+                    quote! {},
+                ),
+            },
+            Some(AuthScheme::Basic) => (
+                quote! {},
+                quote! {
+                    if let Some((user, password)) = #credentials {
+                        builder = builder.header(http::header::AUTHORIZATION, basic_auth_header(user, password));
+                    }
+                },
+            ),
+            None => (quote! {}, quote! {}),
+        };
+
+        quote! {
+            #[doc = #http_request_doc]
+            pub fn #http_request_name(&self, #params #body_param) -> ApiResult<http::Request<Vec<u8>>> {
+                #param_access_code
+                #url_building
+                #http_auth_url
+                #json_rpc_envelope
+                #[allow(unused_mut)]
+                let mut builder = http::Request::builder()
+                    .method(http::Method::#http_method_ident)
+                    .uri(url)
+                    #content_type;
+                #http_auth
+                #http_headers
+                builder
+                    .body(#http_body)
+                    .map_err(|e| ApiError::Api {
+                        status: 400,
+                        message: format!("Invalid request: {}", e)
                     })
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // Generate a variant streaming binary request bodies if the feature is enabled
+    let stream_method = match binary_request_body(operation) {
+        Some(content_type) if cfg!(feature = "stream-upload") && !is_blocking => {
+            let stream_name = format_ident!("{}_stream", method_name);
+            let stream_doc = format!(
+                "Same as [`Self::{}`], but streams the `{}` request body instead of buffering it",
+                method_name, content_type
+            );
+
+            let (stream_type, stream_parsing) =
+                wrap_api_response(input, &return_type, response_parsing.clone(), is_blocking);
+            let (stream_result, stream_body) = sending_method_body(
+                &stream_type,
+                quote! {
+                    #param_access_code
+                    #url_building
+                    #base_request_building
+                    request = request
+                        .header(reqwest::header::CONTENT_TYPE, #content_type)
+                        .body(reqwest::Body::wrap_stream(body));
+
+                    #send_call
+
+                    #stream_parsing
+                },
+                error_type.as_ref(),
+                is_blocking,
+            );
+
+            quote! {
+                #[doc = #stream_doc]
+                pub async fn #stream_name<S, E>(&self, #params body: S) -> #stream_result
+                where
+                    S: futures::Stream<Item = Result<bytes::Bytes, E>> + Send + 'static,
+                    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+                {
+                    #stream_body
                 }
             }
         }
+        _ => quote! {},
     };
 
-    let (signature, send_call) = if is_blocking {
-        (
-            quote! { pub fn #method_name(&self, #params #body_param) -> ApiResult<#return_type> },
-            quote! { let response = Self::send_request(request)?; },
-        )
+    let (method_type, main_parsing) =
+        wrap_api_response(input, &return_type, main_parsing, is_blocking);
+
+    // Generate a variant racing the request against a cancellation token if the feature is enabled
+    let cancellable_method = if cfg!(feature = "cancellation") && !is_blocking {
+        let cancellable_name = format_ident!("{}_cancellable", method_name);
+        let cancellable_doc = format!(
+            "Same as [`Self::{}`], but returns [`ApiError::Cancelled`] as soon as `token` is cancelled",
+            method_name
+        );
+
+        let mut args: Vec<TokenStream2> = all_params
+            .iter()
+            .map(|param| {
+                let param_ident = &param.ident;
+                quote! { #param_ident }
+            })
+            .collect();
+        if use_param_structs && !args.is_empty() {
+            args = vec![quote! { params }];
+        }
+        if !body_param.is_empty() {
+            args.push(quote! { body });
+        }
+
+        let cancellable_result = match &error_type {
+            Some(error_type) => quote! { Result<#method_type, #error_type> },
+            None => quote! { ApiResult<#method_type> },
+        };
+
+        quote! {
+            #[doc = #cancellable_doc]
+            pub async fn #cancellable_name(
+                &self,
+                #params
+                #body_param
+                token: tokio_util::sync::CancellationToken,
+            ) -> #cancellable_result {
+                tokio::select! {
+                    biased;
+                    _ = token.cancelled() => Err(ApiError::Cancelled.into()),
+                    result = self.#method_name(#(#args),*) => result,
+                }
+            }
+        }
     } else {
-        (
-            quote! { pub async fn #method_name(&self, #params #body_param) -> ApiResult<#return_type> },
-            quote! { let response = Self::send_request(request).await?; },
-        )
+        quote! {}
     };
 
-    Ok(quote! {
-        #doc_comment
-        #signature {
+    let method_async = if is_blocking {
+        quote! {}
+    } else {
+        quote! { async }
+    };
+    let method_body = quote! {
+        #request_building
+        #cache_lookup
+
+        #send_call
+
+        #main_parsing
+    };
+    // Share the result of concurrent identical `GET` requests if the feature is enabled
+    let method_body = if cfg!(feature = "single-flight")
+        && !is_blocking
+        && http_method == "get"
+        && json_rpc_method.is_none()
+    {
+        // Requests are only identical if they are sent with the same headers and credentials
+        let key_headers = generate_header_params(
+            &header_params,
+            use_param_structs,
+            |name, value| quote! { key.push_str(&format!("\n{}: {}", #name, #value)); },
+        );
+        let key_cookies = generate_cookie_params(
+            &cookie_params,
+            use_param_structs,
+            |name, value| quote! { key.push_str(&format!("\n{}: {}", #name, #value)); },
+        );
+        let key_credentials = if input.auth.is_some() {
+            let credentials = stored_credentials(input);
+            quote! {
+                if let Some(credentials) = #credentials {
+                    key.push_str(&format!("\nauth: {:?}", credentials));
+                }
+            }
+        } else {
+            quote! {}
+        };
+        quote! {
+            let key = {
+                let mut key = format!("GET {}", url);
+                #key_headers
+                #key_cookies
+                #key_credentials
+                key
+            };
+            let flight = match join_flight::<#method_type>(&self.in_flight, key) {
+                Flight::Wait(receiver) => match receiver.await {
+                    Ok(result) => return result,
+                    // The leading call was dropped, so send the request ourselves
+                    Err(_) => None,
+                },
+                Flight::Lead(guard) => Some(guard),
+                Flight::Alone => None,
+            };
+
+            let result: ApiResult<#method_type> = async { #method_body }.await;
+            if let Some(guard) = flight {
+                guard.land(&result);
+            }
+            result
+        }
+    } else {
+        method_body
+    };
+    let (method_result, method_body) = sending_method_body(
+        &method_type,
+        quote! {
             #param_access_code
             #url_building
-            #request_building
-
-            #send_call
+            #method_body
+        },
+        error_type.as_ref(),
+        is_blocking,
+    );
 
-            #response_parsing
+    Ok(quote! {
+        #doc_comment
+        #doc_alias
+        pub #method_async fn #method_name(&self, #params #body_param) -> #method_result {
+            #method_body
         }
+
+        #validated_method
+
+        #headers_method
+
+        #text_method
+
+        #http_request_method
+
+        #stream_method
+
+        #cancellable_method
     })
 }
 
+/// Generate the parameters of a method's signature and the code extracting their values
+///
+/// With parameter structs, all parameters are taken from a single `params`
+/// argument and extracted into `<name>_value` variables.
+pub fn generate_method_params(
+    path: &str,
+    http_method: &str,
+    operation: &openapiv3::Operation,
+    all_params: &[ParameterInfo],
+    use_param_structs: bool,
+) -> (TokenStream2, TokenStream2) {
+    if use_param_structs {
+        // Use parameter struct approach
+        if all_params.is_empty() {
+            // No parameters - keep empty signature
+            (quote! {}, quote! {})
+        } else {
+            // Generate parameter struct name
+            let operation_id = operation_id_or_default(operation, http_method, path);
+            let struct_name = format_ident!("{}Params", operation_id.to_pascal_case());
+
+            // Method signature uses parameter struct
+            let params = quote! { params: #struct_name, };
+
+            // Code to extract values from parameter struct
+            let param_extractions = all_params.iter().map(|param| {
+                let field_name = &param.ident;
+                let var_name = format_ident!("{}_value", field_name);
+                quote! {
+                    let #var_name = params.#field_name;
+                }
+            });
+
+            let param_access_code = quote! {
+                #(#param_extractions)*
+            };
+
+            (params, param_access_code)
+        }
+    } else {
+        // Use individual parameters approach (existing behavior)
+        let params = all_params.iter().map(|param| {
+            let param_ident = &param.ident;
+            let param_type = &param.param_type;
+            quote! { #param_ident: #param_type, }
+        });
+        (quote! { #(#params)* }, quote! {})
+    }
+}
+
+/// Attach the credentials of the `auth` option to a request, if they are set
+fn request_auth(spec: &OpenAPI, input: &OpenApiInput) -> Result<TokenStream2, String> {
+    let credentials = stored_credentials(input);
+    match input.auth {
+        Some(AuthScheme::Bearer) => Ok(quote! {
+            if let Some(token) = #credentials {
+                request = request.bearer_auth(token);
+            }
+        }),
+        Some(AuthScheme::ApiKey) => {
+            let ApiKeyScheme { name, location } = api_key_scheme(spec)?;
+            Ok(match location {
+                ApiKeyLocation::Header => quote! {
+                    if let Some(key) = #credentials {
+                        request = request.header(#name, key);
+                    }
+                },
+                ApiKeyLocation::Query => quote! {
+                    if let Some(key) = #credentials {
+                        request = request.query(&[(#name, key)]);
+                    }
+                },
+            })
+        }
+        Some(AuthScheme::Basic) => Ok(quote! {
+            if let Some((user, password)) = #credentials {
+                request = request.basic_auth(user, Some(password));
+            }
+        }),
+        None => Ok(quote! {}),
+    }
+}
+
+/// Get the error of a response that isn't a success
+///
+/// Redirects that weren't followed become an `ApiError::Redirect` rather than
+/// an API error with an empty body. JSON error bodies are kept parsed.
+fn error_response(is_blocking: bool) -> TokenStream2 {
+    let text_call = if is_blocking {
+        quote! { response.text() }
+    } else {
+        quote! { response.text().await }
+    };
+
+    quote! {
+        if response.status().is_redirection() {
+            Err(ApiError::Redirect {
+                status: response.status().as_u16(),
+                location: response
+                    .headers()
+                    .get(reqwest::header::LOCATION)
+                    .and_then(|location| location.to_str().ok())
+                    .map(str::to_string),
+            })
+        } else {
+            let status = response.status().as_u16();
+            let body = #text_call.unwrap_or_else(|_| "Unknown error".to_string());
+            Err(ApiError::from_error_response(status, body))
+        }
+    }
+}
+
+/// Get the return type and body of a method sending a request
+///
+/// The body evaluates to an `ApiResult`. With an operation error enum, its
+/// error is converted into the enum.
+pub fn sending_method_body(
+    ok_type: &TokenStream2,
+    body: TokenStream2,
+    error_type: Option<&Ident>,
+    is_blocking: bool,
+) -> (TokenStream2, TokenStream2) {
+    let Some(error_type) = error_type else {
+        return (quote! { ApiResult<#ok_type> }, body);
+    };
+
+    let result = if is_blocking {
+        quote! { (|| { #body })() }
+    } else {
+        quote! { async { #body }.await }
+    };
+    (
+        quote! { Result<#ok_type, #error_type> },
+        quote! {
+            let result: ApiResult<#ok_type> = #result;
+            result.map_err(#error_type::from)
+        },
+    )
+}
+
+/// Get the client method name for an operation
+pub fn client_method_name(
+    path: &str,
+    http_method: &str,
+    operation: &openapiv3::Operation,
+) -> Ident {
+    operation
+        .operation_id
+        .as_ref()
+        .map(|id| {
+            let snake_case_id = id.to_snake_case();
+            create_rust_safe_ident(&snake_case_id)
+        })
+        .unwrap_or_else(|| {
+            let clean_path = path
+                .replace(['{', '}', '/'], "_")
+                .trim_matches('_')
+                .to_string();
+            let method_name = format!("{}_{}", http_method, clean_path);
+            create_rust_safe_ident(&method_name)
+        })
+}
+
 /// Determine the return type and content type from an operation's responses
-fn determine_return_type_from_operation(
+pub fn determine_return_type_from_operation(
+    spec: &OpenAPI,
     operation: &openapiv3::Operation,
-) -> Option<(TokenStream2, String)> {
-    let response_200 = operation
-        .responses
-        .responses
-        .get(&openapiv3::StatusCode::Code(200))?;
-    let response = match response_200 {
-        ReferenceOr::Reference { .. } => return None,
-        ReferenceOr::Item(item) => item,
+) -> Result<Option<(TokenStream2, String)>, String> {
+    let Some(response) = success_response(spec, operation)? else {
+        return Ok(None);
     };
 
     // Try application/json first - this is the most common case
-    if let Some(content) = response.content.get("application/json") {
-        if let Some(schema_ref) = content.schema.as_ref() {
-            if let Ok(rust_type) = reference_or_schema_to_rust_type(schema_ref) {
-                return Some((rust_type, "application/json".to_string()));
-            }
-        }
+    if let Some(schema_ref) = json_response_schema(spec, operation)?
+        && let Ok(rust_type) = reference_or_schema_to_rust_type(schema_ref)
+    {
+        return Ok(Some((rust_type, "application/json".to_string())));
     }
 
     // Only try text types if no JSON content was found
     // Try text/plain; charset=utf-8 first (more specific)
     if let Some(_content) = response.content.get("text/plain; charset=utf-8") {
-        return Some((quote! { String }, "text/plain; charset=utf-8".to_string()));
+        return Ok(Some((
+            quote! { String },
+            "text/plain; charset=utf-8".to_string(),
+        )));
     }
 
     // Try text/plain as fallback
     if let Some(_content) = response.content.get("text/plain") {
-        return Some((quote! { String }, "text/plain".to_string()));
+        return Ok(Some((quote! { String }, "text/plain".to_string())));
     }
 
-    None
+    Ok(None)
 }
 
-/// Generate operation ID from method and path (for parameter struct naming)
-fn generate_operation_id_for_struct(method: &str, path: &str) -> String {
-    // Convert path to camelCase operation name
-    let path_parts: Vec<&str> = path
-        .split('/')
-        .filter(|s| !s.is_empty() && !s.starts_with('{'))
-        .collect();
+/// Get the component type shared by all JSON error responses of an operation
+///
+/// Returns `None` when no error response references a component schema, or
+/// when different error responses use different schemas.
+fn error_response_type(
+    spec: &OpenAPI,
+    operation: &openapiv3::Operation,
+) -> Result<Option<TokenStream2>, String> {
+    let error_responses = operation
+        .responses
+        .responses
+        .iter()
+        .filter(|(status, _)| match status {
+            openapiv3::StatusCode::Code(code) => *code >= 400,
+            openapiv3::StatusCode::Range(range) => *range >= 4,
+        })
+        .map(|(_, response)| response)
+        .chain(operation.responses.default.as_ref());
 
-    if path_parts.is_empty() {
-        method.to_string()
-    } else {
-        format!("{}{}", method, path_parts.join("_").to_pascal_case())
+    let mut type_names = Vec::new();
+    for response_ref in error_responses {
+        let response = resolve_response(spec, response_ref)?;
+        let schema = ["application/json", "application/problem+json"]
+            .iter()
+            .find_map(|content_type| response.content.get(*content_type))
+            .and_then(|content| content.schema.as_ref());
+
+        if let Some(ReferenceOr::Reference { reference }) = schema
+            && let Some(type_name) = reference.strip_prefix("#/components/schemas/")
+            && !type_names.contains(&type_name)
+        {
+            type_names.push(type_name);
+        }
+    }
+
+    match type_names.as_slice() {
+        [type_name] => {
+            let type_ident = format_ident!("{}", type_name.to_pascal_case());
+            Ok(Some(quote! { #type_ident }))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Success statuses checked for the response of an operation, in order of preference
+const SUCCESS_STATUS_CODES: [u16; 6] = [200, 201, 202, 203, 204, 206];
+
+/// Get the successful response of an operation, resolving `components.responses` references
+///
+/// Picks the first success status with content, e.g. a `201 Created` with the
+/// created resource, or else the first one documented at all, like a `204`.
+pub fn success_response<'a>(
+    spec: &'a OpenAPI,
+    operation: &'a openapiv3::Operation,
+) -> Result<Option<&'a openapiv3::Response>, String> {
+    let mut responses = Vec::new();
+    for code in SUCCESS_STATUS_CODES {
+        if let Some(response_ref) = operation
+            .responses
+            .responses
+            .get(&openapiv3::StatusCode::Code(code))
+        {
+            responses.push(resolve_response(spec, response_ref)?);
+        }
     }
+
+    Ok(responses
+        .iter()
+        .find(|response| !response.content.is_empty())
+        .or(responses.first())
+        .copied())
+}
+
+/// Get the `application/json` schema of an operation's successful response
+pub fn json_response_schema<'a>(
+    spec: &'a OpenAPI,
+    operation: &'a openapiv3::Operation,
+) -> Result<Option<&'a ReferenceOr<openapiv3::Schema>>, String> {
+    Ok(success_response(spec, operation)?
+        .and_then(|response| response.content.get("application/json"))
+        .and_then(|content| content.schema.as_ref()))
 }
 
 /// Generate URL building code when using parameter structs
 /// This is similar to generate_url_building but uses the extracted _value variables
-fn generate_url_building_with_param_structs(
+pub fn generate_url_building_with_param_structs(
     path: &str,
     path_params: &[&crate::codegen::params::ParameterInfo],
     query_params: &[&crate::codegen::params::ParameterInfo],
 ) -> TokenStream2 {
     let mut url_building = if path_params.is_empty() {
         quote! {
-            let mut url = format!("{}{}", self.base_url, #path);
+            let mut url = format!("{}{}", self.base_url.trim_end_matches('/'), #path);
         }
     } else {
         // Handle path parameters using extracted values
@@ -343,15 +1187,16 @@ fn generate_url_building_with_param_structs(
             let param_name = &param.name;
             let var_name = format_ident!("{}_value", param.ident);
             let placeholder = format!("{{{}}}", param_name);
+            let value_expr = param.value_to_string(&var_name);
             quote! {
-                path = path.replace(#placeholder, &#var_name.to_string());
+                path = path.replace(#placeholder, &#value_expr);
             }
         });
 
         quote! {
             let mut path = #path.to_string();
             #(#path_replacements)*
-            let mut url = format!("{}{}", self.base_url, path);
+            let mut url = format!("{}{}", self.base_url.trim_end_matches('/'), path);
         }
     };
 
@@ -362,11 +1207,7 @@ fn generate_url_building_with_param_structs(
             let var_name = format_ident!("{}_value", param.ident);
 
             // Define the formatting expression once for both required and optional
-            let formatting_expr = if param.is_array {
-                quote! { #var_name.iter().map(|v| v.to_string()).collect::<Vec<String>>().join(",") }
-            } else {
-                quote! { #var_name.to_string() }
-            };
+            let formatting_expr = param.value_to_string(&var_name);
 
             // Common code for appending the parameter
             let append_param = quote! {
@@ -3,15 +3,35 @@
 //! This module contains the core code generation logic that transforms
 //! parsed OpenAPI specifications into Rust client code.
 
+pub mod auth_check;
+pub mod catalog;
 pub mod client;
 pub mod docs;
 pub mod errors;
+pub mod json_rpc;
 pub mod methods;
+pub mod operation_errors;
+pub mod pagination;
 pub mod param_structs;
+pub mod response_headers;
+pub mod retry;
+pub mod single_flight;
 pub mod structs;
+pub mod ureq;
+pub mod uri_template;
+pub mod validation;
 
+pub use auth_check::*;
+pub use catalog::*;
 pub use client::*;
 pub use docs::*;
 pub use errors::*;
+pub use json_rpc::*;
+pub use operation_errors::*;
+pub use pagination::*;
 pub use param_structs::*;
+pub use retry::*;
+pub use single_flight::*;
 pub use structs::*;
+pub use uri_template::*;
+pub use validation::*;
@@ -0,0 +1,119 @@
+use heck::ToPascalCase;
+use openapiv3::OpenAPI;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+
+use crate::codegen::{operation_id_or_default, reference_or_schema_to_rust_type, spec_operations};
+use crate::parser::resolve_response;
+
+/// Get the name of the error enum of an operation, e.g. `GetUserError`
+pub fn operation_error_name(operation_id: &str) -> String {
+    format!("{}Error", operation_id.to_pascal_case())
+}
+
+/// Generate an error enum for every operation, for the `operation_errors` option
+///
+/// Every documented `4xx`/`5xx` status code gets a variant named after its
+/// reason phrase, holding the typed JSON body if the response has one.
+/// Undocumented statuses end up in `Other`, and failures without a response
+/// in `Client`.
+pub fn generate_operation_errors(spec: &OpenAPI) -> Result<TokenStream2, String> {
+    let mut enums = TokenStream2::new();
+    for (path, method, operation) in spec_operations(spec)? {
+        let operation_id = operation_id_or_default(operation, method, path);
+        let enum_name = format_ident!("{}", operation_error_name(&operation_id));
+        let enum_doc = format!("Errors of the `{}` operation", operation_id);
+
+        let mut variants = Vec::new();
+        let mut conversions = Vec::new();
+        for (status, response_ref) in &operation.responses.responses {
+            let openapiv3::StatusCode::Code(code) = status else {
+                continue;
+            };
+            if *code < 400 {
+                continue;
+            }
+
+            let reason = reqwest::StatusCode::from_u16(*code)
+                .ok()
+                .and_then(|status| status.canonical_reason());
+            let variant = match reason {
+                Some(reason) => format_ident!("{}", reason.to_pascal_case()),
+                None => format_ident!("Status{}", code),
+            };
+            let message = format!("API error {}: {}", code, reason.unwrap_or("Unknown"));
+            let variant_doc = format!("`{}` response", code);
+
+            let response = resolve_response(spec, response_ref)?;
+            let schema = ["application/json", "application/problem+json"]
+                .iter()
+                .find_map(|content_type| response.content.get(*content_type))
+                .and_then(|content| content.schema.as_ref());
+
+            match schema {
+                Some(schema) => {
+                    let body_type = reference_or_schema_to_rust_type(schema)?;
+                    variants.push(quote! {
+                        #[doc = #variant_doc]
+                        #[error(#message)]
+                        #variant(#body_type),
+                    });
+                    conversions.push(quote! {
+                        ApiError::ApiTyped { status: #code, body } => match serde_json::from_value(body.clone()) {
+                            Ok(typed) => Self::#variant(typed),
+                            Err(_) => Self::Other(#code, body),
+                        },
+                        ApiError::Api { status: #code, message } => {
+                            Self::Other(#code, serde_json::Value::String(message))
+                        }
+                    });
+                }
+                None => {
+                    variants.push(quote! {
+                        #[doc = #variant_doc]
+                        #[error(#message)]
+                        #variant,
+                    });
+                    conversions.push(quote! {
+                        ApiError::Api { status: #code, .. } | ApiError::ApiTyped { status: #code, .. } => {
+                            Self::#variant
+                        }
+                    });
+                }
+            }
+        }
+
+        enums.extend(quote! {
+            #[doc = #enum_doc]
+            #[derive(Debug, thiserror::Error)]
+            pub enum #enum_name {
+                #(#variants)*
+
+                /// Undocumented error response, with its status and body
+                ///
+                /// The body is kept as a string if it isn't JSON.
+                #[error("API error {0}: {1}")]
+                Other(u16, serde_json::Value),
+
+                /// The request failed without an error response, e.g. on connection errors
+                #[error(transparent)]
+                Client(ApiError),
+            }
+
+            impl From<ApiError> for #enum_name {
+                fn from(error: ApiError) -> Self {
+                    match error {
+                        #(#conversions)*
+                        ApiError::Api { status, message } => {
+                            Self::Other(status, serde_json::Value::String(message))
+                        }
+                        ApiError::ApiTyped { status, body } => Self::Other(status, body),
+                        error => Self::Client(error),
+                    }
+                }
+            }
+        });
+    }
+
+    Ok(enums)
+}
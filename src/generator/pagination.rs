@@ -0,0 +1,237 @@
+use openapiv3::{OpenAPI, ReferenceOr, Schema, SchemaKind, Type};
+use proc_macro2::{Group, TokenStream as TokenStream2, TokenTree};
+use quote::{format_ident, quote};
+
+use crate::codegen::{
+    ParameterLocation, operation_parameters, reference_or_schema_to_rust_type, schema_to_rust_type,
+};
+use crate::generator::methods::{client_method_name, json_response_schema};
+use crate::parser::{PaginationConfig, resolve_schema_reference};
+
+/// Generate an `<operation>_all` stream for an operation taking the configured cursor parameter
+///
+/// The stream requests pages until the response has no next cursor, yielding
+/// the items of every page. Blocking clients get an iterator instead. Operations
+/// without the cursor query parameter get no stream.
+pub fn generate_pagination_method(
+    spec: &OpenAPI,
+    path: &str,
+    http_method: &str,
+    operation: &openapiv3::Operation,
+    pagination: &PaginationConfig,
+    is_blocking: bool,
+) -> Result<TokenStream2, String> {
+    let params = operation_parameters(operation)?;
+
+    let Some(cursor) = params
+        .iter()
+        .find(|p| p.location == ParameterLocation::Query && p.name == pagination.cursor_param)
+    else {
+        return Ok(quote! {});
+    };
+
+    let method_name = client_method_name(path, http_method, operation);
+    if cursor.param_type.to_string() != quote! { Option<&str> }.to_string() {
+        return Err(format!(
+            "Cursor parameter `{}` of `{}` must be an optional string query parameter",
+            pagination.cursor_param, method_name
+        ));
+    }
+
+    let item_type = pagination_item_type(spec, operation, &pagination.items)?.ok_or_else(|| {
+        format!(
+            "Items pointer `{}` does not resolve to an array in the response of `{}`",
+            pagination.items, method_name
+        )
+    })?;
+
+    let all_name = format_ident!("{}_all", method_name);
+    let doc = format!(
+        "{} the items of all pages of [`Self::{}`], following the `{}` cursor",
+        if is_blocking {
+            "Iterate over"
+        } else {
+            "Stream"
+        },
+        method_name,
+        pagination.cursor_param
+    );
+
+    let mut signature_params = Vec::new();
+    let mut clones = Vec::new();
+    let mut call_args = Vec::new();
+    for param in &params {
+        let ident = &param.ident;
+        if param.name == cursor.name {
+            call_args.push(quote! { cursor.as_deref() });
+            continue;
+        }
+
+        let param_type = with_lifetime(param.param_type.clone());
+        signature_params.push(quote! { #ident: #param_type });
+        // Everything but arrays is `Copy`, so only arrays are cloned for every page
+        if param.is_array {
+            clones.push(quote! { let #ident = #ident.clone(); });
+        }
+        call_args.push(quote! { #ident });
+    }
+
+    let page_parsing = generate_page_parsing(pagination, &item_type);
+
+    if is_blocking {
+        // Arrays are cloned for every page, as the iterator may be called again
+        let call_args = params.iter().zip(call_args).map(|(param, arg)| {
+            if param.is_array {
+                quote! { #arg.clone() }
+            } else {
+                arg
+            }
+        });
+
+        return Ok(quote! {
+            #[doc = #doc]
+            pub fn #all_name<'a>(
+                &'a self,
+                #(#signature_params),*
+            ) -> impl Iterator<Item = ApiResult<#item_type>> + 'a {
+                let mut next_page = Some(None::<String>);
+                let mut items = Vec::new().into_iter();
+
+                std::iter::from_fn(move || loop {
+                    if let Some(item) = items.next() {
+                        return Some(Ok(item));
+                    }
+                    // Pages stop after the last one, or after an error
+                    let cursor = next_page.take()?;
+
+                    let page = (|| {
+                        let page = self.#method_name(#(#call_args),*)?;
+                        #page_parsing
+                    })();
+                    match page {
+                        Ok((next, page_items)) => {
+                            next_page = next;
+                            items = page_items.into_iter();
+                        }
+                        Err(e) => return Some(Err(e)),
+                    }
+                })
+            }
+        });
+    }
+
+    Ok(quote! {
+        #[doc = #doc]
+        pub fn #all_name<'a>(
+            &'a self,
+            #(#signature_params),*
+        ) -> impl futures::Stream<Item = ApiResult<#item_type>> + 'a {
+            use futures::TryStreamExt;
+
+            futures::stream::try_unfold(Some(None::<String>), move |cursor| {
+                #(#clones)*
+                async move {
+                    let Some(cursor) = cursor else {
+                        return Ok(None);
+                    };
+
+                    let page = self.#method_name(#(#call_args),*).await?;
+                    let (next, items) = { #page_parsing }?;
+
+                    let items = futures::stream::iter(items.into_iter().map(Ok));
+                    Ok::<_, ApiError>(Some((items, next)))
+                }
+            })
+            .try_flatten()
+        }
+    })
+}
+
+/// Generate code taking the next cursor and the items from a `page` response
+///
+/// It evaluates to an `ApiResult` of the cursor of the next page, `None` on the
+/// last page, and the items of the page.
+fn generate_page_parsing(pagination: &PaginationConfig, item_type: &TokenStream2) -> TokenStream2 {
+    let next_cursor = &pagination.next_cursor;
+    let items = &pagination.items;
+
+    quote! {
+        let mut page = serde_json::to_value(page)?;
+        let next = match page.pointer(#next_cursor) {
+            Some(serde_json::Value::String(next)) if !next.is_empty() => Some(Some(next.clone())),
+            Some(serde_json::Value::Number(next)) => Some(Some(next.to_string())),
+            _ => None,
+        };
+        let items = match page.pointer_mut(#items) {
+            Some(items) if !items.is_null() => items.take(),
+            _ => serde_json::Value::Array(Vec::new()),
+        };
+        let items: Vec<#item_type> = serde_json::from_value(items)?;
+        Ok::<_, ApiError>((next, items))
+    }
+}
+
+/// Get the item type of the array the items pointer refers to in the JSON response
+fn pagination_item_type(
+    spec: &OpenAPI,
+    operation: &openapiv3::Operation,
+    pointer: &str,
+) -> Result<Option<TokenStream2>, String> {
+    let Some(schema_ref) = json_response_schema(spec, operation)? else {
+        return Ok(None);
+    };
+
+    let mut schema = match schema_ref {
+        ReferenceOr::Item(schema) => schema,
+        ReferenceOr::Reference { reference } => resolve_schema_reference(spec, reference)?,
+    };
+
+    for segment in pointer.split('/').skip(1) {
+        let segment = segment.replace("~1", "/").replace("~0", "~");
+        let SchemaKind::Type(Type::Object(obj)) = &schema.schema_kind else {
+            return Ok(None);
+        };
+        schema = match obj.properties.get(&segment) {
+            Some(ReferenceOr::Item(property)) => property,
+            Some(ReferenceOr::Reference { reference }) => {
+                resolve_schema_reference(spec, reference)?
+            }
+            None => return Ok(None),
+        };
+    }
+
+    match &schema.schema_kind {
+        SchemaKind::Type(Type::Array(array)) => match &array.items {
+            Some(ReferenceOr::Reference { reference }) => {
+                reference_or_schema_to_rust_type(&ReferenceOr::<Schema>::Reference {
+                    reference: reference.clone(),
+                })
+                .map(Some)
+            }
+            Some(ReferenceOr::Item(items)) => schema_to_rust_type(items).map(Some),
+            None => Ok(Some(quote! { serde_json::Value })),
+        },
+        _ => Ok(None),
+    }
+}
+
+/// Give every reference in a parameter type the `'a` lifetime of the stream
+fn with_lifetime(tokens: TokenStream2) -> TokenStream2 {
+    tokens
+        .into_iter()
+        .flat_map(|token| match token {
+            TokenTree::Punct(punct) if punct.as_char() == '&' => {
+                let lifetime: TokenStream2 = quote! { 'a };
+                std::iter::once(TokenTree::Punct(punct))
+                    .chain(lifetime)
+                    .collect::<Vec<_>>()
+            }
+            TokenTree::Group(group) => {
+                let mut inner = Group::new(group.delimiter(), with_lifetime(group.stream()));
+                inner.set_span(group.span());
+                vec![TokenTree::Group(inner)]
+            }
+            token => vec![token],
+        })
+        .collect()
+}
@@ -1,11 +1,14 @@
-use openapiv3::{OpenAPI, Operation, Parameter, PathItem, ReferenceOr};
+use openapiv3::{OpenAPI, Operation, Parameter, ReferenceOr};
 use proc_macro2::{Ident, TokenStream as TokenStream2};
 use quote::{ToTokens, format_ident, quote};
 
 use crate::codegen::params::{ParameterInfo, ParameterLocation};
-use crate::codegen::reference_or_schema_to_rust_type;
+use crate::codegen::{
+    operation_id_or_default, parameter_enum_values, parameter_value_format,
+    reference_or_schema_to_rust_type, spec_operations,
+};
 use crate::utils::create_rust_safe_ident;
-use heck::{ToPascalCase, ToSnakeCase};
+use heck::{ToPascalCase, ToShoutySnakeCase, ToSnakeCase};
 
 /// Generate parameter structs for all operations in the OpenAPI spec
 pub fn generate_param_structs(
@@ -14,42 +17,70 @@ pub fn generate_param_structs(
 ) -> Result<TokenStream2, String> {
     let mut structs = Vec::new();
 
-    for (path, path_item) in &spec.paths.paths {
-        if let ReferenceOr::Item(path_item) = path_item {
-            generate_structs_for_path(path, path_item, &mut structs, struct_attrs)?;
-        }
+    for (path, method, operation) in spec_operations(spec)? {
+        generate_struct_for_operation(path, method, operation, &mut structs, struct_attrs)?;
     }
 
     Ok(quote! {
+        /// Error parsing a parameter struct from string values with `try_from_map`
+        #[derive(Debug, Clone, PartialEq, thiserror::Error)]
+        pub enum ParamParseError {
+            /// A required parameter is missing from the map
+            #[error("missing required parameter `{0}`")]
+            Missing(&'static str),
+
+            /// The value of a parameter can't be parsed into its type
+            #[error("invalid value {value:?} for parameter `{name}`: {message}")]
+            Invalid {
+                name: &'static str,
+                value: String,
+                message: String,
+            },
+        }
+
         #(#structs)*
     })
 }
 
-/// Generate parameter structs for all operations in a single path
-fn generate_structs_for_path(
-    path: &str,
-    path_item: &PathItem,
-    structs: &mut Vec<TokenStream2>,
-    struct_attrs: &[TokenStream2],
-) -> Result<(), String> {
-    let operations = [
-        ("get", &path_item.get),
-        ("post", &path_item.post),
-        ("put", &path_item.put),
-        ("delete", &path_item.delete),
-        ("patch", &path_item.patch),
-        ("head", &path_item.head),
-        ("options", &path_item.options),
-        ("trace", &path_item.trace),
-    ];
-
-    for (method, operation) in operations {
-        if let Some(operation) = operation {
-            generate_struct_for_operation(path, method, operation, structs, struct_attrs)?;
+/// Generate module constants listing the allowed values of enumeration parameters
+///
+/// Used when parameter structs are disabled; with parameter structs the
+/// constants are associated with the struct instead.
+pub fn generate_param_value_constants(spec: &OpenAPI) -> Result<TokenStream2, String> {
+    let mut constants = Vec::new();
+
+    for (path, method, operation) in spec_operations(spec)? {
+        let operation_id = operation_id_or_default(operation, method, path);
+
+        for param_ref in &operation.parameters {
+            let ReferenceOr::Item(param) = param_ref else {
+                continue;
+            };
+            let parameter_data = param.parameter_data_ref();
+            let values = parameter_enum_values(&parameter_data.format);
+            if values.is_empty() {
+                continue;
+            }
+
+            let const_name = format_ident!(
+                "{}_{}_VALUES",
+                operation_id.to_shouty_snake_case(),
+                parameter_data.name.to_shouty_snake_case()
+            );
+            let doc = format!(
+                "Allowed values of the `{}` parameter of `{}`",
+                parameter_data.name, operation_id
+            );
+            constants.push(quote! {
+                #[doc = #doc]
+                pub const #const_name: &[&str] = &[#(#values),*];
+            });
         }
     }
 
-    Ok(())
+    Ok(quote! {
+        #(#constants)*
+    })
 }
 
 /// Generate a parameter struct for a single operation
@@ -61,11 +92,7 @@ fn generate_struct_for_operation(
     struct_attrs: &[TokenStream2],
 ) -> Result<(), String> {
     // Get operation ID or generate one
-    let operation_id = operation
-        .operation_id
-        .as_ref()
-        .cloned()
-        .unwrap_or_else(|| generate_operation_id(method, path));
+    let operation_id = operation_id_or_default(operation, method, path);
 
     // Parse all parameters
     let mut params = Vec::new();
@@ -158,15 +185,12 @@ fn process_parameter_for_struct(
 
     // Check if this is an array parameter
     let is_array = match param_schema {
-        openapiv3::ParameterSchemaOrContent::Schema(schema_ref) => match schema_ref {
-            ReferenceOr::Item(schema) => {
-                matches!(
-                    schema.schema_kind,
-                    openapiv3::SchemaKind::Type(openapiv3::Type::Array(_))
-                )
-            }
-            _ => false,
-        },
+        openapiv3::ParameterSchemaOrContent::Schema(ReferenceOr::Item(schema)) => {
+            matches!(
+                schema.schema_kind,
+                openapiv3::SchemaKind::Type(openapiv3::Type::Array(_))
+            )
+        }
         _ => false,
     };
 
@@ -177,6 +201,9 @@ fn process_parameter_for_struct(
         location,
         is_array,
         required,
+        enum_values: parameter_enum_values(param_schema),
+        format: parameter_value_format(param_schema)
+            .map_err(|e| format!("Parameter {}: {}", param_name, e))?,
     })
 }
 
@@ -205,6 +232,26 @@ fn generate_param_struct(
     // Generate builder methods for optional parameters
     let builder_methods = generate_builder_methods(&optional_params);
 
+    // Generate a constructor parsing the parameters from string values
+    let try_from_map = generate_try_from_map(params)?;
+
+    // Generate an accessor for the serialized query parameters
+    let query_params = generate_query_params(params);
+
+    // Generate constants listing the allowed values of enumeration parameters
+    let value_constants = params
+        .iter()
+        .filter(|p| !p.enum_values.is_empty())
+        .map(|param| {
+            let const_name = format_ident!("{}_VALUES", param.name.to_shouty_snake_case());
+            let doc = format!("Allowed values of the `{}` parameter", param.name);
+            let values = &param.enum_values;
+            quote! {
+                #[doc = #doc]
+                pub const #const_name: &'static [&'static str] = &[#(#values),*];
+            }
+        });
+
     // Generate Default implementation if no required parameters
     let default_impl = if required_params.is_empty() {
         quote! {
@@ -233,8 +280,11 @@ fn generate_param_struct(
         }
 
         impl #struct_name {
+            #(#value_constants)*
             #constructor
             #(#builder_methods)*
+            #try_from_map
+            #query_params
         }
 
         #default_impl
@@ -262,16 +312,27 @@ fn generate_constructor(
         }
     } else {
         // Has required parameters - constructor with args
+        // Required string parameters accept both &str and String, moving a String without copying
+        let is_string = |param: &ParameterInfo| param.param_type.to_string() == "String";
+
         let required_args = required_params.iter().map(|param| {
             let name = &param.ident;
             // For required parameters, use the actual type without Option wrapper
             let param_type = &param.param_type;
-            quote! { #name: #param_type, }
+            if is_string(param) {
+                quote! { #name: impl Into<String>, }
+            } else {
+                quote! { #name: #param_type, }
+            }
         });
 
         let required_init = required_params.iter().map(|param| {
             let name = &param.ident;
-            quote! { #name, }
+            if is_string(param) {
+                quote! { #name: #name.into(), }
+            } else {
+                quote! { #name, }
+            }
         });
 
         let optional_init = optional_params.iter().map(|param| {
@@ -310,7 +371,8 @@ fn generate_builder_methods(optional_params: &[&ParameterInfo]) -> Vec<TokenStre
                 syn::parse2(param.param_type.clone()).unwrap()
             };
 
-            // For String parameters, accept both &str and String for convenience
+            // For String parameters, accept both &str and String for convenience, a String
+            // being moved into the struct without copying
             let input_type = if inner_type.to_token_stream().to_string() == "String" {
                 quote! { impl Into<String> }
             } else {
@@ -333,17 +395,166 @@ fn generate_builder_methods(optional_params: &[&ParameterInfo]) -> Vec<TokenStre
         .collect()
 }
 
-/// Generate operation ID from method and path
-fn generate_operation_id(method: &str, path: &str) -> String {
-    // Convert path to camelCase operation name
-    let path_parts: Vec<&str> = path
-        .split('/')
-        .filter(|s| !s.is_empty() && !s.starts_with('{'))
+/// Generate the `query_params` method returning the serialized query parameters
+///
+/// Values are serialized like in the query string of the request, and unset
+/// optional parameters are left out. Structs without query parameters get no
+/// method.
+fn generate_query_params(params: &[ParameterInfo]) -> TokenStream2 {
+    let query_params: Vec<_> = params
+        .iter()
+        .filter(|p| p.location == ParameterLocation::Query)
         .collect();
+    if query_params.is_empty() {
+        return quote! {};
+    }
 
-    if path_parts.is_empty() {
-        method.to_string()
-    } else {
-        format!("{}{}", method, path_parts.join("_").to_pascal_case())
+    let value = format_ident!("value");
+    let inserts = query_params.iter().map(|param| {
+        let ident = &param.ident;
+        let name = &param.name;
+        let value_expr = param.value_to_string(&value);
+        if param.required {
+            quote! {
+                let value = &self.#ident;
+                query.insert(#name.to_string(), #value_expr);
+            }
+        } else {
+            quote! {
+                if let Some(value) = &self.#ident {
+                    query.insert(#name.to_string(), #value_expr);
+                }
+            }
+        }
+    });
+
+    quote! {
+        /// Query parameters of the request, serialized like in its query string
+        ///
+        /// The map is sorted by parameter name, e.g. for canonicalizing the
+        /// query when signing requests.
+        pub fn query_params(&self) -> std::collections::BTreeMap<String, String> {
+            let mut query = std::collections::BTreeMap::new();
+            #(#inserts)*
+            query
+        }
+    }
+}
+
+/// Generate the `try_from_map` constructor parsing parameters from a map of string values
+///
+/// Values are looked up by the parameter names of the spec. Array parameters
+/// are given as comma-separated values, like in the query string.
+fn generate_try_from_map(params: &[ParameterInfo]) -> Result<TokenStream2, String> {
+    let mut field_inits = Vec::new();
+    for param in params {
+        let ident = &param.ident;
+        let name = &param.name;
+        let param_type: syn::Type = syn::parse2(param.param_type.clone())
+            .map_err(|e| format!("Invalid type of parameter {}: {}", name, e))?;
+        let value_type = if param.required {
+            &param_type
+        } else {
+            generic_argument(&param_type, "Option").unwrap_or(&param_type)
+        };
+        let parse = parse_value(value_type, name);
+
+        field_inits.push(if param.required {
+            quote! {
+                #ident: {
+                    let value = map.get(#name).ok_or(ParamParseError::Missing(#name))?;
+                    #parse?
+                },
+            }
+        } else {
+            quote! {
+                #ident: match map.get(#name) {
+                    Some(value) => Some(#parse?),
+                    None => None,
+                },
+            }
+        });
+    }
+
+    Ok(quote! {
+        /// Parse the parameters from string values keyed by their names in the spec
+        ///
+        /// Array parameters are given as comma-separated values. Keys that
+        /// aren't parameters are ignored.
+        pub fn try_from_map(map: &HashMap<String, String>) -> Result<Self, ParamParseError> {
+            Ok(Self {
+                #(#field_inits)*
+            })
+        }
+    })
+}
+
+/// Generate an expression parsing the string `value` into a parameter type
+///
+/// The expression evaluates to a `Result` with a `ParamParseError`. Types
+/// without a `FromStr` implementation, like component enums, are deserialized
+/// from the value as JSON, or else as a JSON string.
+fn parse_value(value_type: &syn::Type, name: &str) -> TokenStream2 {
+    let invalid = quote! {
+        |e| ParamParseError::Invalid {
+            name: #name,
+            value: value.to_string(),
+            message: e.to_string(),
+        }
+    };
+
+    if let Some(item_type) = generic_argument(value_type, "Vec") {
+        let parse_item = parse_value(item_type, name);
+        return quote! {
+            value
+                .split(',')
+                .filter(|value| !value.is_empty())
+                .map(|value| #parse_item)
+                .collect::<Result<Vec<#item_type>, ParamParseError>>()
+        };
+    }
+
+    match value_type
+        .to_token_stream()
+        .to_string()
+        .replace(' ', "")
+        .as_str()
+    {
+        "String" => quote! { Ok::<String, ParamParseError>(value.to_string()) },
+        "i32"
+        | "i64"
+        | "u64"
+        | "f32"
+        | "f64"
+        | "bool"
+        | "rust_decimal::Decimal"
+        | "chrono::NaiveDate"
+        | "chrono::DateTime<chrono::Utc>"
+        | "uuid::Uuid" => quote! {
+            value.parse::<#value_type>().map_err(#invalid)
+        },
+        _ => quote! {
+            serde_json::from_str::<#value_type>(value)
+                .or_else(|_| serde_json::from_value(serde_json::Value::String(value.to_string())))
+                .map_err(#invalid)
+        },
+    }
+}
+
+/// Get the type argument of a generic type like `Option<T>` or `Vec<T>`
+fn generic_argument<'a>(ty: &'a syn::Type, name: &str) -> Option<&'a syn::Type> {
+    let syn::Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != name {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    match arguments.args.first()? {
+        syn::GenericArgument::Type(ty) => Some(ty),
+        _ => None,
     }
 }
@@ -0,0 +1,188 @@
+use heck::{ToPascalCase, ToSnakeCase};
+use openapiv3::{Header, OpenAPI, Operation, ParameterSchemaOrContent};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+
+use crate::codegen::reference_or_schema_to_rust_type;
+use crate::generator::docs::generate_doc_comment;
+use crate::generator::methods::success_response;
+use crate::parser::{OpenApiInput, resolve_header};
+use crate::utils::create_rust_safe_ident;
+
+/// Generate the `ApiResponse` type of the `include_response_headers` option
+pub fn generate_api_response_type(input: &OpenApiInput) -> TokenStream2 {
+    if !input.include_response_headers {
+        return quote! {};
+    }
+
+    quote! {
+        /// Successful response of a method, with its status and headers
+        #[derive(Debug, Clone)]
+        pub struct ApiResponse<T> {
+            /// Parsed body of the response
+            pub body: T,
+            /// All headers of the response
+            pub headers: reqwest::header::HeaderMap,
+            /// Status code of the response
+            pub status: u16,
+        }
+
+        impl<T> ApiResponse<T> {
+            /// Take the body, dropping the status and headers
+            pub fn into_body(self) -> T {
+                self.body
+            }
+        }
+    }
+}
+
+/// Get the return type and response parsing of a method for the `include_response_headers` option
+///
+/// With the option, the successful result of `parsing` is wrapped in an
+/// `ApiResponse` with the status and headers of `response`. Otherwise both are
+/// returned unchanged.
+pub fn wrap_api_response(
+    input: &OpenApiInput,
+    return_type: &TokenStream2,
+    parsing: TokenStream2,
+    is_blocking: bool,
+) -> (TokenStream2, TokenStream2) {
+    if !input.include_response_headers {
+        return (return_type.clone(), parsing);
+    }
+
+    // Parsing may return early, e.g. with a cached body, so it's evaluated on its own
+    let result = if is_blocking {
+        quote! { (|| { #parsing })() }
+    } else {
+        quote! { async { #parsing }.await }
+    };
+    (
+        quote! { ApiResponse<#return_type> },
+        quote! {
+            let response_status = response.status().as_u16();
+            let response_headers = response.headers().clone();
+            let result: ApiResult<#return_type> = #result;
+            result.map(|body| ApiResponse {
+                body,
+                headers: response_headers,
+                status: response_status,
+            })
+        },
+    )
+}
+
+/// Get the headers documented on an operation's successful response
+pub fn response_headers<'a>(
+    spec: &'a OpenAPI,
+    operation: &'a Operation,
+) -> Result<Vec<(&'a str, &'a Header)>, String> {
+    let Some(response) = success_response(spec, operation)? else {
+        return Ok(Vec::new());
+    };
+
+    response
+        .headers
+        .iter()
+        .map(|(name, header_ref)| Ok((name.as_str(), resolve_header(spec, header_ref)?)))
+        .collect()
+}
+
+/// Name of the struct generated for the documented response headers of an operation
+pub fn response_headers_struct_name(operation_id: &str) -> String {
+    format!("{}ResponseHeaders", operation_id.to_pascal_case())
+}
+
+/// Generate the struct holding the documented response headers of an operation
+///
+/// Every header becomes an optional field, parsed with the type of its schema.
+/// Headers that are missing or fail to parse are `None`.
+pub fn generate_response_headers_struct(
+    spec: &OpenAPI,
+    operation: &Operation,
+    operation_id: &str,
+) -> Result<TokenStream2, String> {
+    let headers = response_headers(spec, operation)?;
+    if headers.is_empty() {
+        return Ok(quote! {});
+    }
+
+    let struct_name = format_ident!("{}", response_headers_struct_name(operation_id));
+    let doc = format!("Documented headers of the `{}` response", operation_id);
+
+    let mut fields = Vec::new();
+    let mut parsers = Vec::new();
+    for (header_name, header) in headers {
+        let field_ident = create_rust_safe_ident(&header_name.to_snake_case());
+        let field_doc = generate_doc_comment(header.description.as_deref());
+        let header_key = header_name.to_lowercase();
+
+        let value = if cfg!(feature = "ureq") {
+            quote! { response.header(#header_key) }
+        } else {
+            quote! {
+                headers.get(#header_key).and_then(|value| value.to_str().ok())
+            }
+        };
+        let (field_type, parser) = match header_type(header)? {
+            Some(field_type) => (
+                field_type.clone(),
+                quote! { #value.and_then(|value| value.trim().parse::<#field_type>().ok()) },
+            ),
+            None => (quote! { String }, quote! { #value.map(str::to_string) }),
+        };
+
+        fields.push(quote! {
+            #field_doc
+            pub #field_ident: Option<#field_type>,
+        });
+        parsers.push(quote! { #field_ident: #parser, });
+    }
+
+    let constructor = if cfg!(feature = "ureq") {
+        quote! {
+            /// Parse the documented headers from a `ureq` response
+            pub fn from_response(response: &ureq::Response) -> Self {
+                Self {
+                    #(#parsers)*
+                }
+            }
+        }
+    } else {
+        quote! {
+            /// Parse the documented headers from a response's headers
+            pub fn from_headers(headers: &reqwest::header::HeaderMap) -> Self {
+                Self {
+                    #(#parsers)*
+                }
+            }
+        }
+    };
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone, Default)]
+        pub struct #struct_name {
+            #(#fields)*
+        }
+
+        impl #struct_name {
+            #constructor
+        }
+    })
+}
+
+/// Get the parsed type of a header, or `None` for headers kept as strings
+fn header_type(header: &Header) -> Result<Option<TokenStream2>, String> {
+    let ParameterSchemaOrContent::Schema(schema_ref) = &header.format else {
+        return Ok(None);
+    };
+
+    let rust_type = reference_or_schema_to_rust_type(schema_ref)?;
+    match rust_type.to_string().replace(' ', "").as_str() {
+        "i32" | "i64" | "u64" | "f32" | "f64" | "bool" | "rust_decimal::Decimal" => {
+            Ok(Some(rust_type))
+        }
+        _ => Ok(None),
+    }
+}
@@ -0,0 +1,149 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+
+use crate::parser::OpenApiInput;
+
+/// Generate the `RetryPolicy` type of the `retry` option
+pub fn generate_retry_policy(input: &OpenApiInput) -> TokenStream2 {
+    if input.retry.is_none() {
+        return quote! {};
+    }
+
+    quote! {
+        /// How failed idempotent requests are retried
+        ///
+        /// Requests failing to connect or answered with a 5xx status are sent
+        /// again, waiting `initial_backoff` before the first retry and twice as
+        /// long before every next one.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct RetryPolicy {
+            /// Times a request is retried after the first attempt
+            pub max_retries: u32,
+            /// Delay before the first retry
+            pub initial_backoff: std::time::Duration,
+        }
+    }
+}
+
+/// Generate sending the built `request` through `send`, retrying it according to the `retry` option
+///
+/// Only requests with an idempotent method are retried, so `POST` and `PATCH`
+/// requests are always sent once. `send` is an expression evaluating to the
+/// result of sending `request`, and `is_connect_error` a pattern matching its
+/// connection errors.
+pub fn generate_send_with_retry(
+    input: &OpenApiInput,
+    send: TokenStream2,
+    is_connect_error: TokenStream2,
+    is_blocking: bool,
+) -> TokenStream2 {
+    if input.retry.is_none() {
+        return send;
+    }
+
+    let sleep = if is_blocking {
+        quote! { std::thread::sleep(backoff); }
+    } else {
+        quote! { tokio::time::sleep(backoff).await; }
+    };
+
+    quote! {{
+        let mut request = request;
+        let mut retries = 0;
+        let mut backoff = self.retry_policy.initial_backoff;
+        loop {
+            // Bodies that can't be cloned, like streams, can't be sent again
+            let retry_request = if retries < self.retry_policy.max_retries && request.method().is_idempotent() {
+                request.try_clone()
+            } else {
+                None
+            };
+            let result = #send;
+            let failed = match &result {
+                Ok(response) => response.status().is_server_error(),
+                Err(#is_connect_error) => e.is_connect(),
+                Err(_) => false,
+            };
+            match retry_request {
+                Some(retry_request) if failed => {
+                    #sleep
+                    backoff *= 2;
+                    retries += 1;
+                    request = retry_request;
+                }
+                _ => break result,
+            }
+        }
+    }}
+}
+
+/// Generate the `Retry-After` helpers used to back off on 429 responses
+///
+/// Only generated with the `retry` feature.
+pub fn generate_retry_helpers() -> TokenStream2 {
+    if !cfg!(feature = "retry") {
+        return quote! {};
+    }
+
+    quote! {
+        /// Longest delay honored from a `Retry-After` header
+        const MAX_RETRY_AFTER: std::time::Duration = std::time::Duration::from_secs(60);
+
+        /// Get the delay requested by a `Retry-After` header, capped at [`MAX_RETRY_AFTER`]
+        ///
+        /// Supports both delay-seconds and HTTP-date values. Dates in the past
+        /// result in a zero delay.
+        #[allow(dead_code)]
+        fn retry_after_delay(headers: &reqwest::header::HeaderMap) -> Option<std::time::Duration> {
+            let value = headers.get(reqwest::header::RETRY_AFTER)?.to_str().ok()?.trim();
+            let delay = match value.parse::<u64>() {
+                Ok(seconds) => std::time::Duration::from_secs(seconds),
+                Err(_) => parse_http_date(value)?
+                    .duration_since(std::time::SystemTime::now())
+                    .unwrap_or_default(),
+            };
+
+            Some(delay.min(MAX_RETRY_AFTER))
+        }
+
+        /// Parse an IMF-fixdate such as `Sun, 06 Nov 1994 08:49:37 GMT`
+        #[allow(dead_code)]
+        fn parse_http_date(value: &str) -> Option<std::time::SystemTime> {
+            let mut parts = value.split_whitespace();
+            let _weekday = parts.next()?;
+            let day: i64 = parts.next()?.parse().ok()?;
+            let month: i64 = match parts.next()? {
+                "Jan" => 1,
+                "Feb" => 2,
+                "Mar" => 3,
+                "Apr" => 4,
+                "May" => 5,
+                "Jun" => 6,
+                "Jul" => 7,
+                "Aug" => 8,
+                "Sep" => 9,
+                "Oct" => 10,
+                "Nov" => 11,
+                "Dec" => 12,
+                _ => return None,
+            };
+            let year: i64 = parts.next()?.parse().ok()?;
+            let mut time = parts.next()?.split(':').map(|part| part.parse::<i64>().ok());
+            let (hour, minute, second) = (time.next()??, time.next()??, time.next()??);
+            if parts.next()? != "GMT" {
+                return None;
+            }
+
+            // Days since the Unix epoch for a date in the proleptic Gregorian calendar
+            let (year, month) = if month <= 2 { (year - 1, month + 9) } else { (year, month - 3) };
+            let era = year.div_euclid(400);
+            let year_of_era = year - era * 400;
+            let day_of_year = (153 * month + 2) / 5 + day - 1;
+            let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+            let days = era * 146_097 + day_of_era - 719_468;
+
+            let seconds = days * 86_400 + hour * 3_600 + minute * 60 + second;
+            Some(std::time::UNIX_EPOCH + std::time::Duration::from_secs(u64::try_from(seconds).ok()?))
+        }
+    }
+}
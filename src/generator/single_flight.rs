@@ -0,0 +1,120 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+
+/// Generate the helpers coalescing concurrent identical `GET` requests
+///
+/// Only generated with the `single-flight` feature.
+pub fn generate_single_flight_helpers() -> TokenStream2 {
+    if !cfg!(feature = "single-flight") {
+        return quote! {};
+    }
+
+    quote! {
+        /// Requests in flight by key, holding the senders of the calls waiting for them
+        type Flights = std::sync::Arc<std::sync::Mutex<HashMap<String, Box<dyn std::any::Any + Send>>>>;
+
+        /// Requests in flight of a client
+        ///
+        /// Clones of the client start without requests in flight, as they may
+        /// send their requests with other credentials or settings.
+        #[derive(Default)]
+        struct InFlight(Flights);
+
+        impl Clone for InFlight {
+            fn clone(&self) -> Self {
+                Self::default()
+            }
+        }
+
+        /// Senders of the calls waiting for a request in flight
+        type FlightWaiters<T> = Vec<futures::channel::oneshot::Sender<ApiResult<T>>>;
+
+        /// Role of a call in the single flight of its request
+        enum Flight<T> {
+            /// No identical request is in flight: send it and share the result
+            Lead(FlightGuard<T>),
+            /// An identical request is in flight: wait for its result
+            Wait(futures::channel::oneshot::Receiver<ApiResult<T>>),
+            /// An identical request is in flight for another response type
+            Alone,
+        }
+
+        /// Join the flight of a request, leading it if no identical request is in flight
+        ///
+        /// Requests are identical if their keys are, which hold everything
+        /// distinguishing the requests: the method, URL, header and cookie
+        /// parameters, and credentials.
+        fn join_flight<T: Send + 'static>(in_flight: &InFlight, key: String) -> Flight<T> {
+            let mut flights = in_flight.0.lock().unwrap();
+            match flights.get_mut(&key) {
+                Some(waiters) => match waiters.downcast_mut::<FlightWaiters<T>>() {
+                    Some(waiters) => {
+                        let (sender, receiver) = futures::channel::oneshot::channel();
+                        waiters.push(sender);
+                        Flight::Wait(receiver)
+                    }
+                    None => Flight::Alone,
+                },
+                None => {
+                    flights.insert(key.clone(), Box::new(FlightWaiters::<T>::new()));
+                    Flight::Lead(FlightGuard {
+                        flights: in_flight.0.clone(),
+                        key: Some(key),
+                        marker: std::marker::PhantomData,
+                    })
+                }
+            }
+        }
+
+        /// Ends the flight of a request when its leading call completes or is dropped
+        ///
+        /// Waiting calls of a dropped leader send the request themselves.
+        struct FlightGuard<T> {
+            flights: Flights,
+            key: Option<String>,
+            marker: std::marker::PhantomData<fn() -> T>,
+        }
+
+        impl<T: Clone + Send + 'static> FlightGuard<T> {
+            /// Share the result of the request with the waiting calls
+            fn land(mut self, result: &ApiResult<T>) {
+                let Some(key) = self.key.take() else {
+                    return;
+                };
+                let waiters = self.flights.lock().unwrap().remove(&key);
+                let Some(Ok(waiters)) = waiters.map(|waiters| waiters.downcast::<FlightWaiters<T>>())
+                else {
+                    return;
+                };
+
+                for waiter in *waiters {
+                    let shared = match result {
+                        Ok(value) => Ok(value.clone()),
+                        Err(ApiError::Api { status, message }) => Err(ApiError::Api {
+                            status: *status,
+                            message: message.clone(),
+                        }),
+                        Err(ApiError::ApiTyped { status, body }) => Err(ApiError::ApiTyped {
+                            status: *status,
+                            body: body.clone(),
+                        }),
+                        Err(ApiError::Redirect { status, location }) => Err(ApiError::Redirect {
+                            status: *status,
+                            location: location.clone(),
+                        }),
+                        Err(e) => Err(ApiError::SharedRequest(e.to_string())),
+                    };
+                    let _ = waiter.send(shared);
+                }
+            }
+        }
+
+        impl<T> Drop for FlightGuard<T> {
+            fn drop(&mut self) {
+                if let Some(key) = self.key.take() {
+                    self.flights.lock().unwrap().remove(&key);
+                }
+            }
+        }
+    }
+}
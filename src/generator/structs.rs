@@ -1,52 +1,399 @@
 use heck::{ToPascalCase, ToSnakeCase};
 use openapiv3::{
-    ObjectType, OpenAPI, ReferenceOr, Schema, SchemaData, SchemaKind, StringType, Type,
+    AdditionalProperties, ArrayType, ObjectType, OpenAPI, ReferenceOr, Schema, SchemaKind,
+    StringType, Type,
 };
-use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::{Ident, Literal, TokenStream as TokenStream2};
 use quote::{format_ident, quote};
-use std::collections::HashSet;
+use syn::ext::IdentExt;
 
-use crate::codegen::schema_to_rust_type;
-use crate::generator::docs::generate_doc_comment;
+use crate::codegen::{
+    additional_properties_type, generate_multipart_body_struct, inline_request_body_object,
+    operation_id_or_default, reference_or_schema_to_rust_type, request_body_schema,
+    request_body_struct_name, schema_to_rust_type, single_ref_all_of, sorted_schemas,
+    spec_operations,
+};
+use crate::generator::docs::{generate_doc_alias, generate_doc_comment};
+use crate::generator::errors::validation_error_name;
+use crate::generator::methods::json_response_schema;
+use crate::generator::response_headers::generate_response_headers_struct;
+use crate::parser::{OpenApiInput, resolve_schema_reference};
 use crate::utils::create_rust_safe_ident;
 
 /// Generate all structs from OpenAPI components
-pub fn generate_structs(
-    spec: &OpenAPI,
-    struct_attrs: &[TokenStream2],
-) -> Result<TokenStream2, String> {
+pub fn generate_structs(spec: &OpenAPI, input: &OpenApiInput) -> Result<TokenStream2, String> {
     let mut generated_structs = TokenStream2::new();
 
     if let Some(components) = &spec.components {
-        for (name, schema_ref) in &components.schemas {
+        for (name, schema_ref) in sorted_schemas(components) {
             match schema_ref {
-                ReferenceOr::Reference { .. } => {
-                    // Skip references for now, they should be resolved elsewhere
-                    continue;
+                ReferenceOr::Reference { reference } => {
+                    // Schemas referencing another schema are aliases of its type, so
+                    // fields referencing them resolve to a generated type
+                    if reference.starts_with("#/components/schemas/") {
+                        resolve_schema_reference(spec, reference)?;
+                    }
+                    let alias = format_ident!("{}", name.to_pascal_case());
+                    let rust_type = reference_or_schema_to_rust_type(schema_ref)?;
+                    generated_structs.extend(quote! {
+                        pub type #alias = #rust_type;
+                    });
                 }
                 ReferenceOr::Item(schema) => {
-                    let struct_tokens = generate_struct_from_schema(name, schema, struct_attrs)?;
+                    let struct_tokens = generate_struct_from_schema(spec, name, schema, input)?;
                     generated_structs.extend(struct_tokens);
                 }
             }
         }
     }
 
+    // Generate structs for inline request body objects
+    for (path, method, operation) in spec_operations(spec)? {
+        let operation_id = operation_id_or_default(operation, method, path);
+        if let Some(schema) = inline_request_body_object(operation) {
+            let struct_name = request_body_struct_name(&operation_id);
+            let struct_tokens = generate_struct_from_schema(spec, &struct_name, schema, input)?;
+            generated_structs.extend(struct_tokens);
+        }
+
+        // Generate structs for documented response headers
+        generated_structs.extend(generate_response_headers_struct(
+            spec,
+            operation,
+            &operation_id,
+        )?);
+
+        // Generate structs for multipart form bodies
+        if cfg!(feature = "multipart") {
+            generated_structs.extend(generate_multipart_body_struct(operation, &operation_id)?);
+        }
+    }
+
+    if input.apply_patch {
+        generated_structs.extend(generate_patch_impls(spec)?);
+    }
+
+    if input.nested_getters {
+        generated_structs.extend(generate_nested_getters(spec)?);
+    }
+
     Ok(generated_structs)
 }
 
+/// Field on the way from a struct to a nested leaf field
+#[derive(Clone)]
+struct NestedStep {
+    ident: Ident,
+    is_optional: bool,
+}
+
+/// Scalar field reachable from a struct through nested structs
+struct NestedLeaf {
+    steps: Vec<NestedStep>,
+    leaf: NestedStep,
+    leaf_type: TokenStream2,
+}
+
+/// Generate accessors for scalar fields nested in optional structs
+///
+/// Every component struct gets a method per scalar field reachable through
+/// at least one optional struct field, named after that field and returning
+/// `None` when any optional field on the way is unset. Names matching a
+/// field of the struct itself, or more than one nested field, are skipped.
+fn generate_nested_getters(spec: &OpenAPI) -> Result<TokenStream2, String> {
+    let Some(components) = &spec.components else {
+        return Ok(quote! {});
+    };
+
+    let mut impls = TokenStream2::new();
+    for (name, schema_ref) in sorted_schemas(components) {
+        let ReferenceOr::Item(schema) = schema_ref else {
+            continue;
+        };
+        let SchemaKind::Type(Type::Object(obj)) = &schema.schema_kind else {
+            continue;
+        };
+
+        let mut leaves = Vec::new();
+        collect_nested_leaves(
+            spec,
+            name,
+            obj,
+            &mut vec![name.clone()],
+            &mut Vec::new(),
+            &mut leaves,
+        )?;
+
+        let own_fields: Vec<_> = obj
+            .properties
+            .keys()
+            .map(|field| field_ident(field))
+            .collect();
+        let getters = leaves.iter().filter_map(|nested| {
+            let name = &nested.leaf.ident;
+            let is_unique = leaves
+                .iter()
+                .filter(|other| other.leaf.ident == *name)
+                .count()
+                == 1;
+            if !is_unique || own_fields.contains(name) {
+                return None;
+            }
+
+            let mut access = quote! { self };
+            for step in &nested.steps {
+                let ident = &step.ident;
+                access = if step.is_optional {
+                    quote! { #access.#ident.as_ref()? }
+                } else {
+                    quote! { #access.#ident }
+                };
+            }
+            let body = if nested.leaf.is_optional {
+                quote! { #access.#name.as_ref() }
+            } else {
+                quote! { Some(&#access.#name) }
+            };
+            let path = nested
+                .steps
+                .iter()
+                .chain(std::iter::once(&nested.leaf))
+                .map(|step| serde_field_name(&step.ident))
+                .collect::<Vec<_>>()
+                .join(".");
+            let doc = format!(
+                "Get `{}`, if the optional fields leading to it are set",
+                path
+            );
+            let leaf_type = &nested.leaf_type;
+
+            Some(quote! {
+                #[doc = #doc]
+                pub fn #name(&self) -> Option<&#leaf_type> {
+                    #body
+                }
+            })
+        });
+        let getters: Vec<_> = getters.collect();
+
+        if !getters.is_empty() {
+            let struct_name = format_ident!("{}", name.to_pascal_case());
+            impls.extend(quote! {
+                impl #struct_name {
+                    #(#getters)*
+                }
+            });
+        }
+    }
+
+    Ok(impls)
+}
+
+/// Collect the scalar fields nested in the struct fields of an object
+///
+/// Only fields reached through at least one optional struct field are
+/// collected. Structs already on the way are not entered again.
+fn collect_nested_leaves(
+    spec: &OpenAPI,
+    struct_name: &str,
+    obj: &ObjectType,
+    visited: &mut Vec<String>,
+    steps: &mut Vec<NestedStep>,
+    leaves: &mut Vec<NestedLeaf>,
+) -> Result<(), String> {
+    for (field_name, field_schema_ref) in &obj.properties {
+        let step = NestedStep {
+            ident: field_ident(field_name),
+            is_optional: is_optional_field(spec, obj, field_name, field_schema_ref)?,
+        };
+
+        let reference = match field_schema_ref {
+            ReferenceOr::Reference { reference } => Some(reference),
+            ReferenceOr::Item(schema) => match single_ref_all_of(schema) {
+                Some(ReferenceOr::Reference { reference }) => Some(reference),
+                _ => None,
+            },
+        };
+        let schema = match reference {
+            Some(reference) if reference.starts_with("#/components/schemas/") => {
+                resolve_schema_reference(spec, reference)?
+            }
+            Some(_) => continue,
+            None => match field_schema_ref {
+                ReferenceOr::Item(schema) => schema,
+                ReferenceOr::Reference { .. } => continue,
+            },
+        };
+
+        match &schema.schema_kind {
+            SchemaKind::Type(Type::Object(nested)) => {
+                let Some(type_name) =
+                    reference.and_then(|reference| reference.strip_prefix("#/components/schemas/"))
+                else {
+                    continue;
+                };
+                if visited.iter().any(|name| name == type_name) {
+                    continue;
+                }
+
+                visited.push(type_name.to_string());
+                steps.push(step);
+                collect_nested_leaves(spec, type_name, nested, visited, steps, leaves)?;
+                steps.pop();
+                visited.pop();
+            }
+            SchemaKind::Type(
+                Type::String(_) | Type::Integer(_) | Type::Number(_) | Type::Boolean(_),
+            ) if steps.iter().any(|step| step.is_optional) => {
+                leaves.push(NestedLeaf {
+                    steps: steps.clone(),
+                    leaf: step,
+                    leaf_type: field_base_type(struct_name, field_schema_ref)?,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Generate `apply_patch` methods merging update structs onto the full structs they update
+///
+/// An update struct is the component a `PUT` or `PATCH` operation takes as its
+/// body when all of its fields are optional, and its full struct is the
+/// component the operation responds with. Every field of the update struct
+/// must exist on the full struct with the same type. Only the first update
+/// struct of a full struct gets an `apply_patch` method.
+fn generate_patch_impls(spec: &OpenAPI) -> Result<TokenStream2, String> {
+    let mut impls = TokenStream2::new();
+    let mut patched = Vec::new();
+
+    for (_, method, operation) in spec_operations(spec)? {
+        if method != "put" && method != "patch" {
+            continue;
+        }
+
+        let Some(ReferenceOr::Reference { reference }) = request_body_schema(operation) else {
+            continue;
+        };
+        let Some(ReferenceOr::Reference {
+            reference: full_reference,
+        }) = json_response_schema(spec, operation)?
+        else {
+            continue;
+        };
+        let (Some(update_name), Some(full_name)) = (
+            reference.strip_prefix("#/components/schemas/"),
+            full_reference.strip_prefix("#/components/schemas/"),
+        ) else {
+            continue;
+        };
+        if update_name == full_name || patched.contains(&full_name) {
+            continue;
+        }
+
+        let (SchemaKind::Type(Type::Object(update_obj)), SchemaKind::Type(Type::Object(full_obj))) = (
+            &resolve_schema_reference(spec, reference)?.schema_kind,
+            &resolve_schema_reference(spec, full_reference)?.schema_kind,
+        ) else {
+            continue;
+        };
+        if !update_obj.required.is_empty() || update_obj.properties.is_empty() {
+            continue;
+        }
+
+        let mut assignments = Vec::new();
+        for (field_name, update_schema_ref) in &update_obj.properties {
+            let Some(full_schema_ref) = full_obj.properties.get(field_name) else {
+                break;
+            };
+            let update_type = field_base_type(update_name, update_schema_ref)?;
+            let full_type = field_base_type(full_name, full_schema_ref)?;
+            if update_type.to_string() != full_type.to_string() {
+                break;
+            }
+
+            let field_ident = create_rust_safe_ident(&field_name.to_snake_case());
+            let value = if is_optional_field(spec, full_obj, field_name, full_schema_ref)? {
+                quote! { Some(value.clone()) }
+            } else {
+                quote! { value.clone() }
+            };
+            assignments.push(quote! {
+                if let Some(value) = &patch.#field_ident {
+                    self.#field_ident = #value;
+                }
+            });
+        }
+        if assignments.len() != update_obj.properties.len() {
+            continue;
+        }
+
+        let full_ident = format_ident!("{}", full_name.to_pascal_case());
+        let update_ident = format_ident!("{}", update_name.to_pascal_case());
+        let doc = format!(
+            "Overwrite the fields set in a [`{}`] patch, predicting the result of the update",
+            update_ident
+        );
+        impls.extend(quote! {
+            impl #full_ident {
+                #[doc = #doc]
+                pub fn apply_patch(&mut self, patch: &#update_ident) {
+                    #(#assignments)*
+                }
+            }
+        });
+        patched.push(full_name);
+    }
+
+    Ok(impls)
+}
+
 /// Generate a struct from an OpenAPI schema
 fn generate_struct_from_schema(
+    spec: &OpenAPI,
     name: &str,
     schema: &Schema,
-    struct_attrs: &[TokenStream2],
+    input: &OpenApiInput,
 ) -> Result<TokenStream2, String> {
     let struct_name = format_ident!("{}", name.to_pascal_case());
     let doc_comment = generate_doc_comment(schema.schema_data.description.as_deref());
+    let struct_attrs = &input.struct_attrs;
+    let value_conversions = generate_value_conversions(&struct_name, input);
+    let derives = schema_derives(name, schema)?;
 
     match &schema.schema_kind {
+        // Objects with only additional properties are maps
+        SchemaKind::Type(Type::Object(obj))
+            if obj.properties.is_empty() && additional_properties_type(obj)?.is_some() =>
+        {
+            let rust_type = schema_to_rust_type(schema)?;
+            Ok(quote! {
+                #doc_comment
+                pub type #struct_name = #rust_type;
+            })
+        }
         SchemaKind::Type(Type::Object(obj)) => {
-            let fields = generate_struct_fields_from_object(name, obj, &schema.schema_data)?;
+            let rename_all = infer_rename_all(obj);
+            let mut fields =
+                generate_struct_fields_from_object(spec, name, obj, rename_all, input)?;
+            fields.extend(generate_additional_properties_field(obj)?);
+            let rename_all_attr = match rename_all {
+                Some(rule) => quote! { #[serde(rename_all = #rule)] },
+                None => quote! {},
+            };
+            let deny_unknown_fields_attr = if denies_unknown_fields(obj) {
+                quote! { #[serde(deny_unknown_fields)] }
+            } else {
+                quote! {}
+            };
+
+            let validate_method = if input.validate {
+                generate_validate_method(spec, name, &struct_name, obj)?
+            } else {
+                quote! {}
+            };
 
             // Convert user attribute token streams to attributes
             let user_attrs = struct_attrs.iter().map(|tokens| {
@@ -56,10 +403,16 @@ fn generate_struct_from_schema(
             Ok(quote! {
                 #doc_comment
                 #(#user_attrs)*
-                #[derive(Debug, Clone, Serialize, Deserialize)]
+                #[derive(#(#derives),*)]
+                #rename_all_attr
+                #deny_unknown_fields_attr
                 pub struct #struct_name {
                     #fields
                 }
+
+                #validate_method
+
+                #value_conversions
             })
         }
         SchemaKind::Type(Type::String(string_schema)) if !string_schema.enumeration.is_empty() => {
@@ -73,10 +426,124 @@ fn generate_struct_from_schema(
             Ok(quote! {
                 #doc_comment
                 #(#user_attrs)*
-                #[derive(Debug, Clone, Serialize, Deserialize)]
+                #[derive(#(#derives),*)]
                 pub enum #struct_name {
                     #variants
                 }
+
+                #value_conversions
+            })
+        }
+        SchemaKind::Type(Type::Integer(int_schema)) if !int_schema.enumeration.is_empty() => {
+            let values: Vec<i64> = int_schema.enumeration.iter().flatten().copied().collect();
+            let variant_names = integer_enum_variant_names(name, schema, &values)?;
+            let variants = variant_names.iter();
+            let from_arms = variant_names
+                .iter()
+                .zip(&values)
+                .map(|(variant, value)| quote! { #struct_name::#variant => #value, });
+            let try_from_arms = variant_names
+                .iter()
+                .zip(&values)
+                .map(|(variant, value)| quote! { #value => Ok(#struct_name::#variant), });
+            let type_name = struct_name.to_string();
+
+            // Convert user attribute token streams to attributes
+            let user_attrs = struct_attrs.iter().map(|tokens| {
+                quote! { #[#tokens] }
+            });
+
+            Ok(quote! {
+                #doc_comment
+                #(#user_attrs)*
+                #[derive(#(#derives),*)]
+                #[serde(try_from = "i64", into = "i64")]
+                pub enum #struct_name {
+                    #(#variants,)*
+                }
+
+                impl From<#struct_name> for i64 {
+                    fn from(value: #struct_name) -> i64 {
+                        match value {
+                            #(#from_arms)*
+                        }
+                    }
+                }
+
+                impl std::fmt::Display for #struct_name {
+                    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(f, "{}", i64::from(self.clone()))
+                    }
+                }
+
+                impl TryFrom<i64> for #struct_name {
+                    type Error = String;
+
+                    fn try_from(value: i64) -> Result<Self, Self::Error> {
+                        match value {
+                            #(#try_from_arms)*
+                            _ => Err(format!("invalid {} value: {}", #type_name, value)),
+                        }
+                    }
+                }
+
+                #value_conversions
+            })
+        }
+        // `allOf` with a single `$ref` is an alias of the referenced type, like other fields
+        SchemaKind::AllOf { all_of } if single_ref_all_of(schema).is_none() => {
+            let mut merged = ObjectType::default();
+            let mut warnings = TokenStream2::new();
+            merge_all_of(spec, name, all_of, &mut merged, &mut warnings)?;
+
+            let merged_schema = Schema {
+                schema_data: schema.schema_data.clone(),
+                schema_kind: SchemaKind::Type(Type::Object(merged)),
+            };
+            let struct_tokens = generate_struct_from_schema(spec, name, &merged_schema, input)?;
+
+            Ok(quote! {
+                #struct_tokens
+                #warnings
+            })
+        }
+        SchemaKind::OneOf { one_of } => {
+            let enum_tokens = generate_one_of_enum(name, schema, one_of, derives)?;
+            let variant_accessors = if input.variant_accessors {
+                generate_variant_accessors(name, one_of)?
+            } else {
+                quote! {}
+            };
+
+            // Convert user attribute token streams to attributes
+            let user_attrs = struct_attrs.iter().map(|tokens| {
+                quote! { #[#tokens] }
+            });
+
+            Ok(quote! {
+                #doc_comment
+                #(#user_attrs)*
+                #enum_tokens
+
+                #variant_accessors
+
+                #value_conversions
+            })
+        }
+        SchemaKind::AnyOf { any_of } => {
+            let enum_tokens = generate_any_of_enum(spec, name, any_of, derives)?;
+
+            // Convert user attribute token streams to attributes
+            let user_attrs = struct_attrs.iter().map(|tokens| {
+                quote! { #[#tokens] }
+            });
+
+            Ok(quote! {
+                #doc_comment
+                #(#user_attrs)*
+                #enum_tokens
+
+                #value_conversions
             })
         }
         _ => {
@@ -90,57 +557,803 @@ fn generate_struct_from_schema(
     }
 }
 
+/// Generate the `validate` method checking the constraints of the spec on the fields of a struct
+///
+/// Strings are checked against `minLength`, `maxLength` and `pattern`, and
+/// numbers against `minimum` and `maximum`. Fields holding generated structs,
+/// directly or in an array, are validated as well.
+fn generate_validate_method(
+    spec: &OpenAPI,
+    name: &str,
+    struct_name: &Ident,
+    obj: &ObjectType,
+) -> Result<TokenStream2, String> {
+    let error_name = validation_error_name(spec);
+    let mut checks = Vec::new();
+    let mut warnings = TokenStream2::new();
+
+    for (field_name, field_schema_ref) in &obj.properties {
+        let field_checks = field_constraint_checks(
+            spec,
+            name,
+            field_name,
+            field_schema_ref,
+            &error_name,
+            &mut warnings,
+        )?;
+        if field_checks.is_empty() {
+            continue;
+        }
+
+        let field_ident = field_ident(field_name);
+        checks.push(
+            if is_optional_field(spec, obj, field_name, field_schema_ref)? {
+                quote! {
+                    if let Some(value) = &self.#field_ident {
+                        #field_checks
+                    }
+                }
+            } else {
+                quote! {
+                    {
+                        let value = &self.#field_ident;
+                        #field_checks
+                    }
+                }
+            },
+        );
+    }
+
+    Ok(quote! {
+        impl #struct_name {
+            /// Check the fields against the constraints declared in the spec
+            ///
+            /// Returns the first field violating a constraint, e.g. to reject
+            /// invalid input before sending it.
+            pub fn validate(&self) -> Result<(), #error_name> {
+                #(#checks)*
+                Ok(())
+            }
+        }
+
+        #warnings
+    })
+}
+
+/// Generate the checks of the constraints on a field, with its value bound to `value`
+///
+/// Patterns the `regex` crate doesn't support are skipped with a warning.
+fn field_constraint_checks(
+    spec: &OpenAPI,
+    struct_name: &str,
+    field_name: &str,
+    field_schema_ref: &ReferenceOr<Box<Schema>>,
+    error_name: &Ident,
+    warnings: &mut TokenStream2,
+) -> Result<TokenStream2, String> {
+    let Some((schema, is_component)) = resolve_field_schema(spec, field_schema_ref)? else {
+        return Ok(quote! {});
+    };
+
+    // Generated structs validate their own fields
+    if is_component && is_generated_struct(schema)? {
+        return Ok(quote! {
+            value.validate().map_err(|e| #error_name {
+                field: format!("{}.{}", #field_name, e.field),
+                message: e.message,
+            })?;
+        });
+    }
+    if let SchemaKind::Type(Type::Array(ArrayType {
+        items: Some(items), ..
+    })) = &schema.schema_kind
+    {
+        return Ok(match resolve_field_schema(spec, items)? {
+            Some((item_schema, true)) if is_generated_struct(item_schema)? => {
+                let validate_item = quote! {
+                    item.validate().map_err(|e| #error_name {
+                        field: format!("{}[{}].{}", #field_name, index, e.field),
+                        message: e.message,
+                    })?;
+                };
+                // Nullable items are an `Option`, with `null` items being valid
+                let validate_item = if is_nullable(spec, items)? {
+                    quote! {
+                        if let Some(item) = item {
+                            #validate_item
+                        }
+                    }
+                } else {
+                    validate_item
+                };
+                quote! {
+                    for (index, item) in value.iter().enumerate() {
+                        #validate_item
+                    }
+                }
+            }
+            _ => quote! {},
+        });
+    }
+
+    let mut checks = TokenStream2::new();
+    let mut check = |violated: TokenStream2, message: String| {
+        checks.extend(quote! {
+            if #violated {
+                return Err(#error_name {
+                    field: #field_name.to_string(),
+                    message: #message.to_string(),
+                });
+            }
+        });
+    };
+
+    let rust_type = schema_to_rust_type(schema)?.to_string();
+    match &schema.schema_kind {
+        SchemaKind::Type(Type::String(string_schema))
+            if string_schema.enumeration.is_empty() && rust_type == "String" =>
+        {
+            if let Some(min_length) = string_schema.min_length {
+                check(
+                    quote! { value.chars().count() < #min_length },
+                    format!("must be at least {} characters long", min_length),
+                );
+            }
+            if let Some(max_length) = string_schema.max_length {
+                check(
+                    quote! { value.chars().count() > #max_length },
+                    format!("must be at most {} characters long", max_length),
+                );
+            }
+            if let Some(pattern) = &string_schema.pattern {
+                match regex::Regex::new(pattern) {
+                    Ok(_) => check(
+                        quote! {{
+                            static PATTERN: std::sync::OnceLock<regex::Regex> =
+                                std::sync::OnceLock::new();
+                            !PATTERN
+                                .get_or_init(|| regex::Regex::new(#pattern).unwrap())
+                                .is_match(value)
+                        }},
+                        format!("must match the pattern `{}`", pattern),
+                    ),
+                    Err(e) => warnings.extend(generate_warning(&format!(
+                        "The pattern of {}.{} is not validated: {}",
+                        struct_name, field_name, e
+                    ))),
+                }
+            }
+        }
+        SchemaKind::Type(Type::Integer(int_schema)) if int_schema.enumeration.is_empty() => {
+            if let Some(minimum) = int_schema.minimum {
+                let limit = Literal::i128_unsuffixed(minimum.into());
+                if int_schema.exclusive_minimum {
+                    check(
+                        quote! { i128::from(*value) <= #limit },
+                        format!("must be greater than {}", minimum),
+                    );
+                } else {
+                    check(
+                        quote! { i128::from(*value) < #limit },
+                        format!("must be at least {}", minimum),
+                    );
+                }
+            }
+            if let Some(maximum) = int_schema.maximum {
+                let limit = Literal::i128_unsuffixed(maximum.into());
+                if int_schema.exclusive_maximum {
+                    check(
+                        quote! { i128::from(*value) >= #limit },
+                        format!("must be less than {}", maximum),
+                    );
+                } else {
+                    check(
+                        quote! { i128::from(*value) > #limit },
+                        format!("must be at most {}", maximum),
+                    );
+                }
+            }
+        }
+        SchemaKind::Type(Type::Number(num_schema)) if rust_type == "f64" || rust_type == "f32" => {
+            if let Some(minimum) = num_schema.minimum {
+                let limit = Literal::f64_unsuffixed(minimum);
+                if num_schema.exclusive_minimum {
+                    check(
+                        quote! { f64::from(*value) <= #limit },
+                        format!("must be greater than {}", minimum),
+                    );
+                } else {
+                    check(
+                        quote! { f64::from(*value) < #limit },
+                        format!("must be at least {}", minimum),
+                    );
+                }
+            }
+            if let Some(maximum) = num_schema.maximum {
+                let limit = Literal::f64_unsuffixed(maximum);
+                if num_schema.exclusive_maximum {
+                    check(
+                        quote! { f64::from(*value) >= #limit },
+                        format!("must be less than {}", maximum),
+                    );
+                } else {
+                    check(
+                        quote! { f64::from(*value) > #limit },
+                        format!("must be at most {}", maximum),
+                    );
+                }
+            }
+        }
+        _ => {}
+    }
+
+    Ok(checks)
+}
+
+/// Get the schema of a field, resolving a reference to a component schema
+///
+/// Also returns whether the field references a component schema. References
+/// outside the component schemas are not resolved.
+fn resolve_field_schema<'a>(
+    spec: &'a OpenAPI,
+    field_schema_ref: &'a ReferenceOr<Box<Schema>>,
+) -> Result<Option<(&'a Schema, bool)>, String> {
+    let reference = match field_schema_ref {
+        ReferenceOr::Reference { reference } => reference,
+        ReferenceOr::Item(schema) => match single_ref_all_of(schema) {
+            Some(ReferenceOr::Reference { reference }) => reference,
+            _ => return Ok(Some((schema, false))),
+        },
+    };
+
+    if reference.starts_with("#/components/schemas/") {
+        Ok(Some((resolve_schema_reference(spec, reference)?, true)))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Check whether a component schema is generated as a struct, rather than an enum or type alias
+fn is_generated_struct(schema: &Schema) -> Result<bool, String> {
+    Ok(match &schema.schema_kind {
+        SchemaKind::Type(Type::Object(obj)) => {
+            !obj.properties.is_empty() || additional_properties_type(obj)?.is_none()
+        }
+        SchemaKind::AllOf { .. } => single_ref_all_of(schema).is_none(),
+        _ => false,
+    })
+}
+
+/// Merge the properties of the schemas of an `allOf` into one object type
+///
+/// Referenced schemas and nested `allOf`s are merged as well. When schemas
+/// define a property differently, the last definition is used and a warning
+/// is emitted. `additionalProperties` isn't merged, as every schema would
+/// reject the properties of the others.
+fn merge_all_of(
+    spec: &OpenAPI,
+    name: &str,
+    all_of: &[ReferenceOr<Schema>],
+    merged: &mut ObjectType,
+    warnings: &mut TokenStream2,
+) -> Result<(), String> {
+    for schema_ref in all_of {
+        let schema = match schema_ref {
+            ReferenceOr::Reference { reference } => resolve_schema_reference(spec, reference)?,
+            ReferenceOr::Item(schema) => schema,
+        };
+        let (properties, required) = match &schema.schema_kind {
+            SchemaKind::Type(Type::Object(obj)) => (&obj.properties, &obj.required),
+            SchemaKind::Any(any) => (&any.properties, &any.required),
+            SchemaKind::AllOf { all_of } => {
+                merge_all_of(spec, name, all_of, merged, warnings)?;
+                continue;
+            }
+            _ => return Err(format!("allOf of {} can only combine object schemas", name)),
+        };
+
+        for (property, property_schema) in properties {
+            let previous = merged
+                .properties
+                .insert(property.clone(), property_schema.clone());
+            if previous.is_some_and(|previous| previous != *property_schema) {
+                warnings.extend(generate_warning(&format!(
+                    "Property `{}` of {} is defined differently by the schemas of its allOf, the last definition is used",
+                    property, name
+                )));
+            }
+        }
+        for property in required {
+            if !merged.required.contains(property) {
+                merged.required.push(property.clone());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Generate code emitting a compiler warning with the given message
+///
+/// Proc macros can't emit warnings on stable Rust, so the warning is the
+/// deprecation note of an item used right away.
+fn generate_warning(message: &str) -> TokenStream2 {
+    quote! {
+        const _: () = {
+            #[deprecated(note = #message)]
+            struct Warning;
+            let _ = Warning;
+        };
+    }
+}
+
+/// Variant of an enum generated from a `oneOf` or `anyOf` schema
+struct OneOfVariant {
+    ident: Ident,
+    rust_type: TokenStream2,
+    /// Name of the referenced component schema
+    schema_name: Option<String>,
+}
+
+/// Generate an enum with a variant per schema of a `oneOf`
+///
+/// Variants are named after the referenced schemas, or the `title` of inline
+/// schemas. Without a `discriminator`, the enum is untagged and the first
+/// matching variant is used. With one, the variant is picked by the value of
+/// the discriminator property, which is kept in the serialized variant.
+fn generate_one_of_enum(
+    name: &str,
+    schema: &Schema,
+    one_of: &[ReferenceOr<Schema>],
+    mut derives: Vec<syn::Path>,
+) -> Result<TokenStream2, String> {
+    let enum_name = format_ident!("{}", name.to_pascal_case());
+    let variants = schema_variants(name, one_of)?;
+    let variant_idents: Vec<_> = variants.iter().map(|v| &v.ident).collect();
+    let variant_types: Vec<_> = variants.iter().map(|v| &v.rust_type).collect();
+
+    // Discriminators only apply when every variant is a referenced schema
+    let discriminator = schema
+        .schema_data
+        .discriminator
+        .as_ref()
+        .filter(|_| variants.iter().all(|v| v.schema_name.is_some()));
+    let Some(discriminator) = discriminator else {
+        return Ok(quote! {
+            #[derive(#(#derives),*)]
+            #[serde(untagged)]
+            pub enum #enum_name {
+                #(#variant_idents(#variant_types),)*
+            }
+        });
+    };
+
+    // Tags come from the discriminator mapping, and default to the schema name
+    let property = &discriminator.property_name;
+    let tags: Vec<String> = variants
+        .iter()
+        .filter_map(|v| v.schema_name.as_deref())
+        .map(|schema_name| {
+            let reference = format!("#/components/schemas/{}", schema_name);
+            discriminator
+                .mapping
+                .iter()
+                .find(|(_, target)| **target == reference || *target == schema_name)
+                .map_or(schema_name, |(tag, _)| tag.as_str())
+                .to_string()
+        })
+        .collect();
+
+    derives.retain(|derive| {
+        let derive = quote! { #derive }.to_string();
+        derive != "Serialize" && derive != "Deserialize"
+    });
+
+    Ok(quote! {
+        #[derive(#(#derives),*)]
+        pub enum #enum_name {
+            #(#variant_idents(#variant_types),)*
+        }
+
+        impl serde::Serialize for #enum_name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let (tag, value) = match self {
+                    #(#enum_name::#variant_idents(value) => (#tags, serde_json::to_value(value)),)*
+                };
+                let mut value = value.map_err(serde::ser::Error::custom)?;
+                if let serde_json::Value::Object(fields) = &mut value {
+                    fields
+                        .entry(#property)
+                        .or_insert_with(|| serde_json::Value::String(tag.to_string()));
+                }
+                value.serialize(serializer)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for #enum_name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = serde_json::Value::deserialize(deserializer)?;
+                let tag = value
+                    .get(#property)
+                    .and_then(serde_json::Value::as_str)
+                    .ok_or_else(|| serde::de::Error::missing_field(#property))?
+                    .to_string();
+                match tag.as_str() {
+                    #(#tags => serde_json::from_value(value)
+                        .map(#enum_name::#variant_idents)
+                        .map_err(serde::de::Error::custom),)*
+                    other => Err(serde::de::Error::unknown_variant(other, &[#(#tags),*])),
+                }
+            }
+        }
+    })
+}
+
+/// Generate the `as_<variant>` and `is_<variant>` accessors of a `oneOf` enum
+fn generate_variant_accessors(
+    name: &str,
+    one_of: &[ReferenceOr<Schema>],
+) -> Result<TokenStream2, String> {
+    let enum_name = format_ident!("{}", name.to_pascal_case());
+    let accessors = schema_variants(name, one_of)?.into_iter().map(|variant| {
+        let OneOfVariant {
+            ident, rust_type, ..
+        } = variant;
+        let method_name = ident.unraw().to_string().to_snake_case();
+        let as_method = format_ident!("as_{}", method_name);
+        let is_method = format_ident!("is_{}", method_name);
+        let as_doc = format!(
+            "Get the value of the `{}` variant, if it's that variant",
+            ident
+        );
+        let is_doc = format!("Whether this is the `{}` variant", ident);
+
+        quote! {
+            #[doc = #as_doc]
+            pub fn #as_method(&self) -> Option<&#rust_type> {
+                match self {
+                    Self::#ident(value) => Some(value),
+                    #[allow(unreachable_patterns)]
+                    _ => None,
+                }
+            }
+
+            #[doc = #is_doc]
+            pub fn #is_method(&self) -> bool {
+                matches!(self, Self::#ident(_))
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #enum_name {
+            #(#accessors)*
+        }
+    })
+}
+
+/// Get the enum variants of the schemas of a `oneOf` or `anyOf`
+///
+/// Variants are named after the referenced schemas, or the `title` of inline
+/// schemas, and numbered otherwise. References to the schema itself are boxed.
+fn schema_variants(
+    name: &str,
+    schemas: &[ReferenceOr<Schema>],
+) -> Result<Vec<OneOfVariant>, String> {
+    let enum_name = format_ident!("{}", name.to_pascal_case());
+    let mut variants: Vec<OneOfVariant> = Vec::new();
+
+    for (index, schema_ref) in schemas.iter().enumerate() {
+        let (variant_name, rust_type, schema_name) = match schema_ref {
+            ReferenceOr::Reference { reference } => {
+                let schema_name = reference.strip_prefix("#/components/schemas/");
+                let rust_type = match schema_name {
+                    Some(schema_name) if schema_name == name => quote! { Box<#enum_name> },
+                    _ => reference_or_schema_to_rust_type(schema_ref)?,
+                };
+                let variant_name = schema_name.map(|schema_name| schema_name.to_pascal_case());
+                (variant_name, rust_type, schema_name.map(str::to_string))
+            }
+            ReferenceOr::Item(item) => {
+                let variant_name = item
+                    .schema_data
+                    .title
+                    .as_deref()
+                    .map(ToPascalCase::to_pascal_case);
+                (variant_name, schema_to_rust_type(item)?, None)
+            }
+        };
+
+        // Variants without a name, or with the name of an earlier one, are numbered
+        let variant_name = variant_name
+            .filter(|variant_name| !variant_name.is_empty())
+            .filter(|variant_name| !variants.iter().any(|v| v.ident == variant_name))
+            .unwrap_or_else(|| format!("Variant{}", index + 1));
+        variants.push(OneOfVariant {
+            ident: create_rust_safe_ident(&variant_name),
+            rust_type,
+            schema_name,
+        });
+    }
+
+    Ok(variants)
+}
+
+/// Generate an untagged enum with a variant per schema of an `anyOf`
+///
+/// Variants are ordered from the most to the least specific schema, as serde
+/// picks the first one matching, e.g. objects with more required properties
+/// before those with fewer and integers before numbers. Values matching none
+/// of them end up in a final `serde_json::Value` variant.
+fn generate_any_of_enum(
+    spec: &OpenAPI,
+    name: &str,
+    any_of: &[ReferenceOr<Schema>],
+    derives: Vec<syn::Path>,
+) -> Result<TokenStream2, String> {
+    let enum_name = format_ident!("{}", name.to_pascal_case());
+    let variants = schema_variants(name, any_of)?;
+
+    let mut ranked = Vec::new();
+    for (variant, schema_ref) in variants.into_iter().zip(any_of) {
+        let schema = match schema_ref {
+            ReferenceOr::Reference { reference } => resolve_schema_reference(spec, reference)?,
+            ReferenceOr::Item(schema) => schema,
+        };
+        ranked.push((schema_specificity(schema), variant));
+    }
+    ranked.sort_by_key(|(specificity, _)| *specificity);
+
+    let variant_idents: Vec<_> = ranked.iter().map(|(_, v)| &v.ident).collect();
+    let variant_types: Vec<_> = ranked.iter().map(|(_, v)| &v.rust_type).collect();
+    let other_ident = if variant_idents.iter().any(|ident| *ident == "Other") {
+        format_ident!("Variant{}", variant_idents.len() + 1)
+    } else {
+        format_ident!("Other")
+    };
+
+    Ok(quote! {
+        #[derive(#(#derives),*)]
+        #[serde(untagged)]
+        pub enum #enum_name {
+            #(#variant_idents(#variant_types),)*
+            /// Value matching none of the other variants
+            #other_ident(serde_json::Value),
+        }
+    })
+}
+
+/// Sort key of a schema in an untagged `anyOf` enum, lower keys being more specific
+///
+/// Objects come first, those with more required and then more properties
+/// before others, followed by arrays, integers, numbers, booleans, string
+/// enums and other strings.
+fn schema_specificity(schema: &Schema) -> (u8, std::cmp::Reverse<(usize, usize)>) {
+    let rank = match &schema.schema_kind {
+        SchemaKind::Type(Type::Object(obj)) => {
+            return (
+                0,
+                std::cmp::Reverse((obj.required.len(), obj.properties.len())),
+            );
+        }
+        SchemaKind::AllOf { .. } | SchemaKind::OneOf { .. } | SchemaKind::AnyOf { .. } => 1,
+        SchemaKind::Type(Type::Array(_)) => 2,
+        SchemaKind::Type(Type::Integer(_)) => 3,
+        SchemaKind::Type(Type::Number(_)) => 4,
+        SchemaKind::Type(Type::Boolean(_)) => 5,
+        SchemaKind::Type(Type::String(string)) if !string.enumeration.is_empty() => 6,
+        SchemaKind::Type(Type::String(_)) => 7,
+        _ => 8,
+    };
+    (rank, std::cmp::Reverse((0, 0)))
+}
+
+/// Get the variant names of an integer enum
+///
+/// Names come from the `x-enum-varnames` extension when the spec has one, and
+/// are derived from the values otherwise, e.g. `Value1` and `ValueMinus1`.
+fn integer_enum_variant_names(
+    name: &str,
+    schema: &Schema,
+    values: &[i64],
+) -> Result<Vec<Ident>, String> {
+    let Some(extension) = schema.schema_data.extensions.get("x-enum-varnames") else {
+        return Ok(values
+            .iter()
+            .map(|value| match value.unsigned_abs() {
+                abs if *value < 0 => format_ident!("ValueMinus{}", abs),
+                abs => format_ident!("Value{}", abs),
+            })
+            .collect());
+    };
+
+    let invalid = || {
+        format!(
+            "x-enum-varnames of {} must be an array with a name for every value",
+            name
+        )
+    };
+    let names = extension.as_array().ok_or_else(invalid)?;
+    if names.len() != values.len() {
+        return Err(invalid());
+    }
+    names
+        .iter()
+        .map(|name| {
+            name.as_str()
+                .map(|name| create_rust_safe_ident(&name.to_pascal_case()))
+                .ok_or_else(invalid)
+        })
+        .collect()
+}
+
+/// Get the derives of a generated type: the base set plus the schema's `x-rust-derives`
+fn schema_derives(name: &str, schema: &Schema) -> Result<Vec<syn::Path>, String> {
+    let mut derives: Vec<syn::Path> = vec![
+        syn::parse_quote!(Debug),
+        syn::parse_quote!(Clone),
+        syn::parse_quote!(Serialize),
+        syn::parse_quote!(Deserialize),
+    ];
+
+    let Some(extension) = schema.schema_data.extensions.get("x-rust-derives") else {
+        return Ok(derives);
+    };
+    let invalid = || {
+        format!(
+            "x-rust-derives of {} must be an array of derive names",
+            name
+        )
+    };
+    for derive in extension.as_array().ok_or_else(invalid)? {
+        let derive: syn::Path = derive
+            .as_str()
+            .and_then(|derive| syn::parse_str(derive).ok())
+            .ok_or_else(invalid)?;
+        let name = quote! { #derive }.to_string();
+        if !derives.iter().any(|d| quote! { #d }.to_string() == name) {
+            derives.push(derive);
+        }
+    }
+
+    Ok(derives)
+}
+
+/// Generate conversions between a generated type and `serde_json::Value`
+fn generate_value_conversions(struct_name: &Ident, input: &OpenApiInput) -> TokenStream2 {
+    if !input.value_conversions {
+        return quote! {};
+    }
+
+    quote! {
+        impl TryFrom<serde_json::Value> for #struct_name {
+            type Error = serde_json::Error;
+
+            fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+                serde_json::from_value(value)
+            }
+        }
+
+        impl From<&#struct_name> for serde_json::Value {
+            fn from(value: &#struct_name) -> Self {
+                serde_json::to_value(value).unwrap_or(serde_json::Value::Null)
+            }
+        }
+
+        impl #struct_name {
+            /// Convert to a `serde_json::Value` without consuming the value
+            pub fn as_json_value(&self) -> serde_json::Value {
+                self.into()
+            }
+        }
+    }
+}
+
+/// Generate the field collecting the additional properties of an object, if it allows them
+///
+/// The properties are flattened into the object, next to its fixed properties.
+/// The field is named `extra`, or `additional_properties` when the object
+/// has an `extra` property of its own.
+fn generate_additional_properties_field(obj: &ObjectType) -> Result<TokenStream2, String> {
+    let Some(value_type) = additional_properties_type(obj)? else {
+        return Ok(quote! {});
+    };
+    let field = if obj
+        .properties
+        .keys()
+        .any(|name| field_ident(name) == "extra")
+    {
+        format_ident!("additional_properties")
+    } else {
+        format_ident!("extra")
+    };
+
+    Ok(quote! {
+        /// Properties besides the ones declared by the schema
+        #[serde(flatten)]
+        pub #field: HashMap<String, #value_type>,
+    })
+}
+
+/// Check whether the struct of an object type rejects unknown fields
+///
+/// Objects setting `additionalProperties: false` are deserialized as strictly
+/// as the server validates them. They never get a flattened field for
+/// additional properties, which `serde` doesn't support together with
+/// `deny_unknown_fields`.
+fn denies_unknown_fields(obj: &ObjectType) -> bool {
+    matches!(
+        obj.additional_properties,
+        Some(AdditionalProperties::Any(false))
+    )
+}
+
 /// Generate struct fields from an object type
 fn generate_struct_fields_from_object(
+    spec: &OpenAPI,
     struct_name: &str,
     obj: &ObjectType,
-    _schema_data: &SchemaData,
+    rename_all: Option<&str>,
+    input: &OpenApiInput,
 ) -> Result<TokenStream2, String> {
     let mut fields = TokenStream2::new();
 
-    let required_fields: HashSet<String> = obj.required.iter().cloned().collect();
-
     for (field_name, field_schema_ref) in &obj.properties {
-        let snake_case_name = field_name.to_snake_case();
-        let field_ident = create_rust_safe_ident(&snake_case_name);
+        let field_ident = field_ident(field_name);
 
         // Generate field documentation and type
-        let (field_type, field_doc) = match field_schema_ref {
-            ReferenceOr::Reference { reference } => {
-                if let Some(type_name) = reference.strip_prefix("#/components/schemas/") {
-                    let type_ident = format_ident!("{}", type_name.to_pascal_case());
-                    let ty = if type_name == struct_name {
-                        quote! { Box<#type_ident> }
-                    } else {
-                        quote! { #type_ident }
-                    };
-                    (ty, quote! {})
-                } else {
-                    (quote! { serde_json::Value }, quote! {})
-                }
-            }
+        let field_type = field_base_type(struct_name, field_schema_ref)?;
+        let field_doc = match field_schema_ref {
+            ReferenceOr::Reference { .. } => quote! {},
             ReferenceOr::Item(schema) => {
-                let rust_type = schema_to_rust_type(schema)?;
-                let doc_comment = generate_doc_comment(schema.schema_data.description.as_deref());
-                (rust_type, doc_comment)
+                generate_doc_comment(schema.schema_data.description.as_deref())
             }
         };
 
-        let field_type = if required_fields.contains(field_name) {
-            field_type
-        } else {
+        // Nullable fields may be sent as `null` even when required
+        let is_optional = is_optional_field(spec, obj, field_name, field_schema_ref)?;
+        let field_type = if is_optional {
             quote! { Option<#field_type> }
+        } else {
+            field_type
         };
 
-        let serde_attr = if field_name != &field_name.to_snake_case() {
+        // Fields not covered by the struct's `rename_all` rule are renamed individually
+        let serde_name = serde_field_name(&field_ident);
+        let serde_attr = if rename_all.is_none() && serde_name != *field_name {
             quote! { #[serde(rename = #field_name)] }
         } else {
             quote! {}
         };
 
+        // Custom (de)serialization module from the `x-serde-with` extension
+        let serde_with_attr = match field_serde_with(struct_name, field_name, field_schema_ref)? {
+            // Missing optional fields are never passed to the module, so default them to `None`
+            Some(module) if is_optional => quote! { #[serde(with = #module, default)] },
+            Some(module) => quote! { #[serde(with = #module)] },
+            None => quote! {},
+        };
+
+        // Leave out empty collections, defaulting them when they are missing
+        let field_type_str = field_type.to_string();
+        let skip_empty_attr = if !input.skip_empty_collections {
+            quote! {}
+        } else if field_type_str.starts_with("Vec <") {
+            quote! { #[serde(default, skip_serializing_if = "Vec::is_empty")] }
+        } else if field_type_str.starts_with("HashMap <") {
+            quote! { #[serde(default, skip_serializing_if = "HashMap::is_empty")] }
+        } else {
+            quote! {}
+        };
+
+        let doc_alias = generate_doc_alias(field_name, &field_ident);
+
         fields.extend(quote! {
             #field_doc
+            #doc_alias
             #serde_attr
+            #serde_with_attr
+            #skip_empty_attr
             pub #field_ident: #field_type,
         });
     }
@@ -148,12 +1361,169 @@ fn generate_struct_fields_from_object(
     Ok(fields)
 }
 
+/// Get the identifier of the struct field for a property
+fn field_ident(field_name: &str) -> Ident {
+    create_rust_safe_ident(&field_name.to_snake_case())
+}
+
+/// Get the name serde uses for a struct field before any renames
+fn serde_field_name(field_ident: &Ident) -> String {
+    let name = field_ident.to_string();
+    name.strip_prefix("r#").unwrap_or(&name).to_string()
+}
+
+/// Infer a `serde(rename_all)` rule covering all properties of an object
+///
+/// Returns `None` when the properties don't need renaming, or when they mix
+/// conventions and are renamed individually.
+fn infer_rename_all(obj: &ObjectType) -> Option<&'static str> {
+    let fields: Vec<_> = obj
+        .properties
+        .keys()
+        .map(|field_name| (serde_field_name(&field_ident(field_name)), field_name))
+        .collect();
+
+    if fields
+        .iter()
+        .all(|(serde_name, field_name)| serde_name == *field_name)
+    {
+        return None;
+    }
+
+    [
+        "camelCase",
+        "PascalCase",
+        "kebab-case",
+        "SCREAMING_SNAKE_CASE",
+        "SCREAMING-KEBAB-CASE",
+    ]
+    .into_iter()
+    .find(|rule| {
+        fields
+            .iter()
+            .all(|(serde_name, field_name)| apply_rename_rule(rule, serde_name) == **field_name)
+    })
+}
+
+/// Rename a snake case field name like serde's `rename_all` rule does
+fn apply_rename_rule(rule: &str, name: &str) -> String {
+    match rule {
+        "camelCase" | "PascalCase" => {
+            let mut renamed = String::new();
+            let mut capitalize = rule == "PascalCase";
+            for c in name.chars() {
+                if c == '_' {
+                    capitalize = true;
+                } else if capitalize {
+                    renamed.push(c.to_ascii_uppercase());
+                    capitalize = false;
+                } else {
+                    renamed.push(c);
+                }
+            }
+            renamed
+        }
+        "kebab-case" => name.replace('_', "-"),
+        "SCREAMING_SNAKE_CASE" => name.to_ascii_uppercase(),
+        "SCREAMING-KEBAB-CASE" => name.to_ascii_uppercase().replace('_', "-"),
+        _ => name.to_string(),
+    }
+}
+
+/// Get the module named by a field's `x-serde-with` extension
+fn field_serde_with(
+    struct_name: &str,
+    field_name: &str,
+    field_schema_ref: &ReferenceOr<Box<Schema>>,
+) -> Result<Option<String>, String> {
+    let ReferenceOr::Item(schema) = field_schema_ref else {
+        return Ok(None);
+    };
+
+    match schema.schema_data.extensions.get("x-serde-with") {
+        Some(serde_json::Value::String(module)) if syn::parse_str::<syn::Path>(module).is_ok() => {
+            Ok(Some(module.clone()))
+        }
+        Some(_) => Err(format!(
+            "x-serde-with of {}.{} must be a module path",
+            struct_name, field_name
+        )),
+        None => Ok(None),
+    }
+}
+
+/// Get the Rust type of a struct field, before wrapping optional fields in `Option`
+///
+/// Fields wrapping a single `$ref` in `allOf` get the referenced type, as
+/// their nullability is handled like that of any other field.
+fn field_base_type(
+    struct_name: &str,
+    field_schema_ref: &ReferenceOr<Box<Schema>>,
+) -> Result<TokenStream2, String> {
+    let reference = match field_schema_ref {
+        ReferenceOr::Reference { reference } => reference,
+        ReferenceOr::Item(schema) => match single_ref_all_of(schema) {
+            Some(ReferenceOr::Reference { reference }) => reference,
+            _ => return schema_to_rust_type(schema),
+        },
+    };
+
+    if let Some(type_name) = reference.strip_prefix("#/components/schemas/") {
+        let type_ident = format_ident!("{}", type_name.to_pascal_case());
+        if type_name == struct_name {
+            Ok(quote! { Box<#type_ident> })
+        } else {
+            Ok(quote! { #type_ident })
+        }
+    } else {
+        Ok(quote! { serde_json::Value })
+    }
+}
+
+/// Check whether a struct field is generated as an `Option`
+fn is_optional_field(
+    spec: &OpenAPI,
+    obj: &ObjectType,
+    field_name: &str,
+    field_schema_ref: &ReferenceOr<Box<Schema>>,
+) -> Result<bool, String> {
+    Ok(!obj.required.iter().any(|required| required == field_name)
+        || is_nullable(spec, field_schema_ref)?)
+}
+
+/// Check whether a field schema, or the component schema it references, is nullable
+fn is_nullable(spec: &OpenAPI, schema_ref: &ReferenceOr<Box<Schema>>) -> Result<bool, String> {
+    match schema_ref {
+        ReferenceOr::Item(schema) => Ok(schema_is_nullable(schema)),
+        ReferenceOr::Reference { reference } if reference.starts_with("#/components/schemas/") => {
+            Ok(schema_is_nullable(resolve_schema_reference(
+                spec, reference,
+            )?))
+        }
+        ReferenceOr::Reference { .. } => Ok(false),
+    }
+}
+
+/// Check whether a schema is nullable, or lists `null` among its enum values
+fn schema_is_nullable(schema: &Schema) -> bool {
+    schema.schema_data.nullable
+        || match &schema.schema_kind {
+            SchemaKind::Type(Type::String(string_schema)) => {
+                string_schema.enumeration.contains(&None)
+            }
+            SchemaKind::Type(Type::Integer(int_schema)) => int_schema.enumeration.contains(&None),
+            _ => false,
+        }
+}
+
 /// Generate enum variants from a string schema
+///
+/// A `null` enum value gets no variant, fields of the enum are an `Option` instead.
 fn generate_enum_variants_from_string(string_schema: &StringType) -> Result<TokenStream2, String> {
     let mut variants = TokenStream2::new();
 
     for value in &string_schema.enumeration {
-        if let Some(variant_str) = value.as_ref().and_then(|v| Some(v.as_str())) {
+        if let Some(variant_str) = value.as_ref().map(|v| v.as_str()) {
             let variant_name = format_ident!("{}", variant_str.to_pascal_case());
             variants.extend(quote! {
                 #[serde(rename = #variant_str)]
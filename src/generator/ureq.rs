@@ -0,0 +1,365 @@
+use openapiv3::OpenAPI;
+use proc_macro2::{Literal, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+
+use crate::codegen::{
+    ParameterLocation, generate_cookie_params, generate_header_params, generate_url_building,
+    operation_id_or_default, operation_json_rpc_method, operation_parameters,
+    operation_success_codes, request_body_type,
+};
+use crate::generator::client::stored_credentials;
+use crate::generator::docs::{generate_doc_alias, generate_method_doc_comment};
+use crate::generator::methods::{
+    client_method_name, determine_return_type_from_operation, generate_method_params,
+    generate_url_building_with_param_structs, sending_method_body,
+};
+use crate::generator::operation_errors::operation_error_name;
+use crate::generator::response_headers::{response_headers, response_headers_struct_name};
+use crate::parser::{ApiKeyLocation, ApiKeyScheme, AuthScheme, OpenApiInput, api_key_scheme};
+
+/// Features relying on `reqwest` or async clients, which can't be combined with the `ureq` feature
+const UNSUPPORTED_FEATURES: [(&str, bool); 14] = [
+    ("middleware", cfg!(feature = "middleware")),
+    ("blocking", cfg!(feature = "blocking")),
+    ("response-validation", cfg!(feature = "response-validation")),
+    ("rate-limit", cfg!(feature = "rate-limit")),
+    ("retry", cfg!(feature = "retry")),
+    ("multipart", cfg!(feature = "multipart")),
+    ("pagination", cfg!(feature = "pagination")),
+    ("cancellation", cfg!(feature = "cancellation")),
+    ("unix-socket", cfg!(feature = "unix-socket")),
+    ("http", cfg!(feature = "http")),
+    ("stream-upload", cfg!(feature = "stream-upload")),
+    ("metrics", cfg!(feature = "metrics")),
+    ("single-flight", cfg!(feature = "single-flight")),
+    ("simd-json", cfg!(feature = "simd-json")),
+];
+
+/// Check that the enabled features and options are supported by the `ureq` backend
+pub fn check_ureq_support(input: &OpenApiInput) -> Result<(), String> {
+    if let Some((feature, _)) = UNSUPPORTED_FEATURES.iter().find(|(_, enabled)| *enabled) {
+        return Err(format!(
+            "The `ureq` feature can't be combined with the `{}` feature",
+            feature
+        ));
+    }
+    if input.etag_cache {
+        return Err("The `etag_cache` option is not supported with the `ureq` feature".to_string());
+    }
+    if input.pagination.is_some() {
+        return Err("The `pagination` option is not supported with the `ureq` feature".to_string());
+    }
+    if input.retry.is_some() {
+        return Err("The `retry` option is not supported with the `ureq` feature".to_string());
+    }
+    if input.client_trait {
+        return Err("The `trait` option is not supported with the `ureq` feature".to_string());
+    }
+    if input.include_response_headers {
+        return Err(
+            "The `include_response_headers` option is not supported with the `ureq` feature"
+                .to_string(),
+        );
+    }
+
+    Ok(())
+}
+
+/// Generate a `ureq` API method from an OpenAPI operation
+///
+/// Methods are blocking and support the same parameters, bodies and
+/// responses as the `reqwest` methods, including the `_with_headers` variant.
+pub fn generate_ureq_client_method(
+    spec: &OpenAPI,
+    path: &str,
+    http_method: &str,
+    operation: &openapiv3::Operation,
+    input: &OpenApiInput,
+) -> Result<TokenStream2, String> {
+    let use_param_structs = input.use_param_structs;
+    let method_name = client_method_name(path, http_method, operation);
+    let operation_id = operation_id_or_default(operation, http_method, path);
+
+    // Methods return the operation's error enum in `operation_errors` mode
+    let error_type = input
+        .operation_errors
+        .then(|| format_ident!("{}", operation_error_name(&operation_id)));
+
+    // JSON-RPC methods are posted to the path without its fragment, which only tells them apart
+    let json_rpc_method = operation_json_rpc_method(operation)?;
+    let url_path = match json_rpc_method {
+        Some(_) => path.split('#').next().unwrap_or(path),
+        None => path,
+    };
+
+    let all_params = operation_parameters(operation)?;
+    let path_params: Vec<_> = all_params
+        .iter()
+        .filter(|p| p.location == ParameterLocation::Path)
+        .collect();
+    let query_params: Vec<_> = all_params
+        .iter()
+        .filter(|p| p.location == ParameterLocation::Query)
+        .collect();
+    let header_params: Vec<_> = all_params
+        .iter()
+        .filter(|p| p.location == ParameterLocation::Header)
+        .collect();
+    let cookie_params: Vec<_> = all_params
+        .iter()
+        .filter(|p| p.location == ParameterLocation::Cookie)
+        .collect();
+
+    let (params, param_access_code) =
+        generate_method_params(path, http_method, operation, &all_params, use_param_structs);
+
+    // The query string is added to the request by `ureq`, which encodes its values
+    let url_building = if use_param_structs {
+        generate_url_building_with_param_structs(url_path, &path_params, &[])
+    } else {
+        generate_url_building(url_path, &path_params, &[])
+    };
+    let query_building = query_params.iter().map(|param| {
+        let param_name = &param.name;
+        let value_ident = if use_param_structs {
+            format_ident!("{}_value", param.ident)
+        } else {
+            param.ident.clone()
+        };
+        let value = param.value_to_string(&value_ident);
+        let append = quote! {
+            request = request.query(#param_name, &#value);
+        };
+
+        if param.required {
+            append
+        } else {
+            quote! {
+                if let Some(#value_ident) = &#value_ident {
+                    #append
+                }
+            }
+        }
+    });
+
+    let http_method_upper = http_method.to_uppercase();
+    let auth = request_auth(spec, input)?;
+    let set_header = |name: &str, value| quote! { request = request.set(#name, &#value); };
+    let mut headers = generate_header_params(&header_params, use_param_structs, set_header);
+    headers.extend(generate_cookie_params(
+        &cookie_params,
+        use_param_structs,
+        set_header,
+    ));
+    let mut request_building = quote! {
+        #[allow(unused_mut)]
+        let mut request = self.client.request(#http_method_upper, &url);
+        #(#query_building)*
+        #auth
+        #headers
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+    };
+
+    // Requests with a body are sent as JSON
+    let mut body_param = TokenStream2::new();
+    let send = if let Some(rpc_method) = &json_rpc_method {
+        // The request body, if any, is sent as the params of the method
+        let rpc_params = if operation.request_body.is_some() {
+            let body_type = request_body_type(operation, &operation_id)?;
+            body_param.extend(quote! { body: #body_type, });
+            quote! { "params": body, }
+        } else {
+            quote! {}
+        };
+        request_building.extend(quote! {
+            let body = serde_json::json!({
+                "jsonrpc": "2.0",
+                "method": #rpc_method,
+                #rpc_params
+                "id": next_json_rpc_id(),
+            });
+        });
+        quote! { Self::send_request(request.send_json(&body))? }
+    } else if operation.request_body.is_some() {
+        let body_type = request_body_type(operation, &operation_id)?;
+        body_param.extend(quote! { body: #body_type, });
+        quote! { Self::send_request(request.send_json(&body))? }
+    } else {
+        quote! { Self::send_request(request.call())? }
+    };
+
+    let (return_type, content_type) = determine_return_type_from_operation(spec, operation)?
+        .unwrap_or_else(|| (quote! { () }, String::new()));
+    let doc_comment = generate_method_doc_comment(spec, operation, path, http_method)?;
+    let doc_alias = match &operation.operation_id {
+        Some(operation_id) => generate_doc_alias(operation_id, &method_name),
+        None => quote! {},
+    };
+
+    // Only the operation's success codes are parsed as the return value
+    let is_success = match operation_success_codes(operation)? {
+        Some(codes) => {
+            let codes = codes.into_iter().map(Literal::u16_unsuffixed);
+            quote! { matches!(response.status(), #(#codes)|*) }
+        }
+        None => quote! { (200..300).contains(&response.status()) },
+    };
+
+    let result = if json_rpc_method.is_some() {
+        quote! {
+            let response: JsonRpcResponse = serde_json::from_reader(response.into_reader())?;
+            response.into_result()
+        }
+    } else if content_type.is_empty() {
+        quote! { Ok(()) }
+    } else if content_type.starts_with("text/") {
+        quote! { Ok(response.into_string()?) }
+    } else {
+        quote! { Ok(serde_json::from_reader(response.into_reader())?) }
+    };
+    let response_error = quote! {
+        else if (300..400).contains(&response.status()) {
+            Err(ApiError::Redirect {
+                status: response.status(),
+                location: response.header("location").map(str::to_string),
+            })
+        } else {
+            let status = response.status();
+            let body = response.into_string().unwrap_or_else(|_| "Unknown error".to_string());
+            Err(ApiError::from_error_response(status, body))
+        }
+    };
+    let response_parsing = quote! {
+        if #is_success {
+            #result
+        } #response_error
+    };
+
+    // Generate a variant also returning the documented response headers
+    let headers_method = if response_headers(spec, operation)?.is_empty() {
+        quote! {}
+    } else {
+        let headers_type = format_ident!("{}", response_headers_struct_name(&operation_id));
+        let headers_name = format_ident!("{}_with_headers", method_name);
+        let headers_doc = format!(
+            "Same as [`Self::{}`], but also returns the documented response headers",
+            method_name
+        );
+        let (headers_result, headers_body) = sending_method_body(
+            &quote! { (#return_type, #headers_type) },
+            quote! {
+                #param_access_code
+                #url_building
+                #request_building
+                let response = #send;
+
+                let headers = #headers_type::from_response(&response);
+                let body: ApiResult<#return_type> = { #response_parsing };
+                Ok((body?, headers))
+            },
+            error_type.as_ref(),
+            true,
+        );
+
+        quote! {
+            #[doc = #headers_doc]
+            pub fn #headers_name(&self, #params #body_param) -> #headers_result {
+                #headers_body
+            }
+        }
+    };
+
+    // Generate a variant returning the raw body, for responses that don't parse, if requested
+    let text_method = if input.text_methods {
+        let text_name = format_ident!("{}_text", method_name);
+        let text_doc = format!(
+            "Same as [`Self::{}`], but returns the raw body of the response instead of parsing it",
+            method_name
+        );
+        let (text_result, text_body) = sending_method_body(
+            &quote! { String },
+            quote! {
+                #param_access_code
+                #url_building
+                #request_building
+                let response = #send;
+
+                if #is_success {
+                    Ok(response.into_string()?)
+                } #response_error
+            },
+            error_type.as_ref(),
+            true,
+        );
+
+        quote! {
+            #[doc = #text_doc]
+            pub fn #text_name(&self, #params #body_param) -> #text_result {
+                #text_body
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let (method_result, method_body) = sending_method_body(
+        &return_type,
+        quote! {
+            #param_access_code
+            #url_building
+            #request_building
+            let response = #send;
+
+            #response_parsing
+        },
+        error_type.as_ref(),
+        true,
+    );
+
+    Ok(quote! {
+        #doc_comment
+        #doc_alias
+        pub fn #method_name(&self, #params #body_param) -> #method_result {
+            #method_body
+        }
+
+        #headers_method
+
+        #text_method
+    })
+}
+
+/// Attach the credentials of the `auth` option to a `ureq` request, if they are set
+fn request_auth(spec: &OpenAPI, input: &OpenApiInput) -> Result<TokenStream2, String> {
+    let credentials = stored_credentials(input);
+    match input.auth {
+        Some(AuthScheme::Bearer) => Ok(quote! {
+            if let Some(token) = #credentials {
+                request = request.set("Authorization", &format!("Bearer {}", token));
+            }
+        }),
+        Some(AuthScheme::ApiKey) => {
+            let ApiKeyScheme { name, location } = api_key_scheme(spec)?;
+            Ok(match location {
+                ApiKeyLocation::Header => quote! {
+                    if let Some(key) = #credentials {
+                        request = request.set(#name, key);
+                    }
+                },
+                ApiKeyLocation::Query => quote! {
+                    if let Some(key) = #credentials {
+                        request = request.query(#name, key);
+                    }
+                },
+            })
+        }
+        Some(AuthScheme::Basic) => Ok(quote! {
+            if let Some((user, password)) = #credentials {
+                request = request.set("Authorization", &basic_auth_header(user, password));
+            }
+        }),
+        None => Ok(quote! {}),
+    }
+}
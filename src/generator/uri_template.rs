@@ -0,0 +1,67 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+
+/// Generate the `UriTemplate` newtype used for `format: uri-template` strings
+///
+/// Only generated with the `uri-template` feature.
+pub fn generate_uri_template_type() -> TokenStream2 {
+    if !cfg!(feature = "uri-template") {
+        return quote! {};
+    }
+
+    quote! {
+        /// A URI template (`format: uri-template`), see [`UriTemplate::expand`]
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+        #[serde(transparent)]
+        pub struct UriTemplate(pub String);
+
+        impl UriTemplate {
+            /// Expand the template, substituting `{var}` expressions with the given variables
+            ///
+            /// Supports simple string expansion only, including lists like
+            /// `{x,y}`. Values are percent-encoded and undefined variables
+            /// are left out.
+            pub fn expand(&self, vars: &HashMap<String, String>) -> String {
+                let mut expanded = String::with_capacity(self.0.len());
+                let mut rest = self.0.as_str();
+                while let Some(start) = rest.find('{') {
+                    let Some(end) = rest[start..].find('}') else {
+                        break;
+                    };
+                    expanded.push_str(&rest[..start]);
+                    let values: Vec<String> = rest[start + 1..start + end]
+                        .split(',')
+                        .filter_map(|name| vars.get(name.trim()))
+                        .map(|value| {
+                            value
+                                .bytes()
+                                .map(|byte| match byte {
+                                    b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                                        (byte as char).to_string()
+                                    }
+                                    _ => format!("%{:02X}", byte),
+                                })
+                                .collect()
+                        })
+                        .collect();
+                    expanded.push_str(&values.join(","));
+                    rest = &rest[start + end + 1..];
+                }
+                expanded.push_str(rest);
+                expanded
+            }
+        }
+
+        impl std::fmt::Display for UriTemplate {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl From<String> for UriTemplate {
+            fn from(template: String) -> Self {
+                Self(template)
+            }
+        }
+    }
+}
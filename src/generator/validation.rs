@@ -0,0 +1,173 @@
+use openapiv3::OpenAPI;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+
+/// Generate the runtime schema validator used by `*_validated` methods
+///
+/// The component schemas are embedded as JSON so `$ref`s in response schemas
+/// can be resolved at runtime. Only generated with the `response-validation`
+/// feature.
+pub fn generate_response_validator(spec: &OpenAPI) -> Result<TokenStream2, String> {
+    if !cfg!(feature = "response-validation") {
+        return Ok(quote! {});
+    }
+
+    let component_schemas = match &spec.components {
+        Some(components) => serde_json::to_string(
+            &components
+                .schemas
+                .iter()
+                .collect::<std::collections::BTreeMap<_, _>>(),
+        )
+        .map_err(|e| format!("Failed to serialize component schemas: {}", e))?,
+        None => "{}".to_string(),
+    };
+
+    Ok(quote! {
+        const RESPONSE_SCHEMA_COMPONENTS: &str = #component_schemas;
+
+        /// Component schemas used to resolve `$ref`s, parsed on first use
+        #[allow(dead_code)]
+        fn response_schema_components() -> &'static serde_json::Value {
+            static COMPONENTS: std::sync::OnceLock<serde_json::Value> = std::sync::OnceLock::new();
+            COMPONENTS.get_or_init(|| {
+                serde_json::from_str(RESPONSE_SCHEMA_COMPONENTS)
+                    .expect("embedded component schemas are valid JSON")
+            })
+        }
+
+        /// Validate a JSON value against an embedded OpenAPI schema
+        ///
+        /// Checks types, required fields and enumerations, returning every
+        /// violation found prefixed with the JSON path where it occurred.
+        #[allow(dead_code)]
+        fn validate_response_schema(
+            value: &serde_json::Value,
+            schema: &str,
+        ) -> Result<(), Vec<String>> {
+            let schema: serde_json::Value =
+                serde_json::from_str(schema).expect("embedded schema is valid JSON");
+            let components = response_schema_components();
+
+            let mut errors = Vec::new();
+            validate_schema_node(value, &schema, components, "$", &mut errors);
+
+            if errors.is_empty() {
+                Ok(())
+            } else {
+                Err(errors)
+            }
+        }
+
+        #[allow(dead_code)]
+        fn validate_schema_node(
+            value: &serde_json::Value,
+            schema: &serde_json::Value,
+            components: &serde_json::Value,
+            path: &str,
+            errors: &mut Vec<String>,
+        ) {
+            if let Some(reference) = schema.get("$ref").and_then(|r| r.as_str()) {
+                if let Some(target) = reference
+                    .strip_prefix("#/components/schemas/")
+                    .and_then(|name| components.get(name))
+                {
+                    validate_schema_node(value, target, components, path, errors);
+                }
+                return;
+            }
+
+            if value.is_null() {
+                let nullable = schema
+                    .get("nullable")
+                    .and_then(|n| n.as_bool())
+                    .unwrap_or(false);
+                if !nullable && schema.get("type").is_some() {
+                    errors.push(format!("{}: unexpected null", path));
+                }
+                return;
+            }
+
+            if let Some(all_of) = schema.get("allOf").and_then(|s| s.as_array()) {
+                for sub_schema in all_of {
+                    validate_schema_node(value, sub_schema, components, path, errors);
+                }
+            }
+
+            for key in ["oneOf", "anyOf"] {
+                if let Some(sub_schemas) = schema.get(key).and_then(|s| s.as_array()) {
+                    let matches_any = sub_schemas.iter().any(|sub_schema| {
+                        let mut sub_errors = Vec::new();
+                        validate_schema_node(value, sub_schema, components, path, &mut sub_errors);
+                        sub_errors.is_empty()
+                    });
+                    if !matches_any {
+                        errors.push(format!("{}: does not match any `{}` schema", path, key));
+                    }
+                }
+            }
+
+            if let Some(expected) = schema.get("type").and_then(|t| t.as_str()) {
+                let matches_type = match expected {
+                    "string" => value.is_string(),
+                    "integer" => value.is_i64() || value.is_u64(),
+                    "number" => value.is_number(),
+                    "boolean" => value.is_boolean(),
+                    "array" => value.is_array(),
+                    "object" => value.is_object(),
+                    _ => true,
+                };
+                if !matches_type {
+                    let found = match value {
+                        serde_json::Value::Null => "null",
+                        serde_json::Value::Bool(_) => "boolean",
+                        serde_json::Value::Number(_) => "number",
+                        serde_json::Value::String(_) => "string",
+                        serde_json::Value::Array(_) => "array",
+                        serde_json::Value::Object(_) => "object",
+                    };
+                    errors.push(format!("{}: expected {}, found {}", path, expected, found));
+                    return;
+                }
+            }
+
+            if let Some(allowed) = schema.get("enum").and_then(|e| e.as_array()) {
+                if !allowed.contains(value) {
+                    errors.push(format!("{}: {} is not an allowed value", path, value));
+                }
+            }
+
+            if let Some(object) = value.as_object() {
+                if let Some(required) = schema.get("required").and_then(|r| r.as_array()) {
+                    for field in required.iter().filter_map(|f| f.as_str()) {
+                        if !object.contains_key(field) {
+                            errors.push(format!("{}: missing required field `{}`", path, field));
+                        }
+                    }
+                }
+
+                if let Some(properties) = schema.get("properties").and_then(|p| p.as_object()) {
+                    for (name, property_schema) in properties {
+                        if let Some(field_value) = object.get(name) {
+                            let field_path = format!("{}.{}", path, name);
+                            validate_schema_node(
+                                field_value,
+                                property_schema,
+                                components,
+                                &field_path,
+                                errors,
+                            );
+                        }
+                    }
+                }
+            }
+
+            if let (Some(items), Some(item_schema)) = (value.as_array(), schema.get("items")) {
+                for (index, item) in items.iter().enumerate() {
+                    let item_path = format!("{}[{}]", path, index);
+                    validate_schema_node(item, item_schema, components, &item_path, errors);
+                }
+            }
+        }
+    })
+}
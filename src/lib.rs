@@ -36,13 +36,30 @@
 //!
 //! - `middleware` - Enables `reqwest-middleware` support for advanced HTTP client features
 //! - `blocking` - Generates synchronous HTTP clients using `reqwest::blocking`
+//! - `response-validation` - Generates `*_validated` methods that check responses against the spec schema
+//! - `rate-limit` - Throttles operations annotated with `x-ratelimit-limit`/`x-ratelimit-window` using `governor`
+//! - `retry` - Retries idempotent requests once after the delay of a `Retry-After` header on 429 responses
+//! - `multipart` - Sends `multipart/form-data` request bodies as forms, honoring per-part `encoding` content types
+//! - `cancellation` - Generates `<method>_cancellable` variants racing requests against a `tokio_util` `CancellationToken`
+//! - `unix-socket` - Generates a `with_unix_socket` constructor sending requests over a Unix domain socket
+//! - `http` - Generates `<method>_http_request` variants building an `http::Request` instead of sending it
+//! - `stream-upload` - Generates `<method>_stream` variants streaming binary request bodies
+//! - `pagination` - Generates `<operation>_all` streams for the `pagination` option using `futures`
+//! - `metrics` - Records request durations and outcomes per operation with the `metrics` crate
+//! - `simd-json` - Deserializes JSON responses with `simd-json` instead of `serde_json`
+//! - `uri-template` - Maps `format: uri-template` strings to a `UriTemplate` newtype with an `expand` helper
+//! - `single-flight` - Coalesces concurrent identical `GET` requests of async clients into one request
+//! - `decimal` - Maps `format: decimal`/`money` numbers to `rust_decimal::Decimal`
+//! - `chrono` - Maps `format: date-time`/`date` strings to `chrono` types
+//! - `uuid` - Maps `format: uuid` strings to `uuid::Uuid`
+//! - `ureq` - Generates blocking clients built on `ureq` 2 instead of `reqwest`, for binaries without an async runtime
 
 mod codegen;
 mod generator;
 mod parser;
 mod utils;
 
-use heck::ToPascalCase;
+use heck::{ToPascalCase, ToShoutySnakeCase};
 use proc_macro::TokenStream;
 use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::{format_ident, quote};
@@ -50,15 +67,17 @@ use syn::parse_macro_input;
 
 use generator::*;
 use parser::*;
+use utils::dump_generated_code;
 
 /// Generates an API client and structs from an OpenAPI specification
 ///
 /// Supports loading OpenAPI specifications from both local files and remote URLs.
 /// The specification format (JSON/YAML) is auto-detected from the file extension
-/// or URL path.
+/// or URL path, or else from the content. `$ref`s to other files or URLs are
+/// resolved relative to the document they appear in.
 ///
 /// # Usage
-/// 
+///
 /// ```rust,ignore
 /// use openapi_gen::openapi_client;
 ///
@@ -69,9 +88,14 @@ use parser::*;
 /// // With custom client name
 /// openapi_client!("path/to/openapi.json", "MyApiClient");
 ///
+/// // With the spec path taken from an environment variable (e.g. set by a build
+/// // script); relative paths are resolved against `OUT_DIR`
+/// openapi_client!(env = "BUNDLED_SPEC", "MyApiClient");
+///
 /// // With configuration options
 /// openapi_client!("openapi.json", use_param_structs = true);
 /// openapi_client!("openapi.json", struct_attrs = (derive(PartialEq)));
+/// openapi_client!("openapi.json", "MyApiClient", dual = true);
 /// openapi_client!(
 ///     "openapi.json",
 ///     "MyApiClient",
@@ -79,11 +103,32 @@ use parser::*;
 ///     struct_attrs = (derive(PartialEq, Hash))
 /// );
 /// ```
-/// 
+///
 /// # Configuration Options
-/// 
+///
 /// - `use_param_structs` - Generate parameter structs for operations instead of individual parameters
 /// - `struct_attrs` - Add custom attributes to generated structs (in addition to default derives)
+/// - `value_conversions` - Generate conversions from and to `serde_json::Value` for generated types
+/// - `apply_patch` - Generate `apply_patch` methods merging update structs (all-optional `PUT`/`PATCH` bodies) onto the full structs they update
+/// - `nested_getters` - Generate accessors for scalar fields nested in optional structs, e.g. `user.theme()` for `user.preferences?.theme`
+/// - `variant_accessors` - Generate `as_<variant>` and `is_<variant>` accessors on `oneOf` enums, e.g. `pet.as_cat()` returning an `Option<&Cat>`
+/// - `skip_empty_collections` - Leave empty required `Vec`/`HashMap` fields out of serialized structs, defaulting them when missing
+/// - `etag_cache` - Cache `GET` responses by URL and revalidate them with `If-None-Match`, reusing the cached body on `304`
+/// - `operation_errors` - Generate an error enum per operation from its documented error responses, returned by its methods instead of `ApiError`
+/// - `base_url_env` - Name of the environment variable read by `from_env` (defaults to `<CLIENT_NAME>_BASE_URL`)
+/// - `auth` - Authentication attached to every request: `"bearer"` generates `with_bearer_token`, `"api_key"` generates `with_api_key` for the spec's `apiKey` security scheme, `"basic"` generates `with_basic_auth`, all with `clear_auth`
+/// - `shared_auth` - Share the credentials of `auth` between clones of the client behind an `Arc<RwLock<_>>`, generating `set_bearer_token`/`set_api_key`/`set_basic_auth` to replace them through `&self`, e.g. when refreshing a token
+/// - `overlay` - Path or URL of a JSON/YAML document whose `components` and `paths` are deep-merged over the spec, a `null` removing a key
+/// - `validate` - Generate a `validate` method on structs checking the `minLength`, `maxLength`, `pattern`, `minimum` and `maximum` constraints of their fields, returning a `ValidationError` (patterns require the `regex` crate)
+/// - `internal_extension` - Extension marking operations and component schemas left out of the client when `true` (defaults to `x-internal`)
+/// - `pagination` - Cursor pagination as `(cursor_param = "...", next_cursor = "/...", items = "/...")`, generating `_all` streams, or iterators for blocking clients (requires the `pagination` feature)
+/// - `retry` - Times failed idempotent requests (connection errors and 5xx responses) are retried with exponential backoff, adjustable with `with_retry_policy`
+/// - `auth_check_operation` - Operation ID of a lightweight authenticated operation without required parameters, called by a generated `verify_credentials` method that reports rejected credentials as `ApiError::Unauthorized`
+/// - `include_response_headers` - Return the body of successful responses in an `ApiResponse` with their `status` and `headers`
+/// - `text_methods` - Generate a `<method>_text` variant of every operation method returning the raw body of successful responses as a `String`
+/// - `trait` - Generate a `<ClientName>Api` trait with the operation methods, implemented by the client using `async_trait`, for substituting mocks
+/// - `dump_to` - Path of a file the formatted generated code is written to for inspection, relative to the crate root
+/// - `dual` - Generate a separate `<ClientName>Blocking` client next to the async client (requires the `blocking` feature)
 #[proc_macro]
 pub fn openapi_client(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as OpenApiInput);
@@ -113,26 +158,117 @@ fn generate_client(input: &OpenApiInput) -> Result<TokenStream2, String> {
         format_ident!("{}Api", sanitized_title)
     };
 
+    // Environment variables read by `from_env`, shared by the async and blocking clients
+    let env_prefix = client_name.to_string().to_shouty_snake_case();
+    let client_env = ClientEnv {
+        base_url: input
+            .base_url_env
+            .clone()
+            .unwrap_or_else(|| format!("{}_BASE_URL", env_prefix)),
+        token: format!("{}_TOKEN", env_prefix),
+    };
+
     // Generate components
-    let structs = generate_structs(&spec, &input.struct_attrs)?;
-    let client_impl = generate_client_impl(&spec, &client_name, input.use_param_structs)?;
-    let error_types = generate_error_types();
+    let structs = generate_structs(&spec, input)?;
+    let client_impl = generate_client_impl(&spec, &client_name, input, &client_env)?;
+    let client_fields = generate_client_fields(&spec, input)?.definitions;
+    let json_rpc = uses_json_rpc(&spec)?;
+    let error_types = generate_error_types(json_rpc, input.auth_check_operation.is_some());
+    let validation_error = generate_validation_error_type(&spec, input.validate);
+    let basic_auth_header = generate_basic_auth_header(input);
+    let operation_info = generate_operation_info_type(&spec);
+    let api_info = generate_api_info(&spec);
+    let response_validator = generate_response_validator(&spec)?;
+    let retry_helpers = generate_retry_helpers();
+    let retry_policy = generate_retry_policy(input);
+    let api_response = response_headers::generate_api_response_type(input);
+    let single_flight_helpers = generate_single_flight_helpers();
+    let json_rpc_helpers = generate_json_rpc_helpers(json_rpc);
+    let operation_errors = if input.operation_errors {
+        generate_operation_errors(&spec)?
+    } else {
+        quote! {}
+    };
+    let uri_template = generate_uri_template_type();
 
-    // Generate parameter structs if requested
+    // Generate parameter structs if requested, otherwise just the enumeration value constants
     let param_structs = if input.use_param_structs {
         generate_param_structs(&spec, &input.struct_attrs)?
     } else {
-        quote! {}
+        generate_param_value_constants(&spec)?
     };
 
     // Generate client documentation
     let client_doc = generate_client_doc_comment(&spec, &client_name.to_string());
 
-    Ok(quote! {
+    // Generate a separate blocking client if requested
+    let blocking_client = if input.dual {
+        let blocking_client_name = format_ident!("{}Blocking", client_name);
+        let blocking_client_doc =
+            generate_client_doc_comment(&spec, &blocking_client_name.to_string());
+        let blocking_client_impl = generate_blocking_client_impl(
+            &spec,
+            &blocking_client_name,
+            &client_name,
+            input,
+            &client_env,
+        )?;
+
+        quote! {
+            #blocking_client_doc
+            #[derive(Clone)]
+            pub struct #blocking_client_name<C = reqwest::blocking::Client> {
+                base_url: String,
+                client: C,
+                #client_fields
+            }
+
+            #blocking_client_impl
+        }
+    } else {
+        quote! {}
+    };
+
+    // Clients built on `ureq` replace the `reqwest` client
+    let default_http_client = if cfg!(feature = "ureq") {
+        quote! { ureq::Agent }
+    } else {
+        quote! { reqwest::Client }
+    };
+
+    // Rebuild when the spec path variable or the artifact it points to changes
+    let spec_tracking = match &input.spec_env {
+        Some(var) if !is_url(&input.spec_path) => {
+            let spec_path = &input.spec_path;
+            quote! {
+                const _: Option<&str> = option_env!(#var);
+                const _: &[u8] = include_bytes!(#spec_path);
+            }
+        }
+        Some(var) => quote! { const _: Option<&str> = option_env!(#var); },
+        None => quote! {},
+    };
+
+    let tokens = quote! {
         use serde::{Deserialize, Serialize};
         use std::collections::HashMap;
 
+        #spec_tracking
+
         #error_types
+        #validation_error
+        #operation_info
+        #api_info
+        #operation_errors
+        #api_response
+
+        #response_validator
+        #retry_helpers
+        #retry_policy
+        #basic_auth_header
+        #single_flight_helpers
+        #json_rpc_helpers
+        #uri_template
 
         #structs
 
@@ -140,11 +276,20 @@ fn generate_client(input: &OpenApiInput) -> Result<TokenStream2, String> {
 
         #client_doc
         #[derive(Clone)]
-        pub struct #client_name<C = reqwest::Client> {
+        pub struct #client_name<C = #default_http_client> {
             base_url: String,
             client: C,
+            #client_fields
         }
 
         #client_impl
-    })
+
+        #blocking_client
+    };
+
+    if let Some(path) = &input.dump_to {
+        dump_generated_code(path, &tokens)?;
+    }
+
+    Ok(tokens)
 }
@@ -0,0 +1,241 @@
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+
+use serde_json::Value;
+
+use super::{is_url, read_spec_document};
+
+/// Resolve references to other files, leaving only references within the spec
+///
+/// References into the `components` of another document, like
+/// `components.yaml#/components/schemas/User`, import the component into the
+/// spec under the same name and point to it there. Other references, like
+/// `schemas/user.yaml`, are replaced by what they reference, with the fields
+/// next to the `$ref` overriding its fields. References are relative to the
+/// document they appear in, and those of remote specs are fetched.
+pub fn resolve_external_refs(spec: &mut Value, spec_path: &str) -> Result<(), String> {
+    let root = document_location(spec_path, "")?;
+    let mut resolver = ExternalRefs {
+        existing: component_refs(spec, &root)?,
+        root,
+        documents: HashMap::new(),
+        imported: HashMap::new(),
+        imports: Vec::new(),
+        inlining: Vec::new(),
+    };
+    let root = resolver.root.clone();
+    resolver.resolve(spec, &root)?;
+
+    for (kind, name, component) in resolver.imports {
+        spec["components"][kind][name] = component;
+    }
+
+    Ok(())
+}
+
+/// State of resolving the references to other files of a spec
+struct ExternalRefs {
+    /// Location of the spec itself
+    root: String,
+    /// Components of the spec, with the location they reference if they're a `$ref` to another file
+    existing: HashMap<(String, String), Option<String>>,
+    /// Documents read so far, by location
+    documents: HashMap<String, Value>,
+    /// Location every imported component was imported from
+    imported: HashMap<(String, String), String>,
+    /// Imported components, added to the spec once all references are resolved
+    imports: Vec<(String, String, Value)>,
+    /// References being replaced, to detect circular references
+    inlining: Vec<String>,
+}
+
+impl ExternalRefs {
+    /// Resolve the references to other files in a value of the given document
+    fn resolve(&mut self, value: &mut Value, document: &str) -> Result<(), String> {
+        let object = match value {
+            Value::Object(object) => object,
+            Value::Array(items) => {
+                return items
+                    .iter_mut()
+                    .try_for_each(|item| self.resolve(item, document));
+            }
+            _ => return Ok(()),
+        };
+
+        let Some(reference) = object.get("$ref").and_then(Value::as_str) else {
+            return object
+                .values_mut()
+                .try_for_each(|value| self.resolve(value, document));
+        };
+        let reference = reference.to_string();
+        let (file, pointer) = reference.split_once('#').unwrap_or((&reference, ""));
+        if file.is_empty() && document == self.root {
+            return Ok(());
+        }
+
+        let target = document_location(document, file)?;
+        let pointer = pointer.to_string();
+        let resolved = self.resolve_reference(&target, &pointer).map_err(|e| {
+            format!(
+                "Failed to resolve reference {} in {}: {}",
+                reference, document, e
+            )
+        })?;
+        match resolved {
+            Some(local_reference) => {
+                object.insert("$ref".to_string(), Value::String(local_reference));
+            }
+            None => {
+                let mut item = self.load(&target, &pointer)?;
+                let key = format!("{}#{}", target, pointer);
+                if self.inlining.contains(&key) {
+                    return Err(format!(
+                        "Failed to resolve reference {} in {}: circular reference",
+                        reference, document
+                    ));
+                }
+                self.inlining.push(key);
+                self.resolve(&mut item, &target)?;
+                self.inlining.pop();
+
+                if let Value::Object(item) = &mut item {
+                    for (key, value) in object.iter().filter(|(key, _)| *key != "$ref") {
+                        item.insert(key.clone(), value.clone());
+                    }
+                }
+                *value = item;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Get the reference within the spec a reference to another document is replaced by
+    ///
+    /// Returns `None` when the referenced value has to be inlined instead, as
+    /// it isn't a component.
+    fn resolve_reference(&mut self, target: &str, pointer: &str) -> Result<Option<String>, String> {
+        if target == self.root {
+            return Ok(Some(format!("#{}", pointer)));
+        }
+
+        let segments: Vec<&str> = pointer.split('/').skip(1).collect();
+        let [kind, name] = match segments.as_slice() {
+            ["components", kind, name] => {
+                [kind, name].map(|s| s.replace("~1", "/").replace("~0", "~"))
+            }
+            _ => return Ok(None),
+        };
+
+        let key = (kind.clone(), name.clone());
+        let location = format!("{}#{}", target, pointer);
+        match (self.imported.get(&key), self.existing.get(&key)) {
+            (Some(imported), _) if *imported == location => {}
+            (Some(imported), _) => {
+                return Err(format!(
+                    "{} `{}` is also imported from {}",
+                    kind, name, imported
+                ));
+            }
+            (None, Some(existing)) if existing.as_ref() != Some(&location) => {
+                return Err(format!("the spec has another {} named `{}`", kind, name));
+            }
+            _ => {
+                self.imported.insert(key, location);
+                let mut component = self.load(target, pointer)?;
+                self.resolve(&mut component, target)?;
+                self.imports.push((kind, name, component));
+            }
+        }
+
+        Ok(Some(format!("#{}", pointer)))
+    }
+
+    /// Get the value a pointer refers to in a document, reading the document if needed
+    fn load(&mut self, document: &str, pointer: &str) -> Result<Value, String> {
+        if !self.documents.contains_key(document) {
+            let content = read_spec_document(document)?;
+            self.documents.insert(document.to_string(), content);
+        }
+
+        self.documents[document]
+            .pointer(pointer)
+            .cloned()
+            .ok_or_else(|| format!("{}#{} not found", document, pointer))
+    }
+}
+
+/// Get the components of a spec, with the location they reference if they're a `$ref` to another file
+fn component_refs(
+    spec: &Value,
+    root: &str,
+) -> Result<HashMap<(String, String), Option<String>>, String> {
+    let mut components = HashMap::new();
+    let Some(Value::Object(kinds)) = spec.get("components") else {
+        return Ok(components);
+    };
+
+    for (kind, entries) in kinds {
+        let Value::Object(entries) = entries else {
+            continue;
+        };
+        for (name, entry) in entries {
+            let location = match entry.get("$ref").and_then(Value::as_str) {
+                Some(reference) if !reference.starts_with('#') => {
+                    let (file, pointer) = reference.split_once('#').unwrap_or((reference, ""));
+                    Some(format!("{}#{}", document_location(root, file)?, pointer))
+                }
+                _ => None,
+            };
+            components.insert((kind.clone(), name.clone()), location);
+        }
+    }
+
+    Ok(components)
+}
+
+/// Get the location of a file referenced from a document
+///
+/// Relative paths are resolved against the directory of the document, or
+/// joined to its URL for remote documents. An empty file refers to the
+/// document itself.
+fn document_location(document: &str, file: &str) -> Result<String, String> {
+    if is_url(file) {
+        return Ok(file.to_string());
+    }
+    if is_url(document) {
+        let base = reqwest::Url::parse(document).map_err(|e| format!("Invalid URL: {}", e))?;
+        let url = base.join(file).map_err(|e| format!("Invalid URL: {}", e))?;
+        return Ok(url.to_string());
+    }
+
+    let path = if file.is_empty() {
+        PathBuf::from(document)
+    } else {
+        Path::new(document)
+            .parent()
+            .unwrap_or(Path::new(""))
+            .join(file)
+    };
+    Ok(normalize_path(&path).to_string_lossy().into_owned())
+}
+
+/// Remove `.` and `..` components from a path without accessing the file system
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir
+                if matches!(
+                    normalized.components().next_back(),
+                    Some(Component::Normal(_))
+                ) =>
+            {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
@@ -1,22 +1,194 @@
 use proc_macro2::TokenStream;
-use syn::{Ident, LitBool, LitStr, Token, parenthesized};
+use syn::ext::IdentExt;
+use syn::{Ident, LitBool, LitInt, LitStr, Token, parenthesized};
+
+use super::resolve_env_spec_path;
 
 /// Input for the openapi_client macro
 pub struct OpenApiInput {
     pub spec_path: String,
+    /// Environment variable the spec path was read from, if any
+    pub spec_env: Option<String>,
     pub client_name: Option<String>,
     pub use_param_structs: bool,
+    pub dual: bool,
+    pub value_conversions: bool,
+    /// Generate `apply_patch` methods for update structs
+    pub apply_patch: bool,
+    /// Generate accessors for scalar fields nested in optional structs
+    pub nested_getters: bool,
+    /// Generate `as_<variant>`/`is_<variant>` accessors on `oneOf` enums
+    pub variant_accessors: bool,
+    /// Leave empty `Vec` and `HashMap` fields out of serialized structs
+    pub skip_empty_collections: bool,
+    /// Cache JSON responses of `GET` operations by `ETag` for conditional requests
+    pub etag_cache: bool,
+    /// Generate an error enum per operation, returned by its methods
+    pub operation_errors: bool,
+    /// Environment variable read by `from_env` for the base URL
+    pub base_url_env: Option<String>,
+    /// Cursor pagination used to generate `_all` streams
+    pub pagination: Option<PaginationConfig>,
+    /// Authentication attached to every request
+    pub auth: Option<AuthScheme>,
+    /// Spec whose `components` and `paths` are merged over the main spec
+    pub overlay: Option<String>,
+    /// File the formatted generated code is written to
+    pub dump_to: Option<String>,
+    /// Times failed idempotent requests are retried with exponential backoff
+    pub retry: Option<u32>,
+    /// Operation called by `verify_credentials` to check the credentials
+    pub auth_check_operation: Option<String>,
+    /// Return responses of methods as an `ApiResponse` with their status and headers
+    pub include_response_headers: bool,
+    /// Generate a `<ClientName>Api` trait with the operation methods, implemented by the client
+    pub client_trait: bool,
+    /// Generate a `<method>_text` variant of every operation method returning the raw response body
+    pub text_methods: bool,
+    /// Share the credentials of the `auth` option between clones of the client through a lock
+    pub shared_auth: bool,
+    /// Generate `validate` methods checking the constraints of the spec on struct fields
+    pub validate: bool,
+    /// Extension marking operations and schemas left out of the generated code
+    pub internal_extension: String,
     pub struct_attrs: Vec<TokenStream>,
 }
 
+/// Authentication scheme of the client, given as `auth = "..."`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthScheme {
+    /// `Authorization: Bearer <token>`, set with `with_bearer_token`
+    Bearer,
+    /// Key of the spec's `apiKey` security scheme, set with `with_api_key`
+    ApiKey,
+    /// `Authorization: Basic <credentials>`, set with `with_basic_auth`
+    Basic,
+}
+
+impl AuthScheme {
+    fn from_lit(lit: &LitStr) -> syn::Result<Self> {
+        match lit.value().as_str() {
+            "bearer" => Ok(AuthScheme::Bearer),
+            "api_key" => Ok(AuthScheme::ApiKey),
+            "basic" => Ok(AuthScheme::Basic),
+            unknown => Err(syn::Error::new_spanned(
+                lit,
+                format!(
+                    "unknown auth scheme: {} (expected \"bearer\", \"api_key\" or \"basic\")",
+                    unknown
+                ),
+            )),
+        }
+    }
+}
+
+/// Cursor pagination configuration, given as
+/// `pagination = (cursor_param = "...", next_cursor = "/...", items = "/...")`
+pub struct PaginationConfig {
+    /// Name of the query parameter carrying the request cursor
+    pub cursor_param: String,
+    /// JSON pointer to the next cursor in the response body
+    pub next_cursor: String,
+    /// JSON pointer to the array of items in the response body
+    pub items: String,
+}
+
+impl syn::parse::Parse for PaginationConfig {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let content;
+        parenthesized!(content in input);
+
+        let mut cursor_param = None;
+        let mut next_cursor = None;
+        let mut items = None;
+
+        while !content.is_empty() {
+            let key: Ident = content.parse()?;
+            content.parse::<Token![=]>()?;
+            let value: LitStr = content.parse()?;
+
+            match key.to_string().as_str() {
+                "cursor_param" => cursor_param = Some(value.value()),
+                "next_cursor" | "items" if !value.value().starts_with('/') => {
+                    return Err(syn::Error::new_spanned(
+                        value,
+                        "expected a JSON pointer starting with `/`",
+                    ));
+                }
+                "next_cursor" => next_cursor = Some(value.value()),
+                "items" => items = Some(value.value()),
+                unknown => {
+                    return Err(syn::Error::new_spanned(
+                        key,
+                        format!("unknown pagination option: {}", unknown),
+                    ));
+                }
+            }
+
+            if content.peek(Token![,]) {
+                content.parse::<Token![,]>()?;
+            }
+        }
+
+        match (cursor_param, next_cursor, items) {
+            (Some(cursor_param), Some(next_cursor), Some(items)) => Ok(PaginationConfig {
+                cursor_param,
+                next_cursor,
+                items,
+            }),
+            _ => Err(syn::Error::new(
+                input.span(),
+                "pagination requires `cursor_param`, `next_cursor` and `items`",
+            )),
+        }
+    }
+}
+
 impl syn::parse::Parse for OpenApiInput {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        // Parse first argument (spec path)
-        let spec_lit: LitStr = input.parse()?;
-        let spec_path = spec_lit.value();
+        // Parse first argument (spec path, or `env = "VAR"` naming a variable holding it)
+        let mut spec_env = None;
+        let spec_path = if input.peek(Ident) {
+            let key: Ident = input.parse()?;
+            if key != "env" {
+                return Err(syn::Error::new_spanned(
+                    key,
+                    "expected spec path or `env = \"VAR\"` as first argument",
+                ));
+            }
+            input.parse::<Token![=]>()?;
+            let var_lit: LitStr = input.parse()?;
+            spec_env = Some(var_lit.value());
+            resolve_env_spec_path(&var_lit.value())
+                .map_err(|e| syn::Error::new_spanned(&var_lit, e))?
+        } else {
+            let spec_lit: LitStr = input.parse()?;
+            spec_lit.value()
+        };
 
         let mut client_name = None;
         let mut use_param_structs = false;
+        let mut dual = false;
+        let mut value_conversions = false;
+        let mut apply_patch = false;
+        let mut nested_getters = false;
+        let mut skip_empty_collections = false;
+        let mut etag_cache = false;
+        let mut operation_errors = false;
+        let mut base_url_env = None;
+        let mut pagination = None;
+        let mut auth = None;
+        let mut overlay = None;
+        let mut dump_to = None;
+        let mut retry = None;
+        let mut auth_check_operation = None;
+        let mut include_response_headers = false;
+        let mut client_trait = false;
+        let mut text_methods = false;
+        let mut shared_auth = false;
+        let mut validate = false;
+        let mut variant_accessors = false;
+        let mut internal_extension = "x-internal".to_string();
         let mut struct_attrs = Vec::new();
 
         // Parse remaining arguments
@@ -28,9 +200,9 @@ impl syn::parse::Parse for OpenApiInput {
                 // String literal - must be client name
                 let client_lit: LitStr = input.parse()?;
                 client_name = Some(client_lit.value());
-            } else if input.peek(Ident) {
-                // Identifier - parse key = value option
-                let key: Ident = input.parse()?;
+            } else if input.peek(Ident::peek_any) {
+                // Identifier - parse key = value option, which may be a keyword like `trait`
+                let key = Ident::parse_any(input)?;
                 input.parse::<Token![=]>()?;
 
                 match key.to_string().as_str() {
@@ -38,6 +210,89 @@ impl syn::parse::Parse for OpenApiInput {
                         let value: LitBool = input.parse()?;
                         use_param_structs = value.value;
                     }
+                    "dual" => {
+                        let value: LitBool = input.parse()?;
+                        dual = value.value;
+                    }
+                    "value_conversions" => {
+                        let value: LitBool = input.parse()?;
+                        value_conversions = value.value;
+                    }
+                    "apply_patch" => {
+                        let value: LitBool = input.parse()?;
+                        apply_patch = value.value;
+                    }
+                    "nested_getters" => {
+                        let value: LitBool = input.parse()?;
+                        nested_getters = value.value;
+                    }
+                    "skip_empty_collections" => {
+                        let value: LitBool = input.parse()?;
+                        skip_empty_collections = value.value;
+                    }
+                    "etag_cache" => {
+                        let value: LitBool = input.parse()?;
+                        etag_cache = value.value;
+                    }
+                    "operation_errors" => {
+                        let value: LitBool = input.parse()?;
+                        operation_errors = value.value;
+                    }
+                    "base_url_env" => {
+                        let value: LitStr = input.parse()?;
+                        base_url_env = Some(value.value());
+                    }
+                    "pagination" => {
+                        pagination = Some(input.parse()?);
+                    }
+                    "auth" => {
+                        let value: LitStr = input.parse()?;
+                        auth = Some(AuthScheme::from_lit(&value)?);
+                    }
+                    "overlay" => {
+                        let value: LitStr = input.parse()?;
+                        overlay = Some(value.value());
+                    }
+                    "dump_to" => {
+                        let value: LitStr = input.parse()?;
+                        dump_to = Some(value.value());
+                    }
+                    "retry" => {
+                        let value: LitInt = input.parse()?;
+                        retry = Some(value.base10_parse()?);
+                    }
+                    "auth_check_operation" => {
+                        let value: LitStr = input.parse()?;
+                        auth_check_operation = Some(value.value());
+                    }
+                    "include_response_headers" => {
+                        let value: LitBool = input.parse()?;
+                        include_response_headers = value.value;
+                    }
+                    "trait" => {
+                        let value: LitBool = input.parse()?;
+                        client_trait = value.value;
+                    }
+                    "text_methods" => {
+                        let value: LitBool = input.parse()?;
+                        text_methods = value.value;
+                    }
+                    "shared_auth" => {
+                        let value: LitBool = input.parse()?;
+                        shared_auth = value.value;
+                    }
+                    "validate" => {
+                        let value: LitBool = input.parse()?;
+                        validate = value.value;
+                    }
+                    "variant_accessors" => {
+                        let value: LitBool = input.parse()?;
+                        variant_accessors = value.value;
+                    }
+                    "internal_extension" => {
+                        let value: LitStr = input.parse()?;
+                        internal_extension = value.value();
+                    }
                     "struct_attrs" => {
                         // Parse parenthesized list of attribute contents
                         let content;
@@ -95,8 +350,30 @@ impl syn::parse::Parse for OpenApiInput {
 
         Ok(OpenApiInput {
             spec_path,
+            spec_env,
             client_name,
             use_param_structs,
+            dual,
+            value_conversions,
+            apply_patch,
+            nested_getters,
+            variant_accessors,
+            skip_empty_collections,
+            etag_cache,
+            operation_errors,
+            base_url_env,
+            pagination,
+            auth,
+            overlay,
+            dump_to,
+            retry,
+            auth_check_operation,
+            include_response_headers,
+            client_trait,
+            text_methods,
+            shared_auth,
+            validate,
+            internal_extension,
             struct_attrs,
         })
     }
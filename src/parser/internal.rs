@@ -0,0 +1,30 @@
+use serde_json::Value;
+
+/// HTTP methods of the operations of a path item
+const OPERATION_METHODS: [&str; 8] = [
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+
+/// Remove the operations and component schemas marked internal from a spec
+///
+/// Items are internal when the given extension, like `x-internal`, is `true`.
+/// Schemas referenced by the remaining items must not be internal themselves.
+pub fn remove_internal_items(spec: &mut Value, extension: &str) {
+    let is_internal = |item: &Value| item.get(extension) == Some(&Value::Bool(true));
+
+    if let Some(Value::Object(paths)) = spec.get_mut("paths") {
+        for path_item in paths.values_mut() {
+            if let Value::Object(path_item) = path_item {
+                for method in OPERATION_METHODS {
+                    if path_item.get(method).is_some_and(is_internal) {
+                        path_item.remove(method);
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some(Value::Object(schemas)) = spec.pointer_mut("/components/schemas") {
+        schemas.retain(|_, schema| !is_internal(schema));
+    }
+}
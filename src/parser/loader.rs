@@ -9,6 +9,51 @@ pub fn is_yaml_format(path: &str) -> bool {
     path_lower.ends_with(".yaml") || path_lower.ends_with(".yml")
 }
 
+/// Resolve the spec path stored in an environment variable
+///
+/// Meant for specs produced by a build script: relative paths are resolved
+/// against `OUT_DIR` when it is set. The resolved file must exist.
+pub fn resolve_env_spec_path(var: &str) -> Result<String, String> {
+    let path = std::env::var(var).map_err(|_| {
+        if var.starts_with("DEP_") {
+            // Metadata of `links` dependencies only reaches build scripts
+            format!(
+                "Environment variable {} with the spec path is not set; `DEP_` variables are only \
+                 visible to build scripts, re-export it with `cargo:rustc-env` and use that variable",
+                var
+            )
+        } else {
+            format!("Environment variable {} with the spec path is not set", var)
+        }
+    })?;
+
+    if is_url(&path) {
+        return Ok(path);
+    }
+
+    let path = match std::env::var_os("OUT_DIR") {
+        Some(out_dir) if std::path::Path::new(&path).is_relative() => {
+            std::path::Path::new(&out_dir).join(&path)
+        }
+        _ => std::path::PathBuf::from(&path),
+    };
+
+    if !path.is_file() {
+        return Err(format!(
+            "Spec artifact {} from environment variable {} not found",
+            path.display(),
+            var
+        ));
+    }
+
+    // Absolute, so the generated code can track the artifact with `include_bytes!`
+    let path = path
+        .canonicalize()
+        .map_err(|e| format!("Failed to resolve spec artifact {}: {}", path.display(), e))?;
+
+    Ok(path.to_string_lossy().into_owned())
+}
+
 /// Fetch content from a URL at compile time
 pub fn fetch_url_content(url: &str) -> Result<String, String> {
     // Use blocking reqwest for compile-time execution
@@ -3,10 +3,24 @@
 //! This module handles loading OpenAPI specifications from files or URLs
 //! and parsing macro input arguments.
 
+pub mod external_refs;
 pub mod input;
+pub mod internal;
 pub mod loader;
+pub mod normalize;
+pub mod overlay;
+pub mod refs;
+pub mod security;
+pub mod servers;
 pub mod spec;
 
+pub use external_refs::*;
 pub use input::*;
+pub use internal::*;
 pub use loader::*;
+pub use normalize::*;
+pub use overlay::*;
+pub use refs::*;
+pub use security::*;
+pub use servers::*;
 pub use spec::*;
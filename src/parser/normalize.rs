@@ -0,0 +1,25 @@
+use serde_json::Value;
+
+/// Rewrite parts of a spec the `openapiv3` crate can't parse into extensions
+///
+/// OpenAPI 3.1 `prefixItems` is kept as an `x-prefixItems` extension, which
+/// the type mapping reads to generate tuples. Boolean `items` next to it are
+/// dropped, as they only restrict additional items.
+pub fn normalize_spec(value: &mut Value) {
+    match value {
+        Value::Object(object) => {
+            if matches!(object.get("prefixItems"), Some(Value::Array(_))) {
+                if let Some(prefix_items) = object.remove("prefixItems") {
+                    object.insert("x-prefixItems".to_string(), prefix_items);
+                }
+                if matches!(object.get("items"), Some(Value::Bool(_))) {
+                    object.remove("items");
+                }
+            }
+
+            object.values_mut().for_each(normalize_spec);
+        }
+        Value::Array(items) => items.iter_mut().for_each(normalize_spec),
+        _ => {}
+    }
+}
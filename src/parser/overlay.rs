@@ -0,0 +1,37 @@
+use serde_json::Value;
+
+/// Merge the `components` and `paths` of an overlay document over a spec
+///
+/// Objects are merged key by key, recursively, while other values of the
+/// overlay replace those of the spec. A `null` removes the key from the spec.
+/// Other top-level fields of the overlay, e.g. `info`, are ignored.
+pub fn apply_overlay(spec: &mut Value, mut overlay: Value) {
+    let Value::Object(spec) = spec else {
+        return;
+    };
+
+    for section in ["components", "paths"] {
+        if let Some(patch) = overlay.get_mut(section).map(Value::take) {
+            merge(
+                spec.entry(section)
+                    .or_insert_with(|| Value::Object(Default::default())),
+                patch,
+            );
+        }
+    }
+}
+
+fn merge(target: &mut Value, patch: Value) {
+    match (target, patch) {
+        (Value::Object(target), Value::Object(patch)) => {
+            for (key, value) in patch {
+                if value.is_null() {
+                    target.remove(&key);
+                } else {
+                    merge(target.entry(key).or_insert(Value::Null), value);
+                }
+            }
+        }
+        (target, patch) => *target = patch,
+    }
+}
@@ -0,0 +1,124 @@
+use indexmap::IndexMap;
+use openapiv3::{Example, Header, OpenAPI, Parameter, ReferenceOr, Response, Schema};
+
+/// Resolve a response that may be a `$ref` into `components.responses`
+pub fn resolve_response<'a>(
+    spec: &'a OpenAPI,
+    response_ref: &'a ReferenceOr<Response>,
+) -> Result<&'a Response, String> {
+    match response_ref {
+        ReferenceOr::Item(response) => Ok(response),
+        ReferenceOr::Reference { reference } => {
+            let responses = spec.components.as_ref().map(|c| &c.responses);
+            resolve_component(responses, "#/components/responses/", reference)
+        }
+    }
+}
+
+/// Resolve an example that may be a `$ref` into `components.examples`
+pub fn resolve_example<'a>(
+    spec: &'a OpenAPI,
+    example_ref: &'a ReferenceOr<Example>,
+) -> Result<&'a Example, String> {
+    match example_ref {
+        ReferenceOr::Item(example) => Ok(example),
+        ReferenceOr::Reference { reference } => {
+            let examples = spec.components.as_ref().map(|c| &c.examples);
+            resolve_component(examples, "#/components/examples/", reference)
+        }
+    }
+}
+
+/// Resolve a header that may be a `$ref` into `components.headers`
+pub fn resolve_header<'a>(
+    spec: &'a OpenAPI,
+    header_ref: &'a ReferenceOr<Header>,
+) -> Result<&'a Header, String> {
+    match header_ref {
+        ReferenceOr::Item(header) => Ok(header),
+        ReferenceOr::Reference { reference } => {
+            let headers = spec.components.as_ref().map(|c| &c.headers);
+            resolve_component(headers, "#/components/headers/", reference)
+        }
+    }
+}
+
+/// Resolve a `$ref` into `components.schemas`
+pub fn resolve_schema_reference<'a>(
+    spec: &'a OpenAPI,
+    reference: &str,
+) -> Result<&'a Schema, String> {
+    let schemas = spec.components.as_ref().map(|c| &c.schemas);
+    resolve_component(schemas, "#/components/schemas/", reference)
+}
+
+/// Replace operation parameters referencing `components.parameters` with the parameters themselves
+///
+/// Lets the generator handle shared parameters, like a `PerPage` query
+/// parameter, the same way as parameters declared inline.
+pub fn inline_parameter_refs(spec: &mut OpenAPI) -> Result<(), String> {
+    let parameters = spec.components.as_ref().map(|c| c.parameters.clone());
+
+    for path_item in spec.paths.paths.values_mut() {
+        let ReferenceOr::Item(path_item) = path_item else {
+            continue;
+        };
+        let operations = [
+            &mut path_item.get,
+            &mut path_item.put,
+            &mut path_item.post,
+            &mut path_item.delete,
+            &mut path_item.options,
+            &mut path_item.head,
+            &mut path_item.patch,
+            &mut path_item.trace,
+        ];
+
+        for operation in operations.into_iter().flatten() {
+            for param_ref in &mut operation.parameters {
+                if let ReferenceOr::Reference { reference } = param_ref {
+                    let param: &Parameter = resolve_component(
+                        parameters.as_ref(),
+                        "#/components/parameters/",
+                        reference,
+                    )?;
+                    *param_ref = ReferenceOr::Item(param.clone());
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Resolve a local component reference, following chained references
+///
+/// Fails with an error naming the reference when the target is missing, not a
+/// local component reference, or part of a reference cycle.
+fn resolve_component<'a, T>(
+    components: Option<&'a IndexMap<String, ReferenceOr<T>>>,
+    prefix: &str,
+    reference: &str,
+) -> Result<&'a T, String> {
+    let mut visited = Vec::new();
+    let mut current = reference;
+
+    loop {
+        if visited.contains(&current) {
+            return Err(format!("Circular reference: {}", reference));
+        }
+        visited.push(current);
+
+        let name = current
+            .strip_prefix(prefix)
+            .ok_or_else(|| format!("Unsupported reference: {}", current))?;
+        let target = components
+            .and_then(|components| components.get(name))
+            .ok_or_else(|| format!("Unresolved reference: {}", current))?;
+
+        match target {
+            ReferenceOr::Item(item) => return Ok(item),
+            ReferenceOr::Reference { reference } => current = reference,
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use openapiv3::{APIKeyLocation, OpenAPI, ReferenceOr, SecurityScheme};
+
+/// Where the key of an `apiKey` security scheme is sent
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiKeyLocation {
+    Header,
+    Query,
+}
+
+/// The `apiKey` security scheme used by the `api_key` auth option
+pub struct ApiKeyScheme {
+    /// Name of the header or query parameter carrying the key
+    pub name: String,
+    pub location: ApiKeyLocation,
+}
+
+/// Get the single `apiKey` security scheme declared in the spec's components
+pub fn api_key_scheme(spec: &OpenAPI) -> Result<ApiKeyScheme, String> {
+    let schemes: Vec<_> = spec
+        .components
+        .iter()
+        .flat_map(|components| components.security_schemes.iter())
+        .filter_map(|(scheme_name, scheme)| match scheme {
+            ReferenceOr::Item(SecurityScheme::APIKey { location, name, .. }) => {
+                Some((scheme_name, location, name))
+            }
+            _ => None,
+        })
+        .collect();
+
+    let [(scheme_name, location, name)] = schemes.as_slice() else {
+        return Err(format!(
+            "auth = \"api_key\" requires exactly one apiKey security scheme, found {}",
+            schemes.len()
+        ));
+    };
+
+    let location = match location {
+        APIKeyLocation::Header => ApiKeyLocation::Header,
+        APIKeyLocation::Query => ApiKeyLocation::Query,
+        APIKeyLocation::Cookie => {
+            return Err(format!(
+                "apiKey security scheme {} is sent in a cookie, only header and query keys are supported",
+                scheme_name
+            ));
+        }
+    };
+
+    Ok(ApiKeyScheme {
+        name: name.to_string(),
+        location,
+    })
+}
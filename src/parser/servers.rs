@@ -0,0 +1,20 @@
+use openapiv3::OpenAPI;
+
+/// Get the URL of the first server in the spec with variables set to their defaults
+///
+/// Returns `None` when the spec declares no servers, or when the first server
+/// URL is relative and so can't serve as a base URL by itself.
+pub fn default_server_url(spec: &OpenAPI) -> Option<String> {
+    let server = spec.servers.first()?;
+
+    let mut url = server.url.clone();
+    for (name, variable) in server.variables.iter().flatten() {
+        url = url.replace(&format!("{{{}}}", name), &variable.default);
+    }
+
+    if url.starts_with("http://") || url.starts_with("https://") {
+        Some(url)
+    } else {
+        None
+    }
+}
@@ -1,21 +1,117 @@
-use super::{OpenApiInput, fetch_url_content, is_url, is_yaml_format};
+use super::{
+    OpenApiInput, apply_overlay, fetch_url_content, inline_parameter_refs, is_url, is_yaml_format,
+    normalize_spec, remove_internal_items, resolve_external_refs,
+};
 use openapiv3::OpenAPI;
 
 /// Load and parse an OpenAPI specification from file or URL
+///
+/// The overlay spec of the `overlay` option, if any, is merged over it first.
+/// References to other files are resolved afterwards, after which referenced
+/// path items and parameters are inlined and internal items removed.
 pub fn load_openapi_spec(input: &OpenApiInput) -> Result<OpenAPI, String> {
-    // Read and parse the OpenAPI spec from file or URL
-    let spec_content = if is_url(&input.spec_path) {
-        fetch_url_content(&input.spec_path)?
+    let mut spec = read_spec_document(&input.spec_path)?;
+    if let Some(overlay_path) = &input.overlay {
+        let overlay = read_spec_document(overlay_path)?;
+        apply_overlay(&mut spec, overlay);
+    }
+    resolve_external_refs(&mut spec, &input.spec_path)?;
+    inline_path_item_refs(&mut spec)?;
+    remove_internal_items(&mut spec, &input.internal_extension);
+    normalize_spec(&mut spec);
+
+    let mut spec: OpenAPI =
+        serde_json::from_value(spec).map_err(|e| format!("Failed to parse OpenAPI spec: {}", e))?;
+    inline_parameter_refs(&mut spec)?;
+
+    Ok(spec)
+}
+
+/// Read and parse a JSON or YAML document from a file or URL
+///
+/// Documents with a `.yaml`/`.yml` extension are parsed as YAML. Others are
+/// parsed as JSON, falling back to YAML when that fails, so specs served
+/// without a recognizable extension can be either.
+pub fn read_spec_document(path: &str) -> Result<serde_json::Value, String> {
+    let content = if is_url(path) {
+        fetch_url_content(path)?
     } else {
-        std::fs::read_to_string(&input.spec_path)
-            .map_err(|e| format!("Failed to read spec file: {}", e))?
+        std::fs::read_to_string(path).map_err(|e| format!("Failed to read spec file: {}", e))?
     };
 
-    let spec: OpenAPI = if is_yaml_format(&input.spec_path) {
-        serde_yaml::from_str(&spec_content).map_err(|e| format!("Failed to parse YAML: {}", e))?
-    } else {
-        serde_json::from_str(&spec_content).map_err(|e| format!("Failed to parse JSON: {}", e))?
+    if is_yaml_format(path) {
+        return serde_yaml::from_str(&content).map_err(|e| format!("Failed to parse YAML: {}", e));
+    }
+
+    serde_json::from_str(&content).or_else(|json_error| {
+        // Content that looks like JSON is reported as invalid JSON rather than YAML
+        if content.trim_start().starts_with(['{', '[']) {
+            return Err(format!("Failed to parse JSON: {}", json_error));
+        }
+        serde_yaml::from_str(&content).map_err(|e| format!("Failed to parse YAML: {}", e))
+    })
+}
+
+/// Replace path items that are a `$ref` with the path items they reference
+///
+/// References are resolved as JSON pointers into the spec, like
+/// `#/components/pathItems/Users`, as references to other files have been
+/// resolved already. Other fields next to the `$ref` override those of the
+/// referenced path item.
+fn inline_path_item_refs(spec: &mut serde_json::Value) -> Result<(), String> {
+    let Some(serde_json::Value::Object(paths)) = spec.get("paths") else {
+        return Ok(());
     };
 
-    Ok(spec)
+    let mut resolved = Vec::new();
+    for (path, path_item) in paths {
+        let Some(reference) = path_item.get("$ref").and_then(serde_json::Value::as_str) else {
+            continue;
+        };
+        let mut item = resolve_path_item_ref(spec, reference).map_err(|e| {
+            format!(
+                "Failed to resolve path item reference {} of {}: {}",
+                reference, path, e
+            )
+        })?;
+        if let (serde_json::Value::Object(item), serde_json::Value::Object(siblings)) =
+            (&mut item, path_item)
+        {
+            for (key, value) in siblings.iter().filter(|(key, _)| *key != "$ref") {
+                item.insert(key.clone(), value.clone());
+            }
+        }
+        resolved.push((path.clone(), item));
+    }
+
+    for (path, item) in resolved {
+        spec["paths"][path] = item;
+    }
+
+    Ok(())
+}
+
+/// Resolve a path item reference, following chained references
+fn resolve_path_item_ref(
+    spec: &serde_json::Value,
+    reference: &str,
+) -> Result<serde_json::Value, String> {
+    let mut visited = Vec::new();
+    let mut current = reference.to_string();
+
+    loop {
+        if visited.contains(&current) {
+            return Err("circular reference".to_string());
+        }
+        visited.push(current.clone());
+
+        let pointer = current.strip_prefix('#').unwrap_or(&current);
+        let item = spec
+            .pointer(pointer)
+            .ok_or_else(|| format!("{} not found", current))?;
+        match item.get("$ref").and_then(serde_json::Value::as_str) {
+            Some(next) => current = next.to_string(),
+            None => return Ok(item.clone()),
+        }
+    }
 }
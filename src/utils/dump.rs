@@ -0,0 +1,26 @@
+use proc_macro2::TokenStream;
+
+/// Write the formatted generated code to a file for inspection
+///
+/// Used by the `dump_to` option. Missing parent directories are created.
+pub fn dump_generated_code(path: &str, tokens: &TokenStream) -> Result<(), String> {
+    let file = syn::parse2::<syn::File>(tokens.clone())
+        .map_err(|e| format!("Failed to parse generated code for {}: {}", path, e))?;
+    let code = prettyplease::unparse(&file);
+
+    let path = std::path::Path::new(path);
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create directory {}: {}", parent.display(), e))?;
+    }
+    std::fs::write(path, code).map_err(|e| {
+        format!(
+            "Failed to write generated code to {}: {}",
+            path.display(),
+            e
+        )
+    })
+}
@@ -1,8 +1,11 @@
 //! Utility functions for code generation.
 //!
 //! This module provides helper functions for handling Rust keywords,
-//! identifier creation, and other common code generation tasks.
+//! identifier creation, writing generated code, and other common code
+//! generation tasks.
 
+pub mod dump;
 pub mod keywords;
 
+pub use dump::*;
 pub use keywords::*;
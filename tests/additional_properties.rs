@@ -0,0 +1,51 @@
+use openapi_gen::openapi_client;
+use serde_json::json;
+
+openapi_client!("tests/fixtures/additional_properties.yaml", "TelemetryApi");
+
+#[test]
+fn test_free_form_metadata_is_a_map() {
+    let metadata: Metadata = serde_json::from_value(json!({
+        "owner": "ops",
+        "priority": 3,
+        "tags": ["edge"]
+    }))
+    .unwrap();
+
+    let _: &HashMap<String, serde_json::Value> = &metadata;
+    assert_eq!(metadata["owner"], "ops");
+    assert_eq!(metadata["priority"], 3);
+}
+
+#[test]
+fn test_map_of_schema_values_is_typed() {
+    let metrics: Metrics = serde_json::from_value(json!({
+        "cpu": { "value": 0.5 },
+        "memory": { "value": 0.75 }
+    }))
+    .unwrap();
+
+    let _: &HashMap<String, Metric> = &metrics;
+    assert_eq!(metrics["memory"].value, 0.75);
+}
+
+#[test]
+fn test_additional_properties_are_collected_next_to_fixed_ones() {
+    let device: Device = serde_json::from_value(json!({
+        "id": 1,
+        "labels": { "zone": "eu" },
+        "firmware": "1.2.0",
+        "vendor": "acme"
+    }))
+    .unwrap();
+
+    assert_eq!(device.id, 1);
+    let labels: HashMap<String, String> = device.labels.clone().unwrap();
+    assert_eq!(labels["zone"], "eu");
+    assert_eq!(device.extra.len(), 2);
+    assert_eq!(device.extra["firmware"], "1.2.0");
+
+    let value = serde_json::to_value(&device).unwrap();
+    assert_eq!(value["vendor"], "acme");
+    assert_eq!(value["id"], 1);
+}
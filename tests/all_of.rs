@@ -0,0 +1,91 @@
+#![cfg(not(feature = "ureq"))]
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!("tests/fixtures/all_of.yaml", "PetsApi");
+
+#[test]
+fn test_all_of_fields_are_merged() {
+    let dog: Dog = serde_json::from_value(json!({
+        "id": 1,
+        "name": "Rex",
+        "breed": "Boxer"
+    }))
+    .unwrap();
+
+    assert_eq!(dog.id, 1);
+    assert_eq!(dog.name, "Rex");
+    assert_eq!(dog.breed, "Boxer");
+    assert_eq!(dog.good_boy, None);
+
+    assert!(serde_json::from_value::<Dog>(json!({"id": 1, "breed": "Boxer"})).is_err());
+}
+
+#[test]
+fn test_nested_all_of_fields_are_merged() {
+    let dog: ServiceDog = serde_json::from_value(json!({
+        "id": 2,
+        "name": "Lassie",
+        "breed": "Collie",
+        "goodBoy": true,
+        "handler": "Timmy"
+    }))
+    .unwrap();
+
+    assert_eq!(dog.name, "Lassie");
+    assert_eq!(dog.good_boy, Some(true));
+    assert_eq!(dog.handler, "Timmy");
+}
+
+// Conflicting definitions emit a deprecation warning
+#[allow(deprecated)]
+mod conflicts {
+    use openapi_gen::openapi_client;
+    use serde_json::json;
+
+    openapi_client!("tests/fixtures/all_of_conflicts.yaml", "PetsApi");
+
+    #[test]
+    fn test_last_conflicting_definition_is_used() {
+        let tagged: Tagged = serde_json::from_value(json!({"id": 3, "name": 7})).unwrap();
+
+        let name: i32 = tagged.name;
+        assert_eq!(name, 7);
+    }
+}
+
+#[tokio::test]
+async fn test_all_of_request_and_response() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/dogs"))
+        .and(body_json(json!({
+            "id": 1,
+            "name": "Rex",
+            "breed": "Boxer",
+            "goodBoy": true
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "id": 1,
+            "name": "Rex",
+            "breed": "Boxer"
+        })))
+        .mount(&server)
+        .await;
+    let client = PetsApi::for_testing(server.uri());
+
+    let dog = client
+        .create_dog(Dog {
+            id: 1,
+            name: "Rex".to_string(),
+            breed: "Boxer".to_string(),
+            good_boy: Some(true),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(dog.breed, "Boxer");
+}
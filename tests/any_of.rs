@@ -0,0 +1,60 @@
+#![cfg(not(feature = "ureq"))]
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!("tests/fixtures/any_of.yaml", "ContactsApi");
+
+#[test]
+fn test_most_specific_variant_is_picked() {
+    // `NamedEmail` requires more properties, so it's tried before `Email`
+    let address: Address =
+        serde_json::from_value(json!({"email": "john@example.com", "name": "John"})).unwrap();
+    assert!(matches!(&address, Address::NamedEmail(named) if named.name == "John"));
+
+    let address: Address = serde_json::from_value(json!({"email": "john@example.com"})).unwrap();
+    assert!(matches!(&address, Address::Email(email) if email.email == "john@example.com"));
+
+    let address: Address = serde_json::from_value(json!("+31 20 123 4567")).unwrap();
+    assert!(matches!(&address, Address::Phone(phone) if phone == "+31 20 123 4567"));
+}
+
+#[test]
+fn test_integers_are_tried_before_numbers() {
+    let amount: Amount = serde_json::from_value(json!(42)).unwrap();
+    assert!(matches!(amount, Amount::Variant2(42)));
+
+    let amount: Amount = serde_json::from_value(json!(4.2)).unwrap();
+    assert!(matches!(amount, Amount::Variant1(value) if value == 4.2));
+}
+
+#[test]
+fn test_unmatched_values_are_kept() {
+    let address: Address = serde_json::from_value(json!({"fax": 12345})).unwrap();
+    assert!(matches!(&address, Address::Other(value) if value["fax"] == 12345));
+
+    assert_eq!(
+        serde_json::to_value(&address).unwrap(),
+        json!({"fax": 12345})
+    );
+}
+
+#[tokio::test]
+async fn test_any_of_field_in_response() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/contacts/7"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 7,
+            "address": {"email": "jane@example.com", "name": "Jane"}
+        })))
+        .mount(&server)
+        .await;
+
+    let client = ContactsApi::for_testing(server.uri());
+    let contact = client.get_contact(7).await.unwrap();
+
+    assert!(matches!(&contact.address, Address::NamedEmail(named) if named.name == "Jane"));
+}
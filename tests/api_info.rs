@@ -0,0 +1,54 @@
+mod full_info {
+    use openapi_gen::openapi_client;
+
+    openapi_client!("openapi.json", "InfoApi");
+
+    #[test]
+    fn test_api_info_is_taken_from_spec() {
+        assert_eq!(API_INFO.title, "OpenAPI Client Test API");
+        assert_eq!(API_INFO.version, "2.1.0");
+        assert!(
+            API_INFO
+                .description
+                .unwrap()
+                .starts_with("A comprehensive test API")
+        );
+        assert_eq!(API_INFO.contact_email, Some("test@example.com"));
+        assert_eq!(API_INFO.license_name, Some("MIT"));
+    }
+}
+
+mod minimal_info {
+    use openapi_gen::openapi_client;
+
+    openapi_client!("tests/fixtures/tagged_operations.yaml", "StoreApi");
+
+    #[test]
+    fn test_missing_info_fields_are_none() {
+        const INFO: ApiInfo = API_INFO;
+
+        assert_eq!(INFO.title, "Store API");
+        assert_eq!(INFO.version, "1.0.0");
+        assert_eq!(INFO.description, None);
+        assert_eq!(INFO.contact_email, None);
+        assert_eq!(INFO.license_name, None);
+    }
+}
+
+mod api_info_schema {
+    use openapi_gen::openapi_client;
+
+    openapi_client!("tests/fixtures/metadata_schemas.yaml", "StatusApi");
+
+    #[test]
+    fn test_api_info_schema_keeps_its_name() {
+        let info = ApiInfo {
+            build: "abc123".to_string(),
+        };
+        const METADATA: SchemaApiInfo = API_INFO;
+
+        assert_eq!(info.build, "abc123");
+        assert_eq!(METADATA.title, "Status API");
+        assert_eq!(METADATA.version, "3.2.0");
+    }
+}
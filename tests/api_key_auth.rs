@@ -0,0 +1,108 @@
+#![cfg(not(feature = "ureq"))]
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+use wiremock::matchers::{header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!(
+    "tests/fixtures/api_key.yaml",
+    "WeatherApi",
+    auth = "api_key"
+);
+
+mod query {
+    use openapi_gen::openapi_client;
+
+    openapi_client!(
+        "tests/fixtures/api_key.yaml",
+        "WeatherQueryApi",
+        auth = "api_key",
+        overlay = "tests/fixtures/api_key_query.yaml"
+    );
+}
+
+fn forecast() -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({"summary": "sunny"}))
+}
+
+#[tokio::test]
+async fn test_api_key_is_sent_in_header() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/forecast"))
+        .and(header("x-api-key", "secret-key"))
+        .respond_with(forecast())
+        .mount(&server)
+        .await;
+    let client = WeatherApi::for_testing(server.uri()).with_api_key("secret-key");
+
+    let forecast = client.get_forecast().await.unwrap();
+
+    assert_eq!(forecast.summary, "sunny");
+}
+
+#[tokio::test]
+async fn test_api_key_is_sent_in_query() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/forecast"))
+        .and(query_param("api_key", "secret-key"))
+        .respond_with(forecast())
+        .mount(&server)
+        .await;
+    let client = query::WeatherQueryApi::for_testing(server.uri()).with_api_key("secret-key");
+
+    let forecast = client.get_forecast().await.unwrap();
+
+    assert_eq!(forecast.summary, "sunny");
+}
+
+#[tokio::test]
+async fn test_clear_auth_removes_api_key() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/forecast"))
+        .respond_with(forecast())
+        .mount(&server)
+        .await;
+    let mut client = WeatherApi::for_testing(server.uri()).with_api_key("secret-key");
+
+    client.clear_auth();
+    client.get_forecast().await.unwrap();
+
+    let requests = server.received_requests().await.unwrap();
+    assert!(requests[0].headers.get("x-api-key").is_none());
+}
+
+#[test]
+fn test_from_env_reads_api_key() {
+    // SAFETY: no other test reads or writes these variables
+    unsafe {
+        std::env::set_var("WEATHER_API_BASE_URL", "http://127.0.0.1:8080");
+        std::env::set_var("WEATHER_API_TOKEN", "env-key");
+    }
+
+    let client = WeatherApi::from_env().unwrap();
+
+    assert_eq!(client.api_key.as_deref(), Some("env-key"));
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn test_http_request_carries_api_key() {
+    let request = WeatherApi::new("https://api.example.com")
+        .with_api_key("secret-key")
+        .get_forecast_http_request()
+        .unwrap();
+    assert_eq!(request.headers()["x-api-key"], "secret-key");
+
+    let request = query::WeatherQueryApi::new("https://api.example.com")
+        .with_api_key("secret-key")
+        .get_forecast_http_request()
+        .unwrap();
+    assert_eq!(
+        request.uri().to_string(),
+        "https://api.example.com/forecast?api_key=secret-key"
+    );
+}
@@ -0,0 +1,46 @@
+use openapi_gen::openapi_client;
+
+openapi_client!("openapi.json", "PatchApi", apply_patch = true);
+
+fn user() -> User {
+    serde_json::from_value(serde_json::json!({
+        "id": 1,
+        "username": "john_doe",
+        "email": "john@example.com",
+        "firstName": "John",
+        "lastName": "Doe",
+        "status": "active"
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_set_fields_overwrite_the_full_struct() {
+    let mut user = user();
+    let patch = UpdateUserRequest {
+        first_name: Some("Jane".to_string()),
+        last_name: None,
+        email: Some("jane@example.com".to_string()),
+        profile: None,
+    };
+
+    user.apply_patch(&patch);
+
+    assert_eq!(user.first_name.as_deref(), Some("Jane"));
+    assert_eq!(user.last_name.as_deref(), Some("Doe"));
+    assert_eq!(user.email, "jane@example.com");
+    assert!(user.profile.is_none());
+}
+
+#[test]
+fn test_empty_patch_leaves_the_full_struct_unchanged() {
+    let mut user = user();
+    let patch: UpdateUserRequest = serde_json::from_value(serde_json::json!({})).unwrap();
+
+    user.apply_patch(&patch);
+
+    assert_eq!(
+        serde_json::to_value(&user).unwrap(),
+        serde_json::to_value(self::user()).unwrap()
+    );
+}
@@ -0,0 +1,64 @@
+#![cfg(not(feature = "ureq"))]
+
+use openapi_gen::openapi_client;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!(
+    "tests/fixtures/auth_check.yaml",
+    "AccountsApi",
+    auth = "bearer",
+    auth_check_operation = "getCurrentUser"
+);
+
+/// Accept the `valid` token on `/me`, and answer 401 for any other request
+async fn accounts_server() -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/me"))
+        .and(header("authorization", "Bearer valid"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "id": 1 })))
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/me"))
+        .respond_with(ResponseTemplate::new(401))
+        .mount(&server)
+        .await;
+    server
+}
+
+#[tokio::test]
+async fn test_valid_credentials_are_verified() {
+    let server = accounts_server().await;
+    let client = AccountsApi::for_testing(server.uri()).with_bearer_token("valid");
+
+    client.verify_credentials().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_rejected_credentials_are_unauthorized() {
+    let server = accounts_server().await;
+    let client = AccountsApi::for_testing(server.uri()).with_bearer_token("expired");
+
+    let error = client.verify_credentials().await.unwrap_err();
+
+    assert!(matches!(error, ApiError::Unauthorized));
+    assert!(error.is_unauthorized());
+}
+
+#[tokio::test]
+async fn test_other_failures_keep_their_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/me"))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&server)
+        .await;
+    let client = AccountsApi::for_testing(server.uri()).with_bearer_token("valid");
+
+    let error = client.verify_credentials().await.unwrap_err();
+
+    assert_eq!(error.status(), Some(503));
+}
@@ -0,0 +1,139 @@
+#![cfg(not(feature = "ureq"))]
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!("openapi.json", "LegacyApi", auth = "basic");
+
+async fn user_server() -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/42"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 42,
+            "username": "john_doe",
+            "email": "john@example.com",
+            "status": "active"
+        })))
+        .mount(&server)
+        .await;
+    server
+}
+
+async fn authorization_headers(server: &MockServer) -> Vec<Option<String>> {
+    server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .map(|request| {
+            request
+                .headers
+                .get("authorization")
+                .map(|value| value.to_str().unwrap().to_string())
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_basic_auth_is_sent() {
+    let server = user_server().await;
+    let client = LegacyApi::for_testing(server.uri()).with_basic_auth("john", "secret");
+
+    client.get_user_by_id(42).await.unwrap();
+
+    assert_eq!(
+        authorization_headers(&server).await,
+        [Some("Basic am9objpzZWNyZXQ=".to_string())]
+    );
+}
+
+#[tokio::test]
+async fn test_header_is_omitted_without_credentials() {
+    let server = user_server().await;
+    let mut client = LegacyApi::for_testing(server.uri());
+
+    client.get_user_by_id(42).await.unwrap();
+    client = client.with_basic_auth("john", "secret");
+    client.clear_auth();
+    client.get_user_by_id(42).await.unwrap();
+
+    assert_eq!(authorization_headers(&server).await, [None, None]);
+}
+
+#[test]
+fn test_builder_sets_basic_auth() {
+    let client = LegacyApi::builder()
+        .base_url("https://api.example.com")
+        .basic_auth("john", "secret")
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        client.basic_auth,
+        Some(("john".to_string(), "secret".to_string()))
+    );
+}
+
+#[test]
+fn test_from_env_reads_user_and_password() {
+    // SAFETY: no other test reads or writes these variables
+    unsafe {
+        std::env::set_var("LEGACY_API_BASE_URL", "http://127.0.0.1:8080");
+        std::env::set_var("LEGACY_API_TOKEN", "john:pass:word");
+    }
+
+    let client = LegacyApi::from_env().unwrap();
+
+    assert_eq!(
+        client.basic_auth,
+        Some(("john".to_string(), "pass:word".to_string()))
+    );
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn test_http_request_carries_basic_auth() {
+    let client = LegacyApi::new("https://api.example.com");
+    for (user, password, header) in [
+        ("john", "secret", "Basic am9objpzZWNyZXQ="),
+        ("ab", "c", "Basic YWI6Yw=="),
+        ("ab", "cd", "Basic YWI6Y2Q="),
+        (
+            "Aladdin",
+            "open sesame",
+            "Basic QWxhZGRpbjpvcGVuIHNlc2FtZQ==",
+        ),
+    ] {
+        let request = client
+            .clone()
+            .with_basic_auth(user, password)
+            .get_user_by_id_http_request(42)
+            .unwrap();
+        assert_eq!(request.headers()["authorization"], header);
+    }
+}
+
+#[cfg(feature = "blocking")]
+#[tokio::test]
+async fn test_blocking_client_sends_basic_auth() {
+    let server = user_server().await;
+    let uri = server.uri();
+
+    // The blocking client runs its own runtime, so it must live outside the async context
+    tokio::task::spawn_blocking(move || {
+        LegacyApi::with_client(uri, reqwest::blocking::Client::new())
+            .with_basic_auth("john", "secret")
+            .get_user_by_id(42)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    assert_eq!(
+        authorization_headers(&server).await,
+        [Some("Basic am9objpzZWNyZXQ=".to_string())]
+    );
+}
@@ -0,0 +1,119 @@
+#![cfg(not(feature = "ureq"))]
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!("openapi.json", "BearerApi", auth = "bearer");
+
+async fn user_server() -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/42"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 42,
+            "username": "john_doe",
+            "email": "john@example.com",
+            "status": "active"
+        })))
+        .mount(&server)
+        .await;
+    server
+}
+
+async fn authorization_headers(server: &MockServer) -> Vec<Option<String>> {
+    server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .map(|request| {
+            request
+                .headers
+                .get("authorization")
+                .map(|value| value.to_str().unwrap().to_string())
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_bearer_token_is_sent() {
+    let server = user_server().await;
+    let client = BearerApi::for_testing(server.uri()).with_bearer_token("secret-token");
+
+    client.get_user_by_id(42).await.unwrap();
+
+    assert_eq!(
+        authorization_headers(&server).await,
+        [Some("Bearer secret-token".to_string())]
+    );
+}
+
+#[tokio::test]
+async fn test_header_is_omitted_without_token() {
+    let server = user_server().await;
+    let client = BearerApi::for_testing(server.uri());
+
+    client.get_user_by_id(42).await.unwrap();
+
+    assert_eq!(authorization_headers(&server).await, [None]);
+}
+
+#[tokio::test]
+async fn test_clear_auth_removes_token() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/42"))
+        .and(header("authorization", "Bearer secret-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 42,
+            "username": "john_doe",
+            "email": "john@example.com",
+            "status": "active"
+        })))
+        .mount(&server)
+        .await;
+    let mut client = BearerApi::for_testing(server.uri()).with_bearer_token("secret-token");
+
+    client.get_user_by_id(42).await.unwrap();
+    client.clear_auth();
+    let error = client.get_user_by_id(42).await.unwrap_err();
+
+    assert!(matches!(error, ApiError::Api { status: 404, .. }));
+}
+
+#[test]
+fn test_from_env_reads_token() {
+    // SAFETY: no other test reads or writes these variables
+    unsafe {
+        std::env::set_var("BEARER_API_BASE_URL", "http://127.0.0.1:8080");
+        std::env::set_var("BEARER_API_TOKEN", "env-token");
+    }
+
+    let client = BearerApi::from_env().unwrap();
+
+    assert_eq!(client.bearer_token.as_deref(), Some("env-token"));
+}
+
+#[cfg(feature = "blocking")]
+#[tokio::test]
+async fn test_blocking_client_sends_bearer_token() {
+    let server = user_server().await;
+    let uri = server.uri();
+
+    // The blocking client runs its own runtime, so it must live outside the async context
+    tokio::task::spawn_blocking(move || {
+        BearerApi::with_client(uri, reqwest::blocking::Client::new())
+            .with_bearer_token("blocking-token")
+            .get_user_by_id(42)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    assert_eq!(
+        authorization_headers(&server).await,
+        [Some("Bearer blocking-token".to_string())]
+    );
+}
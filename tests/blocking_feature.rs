@@ -76,5 +76,5 @@ fn test_blocking_methods_exist() {
     // Example method signatures that should exist:
     // client.list_users(Some(10), Some(0), Some("active".to_string())) -> ApiResult<UserList>
     // client.get_user_by_id(123) -> ApiResult<User>
-    // client.create_user(serde_json::json!({"name": "Test"})) -> ApiResult<User>
+    // client.create_user(CreateUserRequest { .. }) -> ApiResult<User>
 }
@@ -0,0 +1,94 @@
+#![cfg(feature = "cancellation")]
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+use std::time::{Duration, Instant};
+use tokio_util::sync::CancellationToken;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!("openapi.json", "CancellableApi");
+
+async fn user_server(delay: Duration) -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/42"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({
+                    "id": 42,
+                    "username": "john_doe",
+                    "email": "john@example.com",
+                    "status": "active"
+                }))
+                .set_delay(delay),
+        )
+        .mount(&server)
+        .await;
+    server
+}
+
+#[tokio::test]
+async fn test_uncancelled_request_completes() {
+    let server = user_server(Duration::ZERO).await;
+    let client = CancellableApi::for_testing(server.uri());
+
+    let user = client
+        .get_user_by_id_cancellable(42, CancellationToken::new())
+        .await
+        .unwrap();
+
+    assert_eq!(user.id, 42);
+}
+
+#[tokio::test]
+async fn test_cancelling_the_token_aborts_the_request() {
+    let server = user_server(Duration::from_secs(10)).await;
+    let client = CancellableApi::for_testing(server.uri());
+
+    let token = CancellationToken::new();
+    let canceller = token.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        canceller.cancel();
+    });
+
+    let start = Instant::now();
+    let result = client.get_user_by_id_cancellable(42, token).await;
+
+    assert!(matches!(result, Err(ApiError::Cancelled)));
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
+
+#[tokio::test]
+async fn test_already_cancelled_token_sends_nothing() {
+    let server = MockServer::start().await;
+    let client = CancellableApi::for_testing(server.uri());
+
+    let token = CancellationToken::new();
+    token.cancel();
+    let result = client.list_users_cancellable(None, None, None, token).await;
+
+    assert!(matches!(result, Err(ApiError::Cancelled)));
+    assert!(server.received_requests().await.unwrap().is_empty());
+}
+
+mod param_structs {
+    use super::*;
+
+    openapi_client!("openapi.json", "CancellableApi", use_param_structs = true);
+
+    #[tokio::test]
+    async fn test_cancellable_variant_takes_param_struct() {
+        let server = user_server(Duration::ZERO).await;
+        let client = CancellableApi::for_testing(server.uri());
+
+        let params = GetUserByIdParams::new(42);
+        let user = client
+            .get_user_by_id_cancellable(params, CancellationToken::new())
+            .await
+            .unwrap();
+
+        assert_eq!(user.id, 42);
+    }
+}
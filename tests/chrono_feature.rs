@@ -0,0 +1,98 @@
+use openapi_gen::openapi_client;
+
+openapi_client!("tests/fixtures/dates.json", "EventsApi");
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_date_fields_use_chrono_types() {
+    use chrono::{DateTime, NaiveDate, Utc};
+
+    let event: Event = serde_json::from_value(serde_json::json!({
+        "id": 1,
+        "created_at": "2024-03-01T12:30:00Z",
+        "scheduledFor": "2024-04-15"
+    }))
+    .unwrap();
+
+    let created_at: DateTime<Utc> = event.created_at;
+    let scheduled_for: Option<NaiveDate> = event.scheduled_for;
+    assert_eq!(created_at.to_rfc3339(), "2024-03-01T12:30:00+00:00");
+    assert_eq!(scheduled_for, NaiveDate::from_ymd_opt(2024, 4, 15));
+
+    let json = serde_json::to_value(&event).unwrap();
+    assert_eq!(json["created_at"], "2024-03-01T12:30:00Z");
+    assert_eq!(json["scheduledFor"], "2024-04-15");
+}
+
+#[cfg(not(feature = "chrono"))]
+#[test]
+fn test_date_fields_fall_back_to_strings() {
+    let event = Event {
+        id: 1,
+        created_at: "2024-03-01T12:30:00Z".to_string(),
+        scheduled_for: None,
+    };
+
+    let _: String = event.created_at;
+}
+
+#[cfg(feature = "chrono")]
+#[tokio::test]
+async fn test_date_query_parameter_is_typed() {
+    use chrono::{TimeZone, Utc};
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/events"))
+        .and(query_param("since", "2024-03-01T12:30:00Z"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = EventsApi::new(server.uri());
+
+    let since = Utc.with_ymd_and_hms(2024, 3, 1, 12, 30, 0).unwrap();
+    let events = client.list_events(Some(since)).await.unwrap();
+
+    assert!(events.is_empty());
+}
+
+#[cfg(not(feature = "chrono"))]
+#[test]
+fn test_date_query_parameter_is_a_string() {
+    let client = EventsApi::new("https://api.example.com");
+
+    let _future = client.list_events(Some("2024-03-01T12:30:00Z"));
+}
+
+#[cfg(feature = "chrono")]
+#[tokio::test]
+async fn test_date_query_parameters_follow_their_date_format() {
+    use chrono::{NaiveDate, TimeZone, Utc};
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/events/search"))
+        .and(query_param("from", "1709296200"))
+        .and(query_param("until", "1709299800500"))
+        .and(query_param("day", "20240301"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = EventsApi::new(server.uri());
+
+    let from = Utc.with_ymd_and_hms(2024, 3, 1, 12, 30, 0).unwrap();
+    let until = Utc.timestamp_millis_opt(1_709_299_800_500).unwrap();
+    let day = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+    let events = client
+        .search_events(from, Some(until), Some(day))
+        .await
+        .unwrap();
+
+    assert!(events.is_empty());
+}
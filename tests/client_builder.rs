@@ -0,0 +1,87 @@
+#![cfg(not(feature = "ureq"))]
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+use std::time::Duration;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!("openapi.json", "BuiltApi", auth = "bearer");
+
+async fn user_server() -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/42"))
+        .and(header("authorization", "Bearer secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 42,
+            "username": "john_doe",
+            "email": "john@example.com",
+            "status": "active"
+        })))
+        .mount(&server)
+        .await;
+    server
+}
+
+#[tokio::test]
+async fn test_builder_produces_an_equivalent_client() {
+    let server = user_server().await;
+    let built = BuiltApi::builder()
+        .base_url(server.uri())
+        .bearer_token("secret")
+        .timeout(Duration::from_secs(5))
+        .build()
+        .unwrap();
+    let chained = BuiltApi::new(server.uri())
+        .with_bearer_token("secret")
+        .with_timeout(Duration::from_secs(5));
+
+    let built_user = built.get_user_by_id(42).await.unwrap();
+    let chained_user = chained.get_user_by_id(42).await.unwrap();
+
+    assert_eq!(built_user.id, chained_user.id);
+    assert_eq!(built.timeout, chained.timeout);
+    assert_eq!(built.bearer_token, chained.bearer_token);
+}
+
+#[tokio::test]
+async fn test_builder_with_custom_client() {
+    let server = user_server().await;
+    let http_client = reqwest::Client::builder().no_proxy().build().unwrap();
+    let client = BuiltApi::builder()
+        .client(http_client)
+        .base_url(server.uri())
+        .bearer_token("secret")
+        .build()
+        .unwrap();
+
+    let user = client.get_user_by_id(42).await.unwrap();
+
+    assert_eq!(user.id, 42);
+}
+
+#[test]
+fn test_builder_requires_a_base_url() {
+    let result = BuiltApi::builder().bearer_token("secret").build();
+
+    assert!(matches!(result, Err(ApiError::Config(_))));
+}
+
+#[cfg(feature = "middleware")]
+#[tokio::test]
+async fn test_builder_with_middleware_client() {
+    use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
+
+    let server = user_server().await;
+    let middleware_client = ClientBuilder::new(reqwest::Client::new()).build();
+    let client: BuiltApi<ClientWithMiddleware> = BuiltApi::builder()
+        .base_url(server.uri())
+        .bearer_token("secret")
+        .build_with(middleware_client)
+        .unwrap();
+
+    let user = client.get_user_by_id(42).await.unwrap();
+
+    assert_eq!(user.id, 42);
+}
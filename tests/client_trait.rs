@@ -0,0 +1,108 @@
+#![cfg(not(feature = "ureq"))]
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!("tests/fixtures/client_trait.yaml", "UsersClient", trait = true);
+
+/// Fake client answering from a fixed list of users instead of sending requests
+struct FakeUsers(Vec<User>);
+
+#[async_trait::async_trait]
+impl UsersClientApi for FakeUsers {
+    async fn list_users(&self, name: Option<&str>) -> ApiResult<Vec<User>> {
+        Ok(self
+            .0
+            .iter()
+            .filter(|user| name.is_none_or(|name| user.name == name))
+            .cloned()
+            .collect())
+    }
+
+    async fn get_user(&self, id: i64) -> ApiResult<User> {
+        self.0
+            .iter()
+            .find(|user| user.id == id)
+            .cloned()
+            .ok_or_else(|| ApiError::Api {
+                status: 404,
+                message: "User not found".to_string(),
+            })
+    }
+
+    async fn delete_user(&self, _id: i64) -> ApiResult<()> {
+        Ok(())
+    }
+}
+
+/// Code under test, depending on the trait rather than the client
+async fn user_name(api: &dyn UsersClientApi, id: i64) -> ApiResult<String> {
+    Ok(api.get_user(id).await?.name)
+}
+
+#[tokio::test]
+async fn test_fake_implementor_is_used_as_trait_object() {
+    let api: Box<dyn UsersClientApi> = Box::new(FakeUsers(vec![
+        User {
+            id: 1,
+            name: "john".to_string(),
+        },
+        User {
+            id: 2,
+            name: "jane".to_string(),
+        },
+    ]));
+
+    assert_eq!(user_name(api.as_ref(), 2).await.unwrap(), "jane");
+    assert!(user_name(api.as_ref(), 3).await.unwrap_err().is_not_found());
+    assert_eq!(api.list_users(Some("john")).await.unwrap().len(), 1);
+    assert!(api.delete_user(1).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_client_implements_trait() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/42"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"id": 42, "name": "jane"})))
+        .mount(&server)
+        .await;
+    let client = UsersClient::for_testing(server.uri());
+
+    assert_eq!(user_name(&client, 42).await.unwrap(), "jane");
+}
+
+#[cfg(feature = "blocking")]
+mod blocking {
+    use super::*;
+
+    /// Fake blocking client, whose trait has no async methods
+    struct FakeBlockingUsers;
+
+    impl UsersClientBlockingApi for FakeBlockingUsers {
+        fn list_users(&self, _name: Option<&str>) -> ApiResult<Vec<User>> {
+            Ok(Vec::new())
+        }
+
+        fn get_user(&self, id: i64) -> ApiResult<User> {
+            Ok(User {
+                id,
+                name: "john".to_string(),
+            })
+        }
+
+        fn delete_user(&self, _id: i64) -> ApiResult<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_blocking_trait_has_blocking_methods() {
+        let api: &dyn UsersClientBlockingApi = &FakeBlockingUsers;
+
+        assert_eq!(api.get_user(7).unwrap().name, "john");
+        assert!(api.list_users(None).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,31 @@
+use openapi_gen::openapi_client;
+
+mod yaml {
+    use super::*;
+
+    openapi_client!("tests/fixtures/sniffed_yaml.txt", "NotesApi");
+
+    #[test]
+    fn test_yaml_spec_without_yaml_extension_is_parsed() {
+        let note: Note = serde_json::from_str(r#"{"id": 1, "text": "Hello"}"#).unwrap();
+
+        assert_eq!(note.text, "Hello");
+        let client = NotesApi::new("https://api.example.com");
+        let _future = client.get_note(note.id);
+    }
+}
+
+mod json {
+    use super::*;
+
+    openapi_client!("tests/fixtures/sniffed_json", "NotesApi");
+
+    #[test]
+    fn test_json_spec_without_extension_is_parsed() {
+        let note: Note = serde_json::from_str(r#"{"id": 1, "text": "Hello"}"#).unwrap();
+
+        assert_eq!(note.id, 1);
+        let client = NotesApi::new("https://api.example.com");
+        let _future = client.get_note(note.id);
+    }
+}
@@ -0,0 +1,68 @@
+#![cfg(not(feature = "ureq"))]
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!("tests/fixtures/cookie_params.yaml", "CartApi");
+
+async fn cart_server(cookie: &str) -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/cart"))
+        .and(header("cookie", cookie))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"items": 3})))
+        .expect(1)
+        .mount(&server)
+        .await;
+    server
+}
+
+#[tokio::test]
+async fn test_cookie_parameters_are_sent_in_one_header() {
+    let server = cart_server("session_id=s-1; currency=EUR").await;
+    let client = CartApi::for_testing(server.uri());
+
+    let cart = client.get_cart("s-1", Some("EUR"), None).await.unwrap();
+
+    assert_eq!(cart.items, 3);
+}
+
+#[tokio::test]
+async fn test_unset_optional_cookie_parameters_are_left_out() {
+    let server = cart_server("session_id=s-1").await;
+    let client = CartApi::for_testing(server.uri());
+
+    let cart = client.get_cart("s-1", None, Some(5)).await.unwrap();
+
+    assert_eq!(cart.items, 3);
+}
+
+mod param_structs {
+    use super::cart_server;
+    use openapi_gen::openapi_client;
+
+    openapi_client!(
+        "tests/fixtures/cookie_params.yaml",
+        "CartApi",
+        use_param_structs = true
+    );
+
+    #[tokio::test]
+    async fn test_cookie_parameters_are_sent_from_param_structs() {
+        let server = cart_server("session_id=s-1; currency=USD").await;
+        let client = CartApi::for_testing(server.uri());
+
+        let cart = client
+            .get_cart(GetCartParams {
+                session_id: "s-1".to_string(),
+                currency: Some("USD".to_string()),
+                limit: Some(5),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(cart.items, 3);
+    }
+}
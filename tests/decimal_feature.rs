@@ -0,0 +1,50 @@
+use openapi_gen::openapi_client;
+
+openapi_client!("tests/fixtures/decimal.json", "BillingApi");
+
+#[cfg(feature = "decimal")]
+#[test]
+fn test_decimal_fields_use_fixed_point_type() {
+    use rust_decimal::Decimal;
+    use std::str::FromStr;
+
+    let invoice = Invoice {
+        id: 1,
+        amount: Decimal::from_str("19.99").unwrap(),
+        tax: Decimal::from_str("4.20").unwrap(),
+        total: Decimal::from_str("24.19").unwrap(),
+        discount: Some(0.1),
+    };
+
+    // Sum must be exact, which would not hold for floating point types
+    assert_eq!(invoice.amount + invoice.tax, invoice.total);
+
+    let json = serde_json::to_value(&invoice).unwrap();
+    let parsed: Invoice = serde_json::from_value(json).unwrap();
+    assert_eq!(parsed.total, Decimal::from_str("24.19").unwrap());
+}
+
+#[cfg(feature = "decimal")]
+#[test]
+fn test_decimal_query_parameter() {
+    use rust_decimal::Decimal;
+
+    let client = BillingApi::new("https://api.example.com");
+
+    // Decimal query parameters are passed as typed values
+    let _future = client.get_invoice(1, Some(Decimal::new(1050, 2)));
+}
+
+#[cfg(not(feature = "decimal"))]
+#[test]
+fn test_decimal_fields_fall_back_to_floats() {
+    let invoice = Invoice {
+        id: 1,
+        amount: 19.99,
+        tax: "4.20".to_string(),
+        total: 24.19,
+        discount: None,
+    };
+
+    let _: f32 = invoice.amount;
+}
@@ -0,0 +1,25 @@
+mod with_servers {
+    use openapi_gen::openapi_client;
+
+    openapi_client!("openapi.json", "ServersApi");
+
+    #[test]
+    fn test_uses_first_server() {
+        let client = ServersApi::new_with_default_server();
+
+        assert_eq!(client.base_url, "https://api.test.com/v2");
+    }
+}
+
+mod with_server_variables {
+    use openapi_gen::openapi_client;
+
+    openapi_client!("tests/fixtures/servers.yaml", "RegionalApi");
+
+    #[test]
+    fn test_substitutes_variable_defaults() {
+        let client = RegionalApi::new_with_default_server();
+
+        assert_eq!(client.base_url, "https://eu.api.example.com/v1");
+    }
+}
@@ -0,0 +1,71 @@
+use openapi_gen::openapi_client;
+
+mod spec {
+    use super::*;
+
+    openapi_client!(
+        "tests/fixtures/ordering.yaml",
+        "OrderingApi",
+        dump_to = "target/generated/ordering_client.rs"
+    );
+}
+
+mod spec_again {
+    use super::*;
+
+    openapi_client!(
+        "tests/fixtures/ordering.yaml",
+        "OrderingApi",
+        dump_to = "target/generated/ordering_client_again.rs"
+    );
+}
+
+// The same spec, with its paths, methods and schemas in reverse order
+mod reversed_spec {
+    use super::*;
+
+    openapi_client!(
+        "tests/fixtures/ordering_reversed.yaml",
+        "OrderingApi",
+        dump_to = "target/generated/ordering_reversed_client.rs"
+    );
+}
+
+fn generated_code(name: &str) -> String {
+    std::fs::read_to_string(format!("target/generated/{}.rs", name)).unwrap()
+}
+
+#[test]
+fn test_output_is_the_same_for_every_run() {
+    assert_eq!(
+        generated_code("ordering_client"),
+        generated_code("ordering_client_again")
+    );
+}
+
+#[test]
+fn test_output_does_not_depend_on_the_order_of_the_spec() {
+    assert_eq!(
+        generated_code("ordering_client"),
+        generated_code("ordering_reversed_client")
+    );
+}
+
+#[test]
+fn test_operations_and_schemas_are_sorted() {
+    let code = generated_code("ordering_client");
+    let position = |item: &str| {
+        code.find(item)
+            .unwrap_or_else(|| panic!("{} not found", item))
+    };
+
+    // Operations by path, then method
+    assert!(position("fn list_owners(") < position("fn list_pets("));
+    assert!(position("fn list_pets(") < position("fn create_pet("));
+    assert!(position("fn create_pet(") < position("fn delete_pet("));
+    assert!(position("fn delete_pet(") < position("fn get_pet("));
+    // Schemas by name
+    assert!(position("pub struct NewPet") < position("pub struct Owner"));
+    assert!(position("pub struct Owner") < position("pub struct Pet"));
+    assert!(position("pub struct Pet") < position("pub enum Status"));
+}
@@ -0,0 +1,24 @@
+use openapi_gen::openapi_client;
+
+// Methods and fields get a `doc(alias)` with their name in the spec when it
+// differs from the Rust name, and none where rustdoc would reject the alias
+openapi_client!("tests/fixtures/doc_aliases.yaml", "CatalogApi");
+
+#[test]
+fn test_renamed_items_compile_with_aliases() {
+    let client = CatalogApi::new("https://api.example.com");
+    let _items = client.list_catalog_items();
+    let _deleted = client.delete_item(1);
+
+    let item: Item = serde_json::from_value(serde_json::json!({
+        "itemID": 1,
+        "type": "book",
+        "display name": "Rust",
+        "vendor's code": "R-1"
+    }))
+    .unwrap();
+
+    assert_eq!(item.item_id, 1);
+    assert_eq!(item.display_name.as_deref(), Some("Rust"));
+    assert_eq!(item.vendor_s_code.as_deref(), Some("R-1"));
+}
@@ -0,0 +1,38 @@
+use openapi_gen::openapi_client;
+
+openapi_client!(
+    "tests/fixtures/doc_comments.yaml",
+    "InvoicesApi",
+    dump_to = "target/generated/doc_comments_client.rs"
+);
+
+#[test]
+fn test_description_lines_are_kept() {
+    let code = std::fs::read_to_string("target/generated/doc_comments_client.rs").unwrap();
+
+    let expected = "
+/// An invoice sent to a customer.
+///
+/// Invoices are either:
+/// - `draft`, while they can be edited
+///   and deleted
+/// - `sent`, once the customer received them
+///
+/// ```text
+/// GET /invoices/{id}
+/// ```
+///
+/// ```json
+/// {\"id\": 1}
+/// ```
+#[derive(";
+    assert!(code.contains(expected), "{}", code);
+    assert!(code.contains("\n    /// Identifier of the invoice\n"));
+}
+
+#[test]
+fn test_documented_struct_is_generated() {
+    let invoice = Invoice { id: 1 };
+
+    assert_eq!(invoice.id, 1);
+}
@@ -0,0 +1,126 @@
+#![cfg(feature = "blocking")]
+
+use openapi_gen::openapi_client;
+
+openapi_client!("openapi.json", "DualApi", dual = true);
+
+#[test]
+fn test_dual_clients_are_distinct_types() {
+    let async_client = DualApi::new("https://api.example.com");
+    let blocking_client = DualApiBlocking::new("https://api.example.com");
+
+    let _: DualApi<reqwest::Client> = async_client;
+    let _: DualApiBlocking<reqwest::blocking::Client> = blocking_client;
+}
+
+#[test]
+fn test_blocking_client_methods_are_synchronous() {
+    // Blocking methods return results directly instead of futures
+    let _get_user: fn(&DualApiBlocking, i64) -> ApiResult<User> = DualApiBlocking::get_user_by_id;
+    let _delete_user: fn(&DualApiBlocking, i64) -> ApiResult<()> = DualApiBlocking::delete_user;
+}
+
+#[test]
+fn test_blocking_client_with_custom_client() {
+    let http_client = reqwest::blocking::Client::builder()
+        .timeout(std::time::Duration::from_secs(5))
+        .build()
+        .unwrap();
+    let client = DualApiBlocking::with_client("https://api.example.com", http_client);
+
+    let _: DualApiBlocking = client;
+}
+
+mod conversion {
+    use openapi_gen::openapi_client;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    openapi_client!("openapi.json", "AuthedApi", dual = true, auth = "bearer");
+
+    async fn user_server() -> MockServer {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/users/42"))
+            .and(header("authorization", "Bearer secret"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": 42,
+                "username": "john_doe",
+                "email": "john@example.com",
+                "status": "active"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        server
+    }
+
+    #[tokio::test]
+    async fn test_to_blocking_copies_the_configuration() {
+        let server = user_server().await;
+        let client = AuthedApi::new(server.uri())
+            .with_bearer_token("secret")
+            .with_timeout(std::time::Duration::from_secs(5));
+
+        let user = tokio::task::spawn_blocking(move || {
+            client
+                .to_blocking(reqwest::blocking::Client::new())
+                .get_user_by_id(42)
+        })
+        .await
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(user.id, 42);
+    }
+
+    #[tokio::test]
+    async fn test_to_async_copies_the_configuration() {
+        let server = user_server().await;
+        let uri = server.uri();
+        let client = tokio::task::spawn_blocking(move || {
+            AuthedApiBlocking::new(uri)
+                .with_bearer_token("secret")
+                .to_async(reqwest::Client::new())
+        })
+        .await
+        .unwrap();
+
+        let user = client.get_user_by_id(42).await.unwrap();
+
+        assert_eq!(user.id, 42);
+    }
+
+    #[tokio::test]
+    async fn test_to_blocking_sends_with_the_given_client() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/users/42"))
+            .and(header("x-origin", "blocking"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": 42,
+                "username": "john_doe",
+                "email": "john@example.com",
+                "status": "active"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = AuthedApi::new(server.uri()).with_bearer_token("secret");
+
+        let user = tokio::task::spawn_blocking(move || {
+            let mut headers = reqwest::header::HeaderMap::new();
+            headers.insert("x-origin", "blocking".parse().unwrap());
+            let http_client = reqwest::blocking::Client::builder()
+                .default_headers(headers)
+                .build()
+                .unwrap();
+            client.to_blocking(http_client).get_user_by_id(42)
+        })
+        .await
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(user.id, 42);
+    }
+}
@@ -0,0 +1,26 @@
+use openapi_gen::openapi_client;
+
+openapi_client!(
+    "openapi.json",
+    "DumpedApi",
+    dump_to = "target/generated/dump_to_client.rs"
+);
+
+#[cfg(not(feature = "ureq"))]
+#[test]
+fn test_generated_code_is_written_formatted() {
+    let code = std::fs::read_to_string("target/generated/dump_to_client.rs").unwrap();
+
+    assert!(code.contains("pub struct DumpedApi<C = reqwest::Client> {"));
+    assert!(
+        code.contains(
+            "\n    pub async fn get_user_by_id(&self, user_id: i64) -> ApiResult<User> {\n"
+        )
+    );
+    syn::parse_file(&code).unwrap();
+}
+
+#[test]
+fn test_dumped_client_is_still_generated() {
+    let _: DumpedApi = DumpedApi::new("https://api.example.com");
+}
@@ -0,0 +1,31 @@
+use openapi_gen::openapi_client;
+use serde_json::json;
+
+openapi_client!("tests/fixtures/enum_refs.yaml", "ShipmentsApi");
+
+#[test]
+fn test_field_referencing_component_enum() {
+    let shipment: Shipment = serde_json::from_value(json!({
+        "id": 1,
+        "status": "delivered",
+        "carrier": "ups"
+    }))
+    .unwrap();
+
+    assert!(matches!(shipment.status, ShipmentStatus::Delivered));
+}
+
+#[test]
+fn test_field_referencing_enum_through_alias_schema() {
+    let shipment: Shipment = serde_json::from_value(json!({
+        "id": 1,
+        "status": "pending",
+        "carrier": "dhl",
+        "returnCarrier": "ups"
+    }))
+    .unwrap();
+
+    let carrier: Carrier = shipment.carrier;
+    assert!(matches!(carrier, CarrierName::Dhl));
+    assert!(matches!(shipment.return_carrier, Some(CarrierName::Ups)));
+}
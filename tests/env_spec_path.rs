@@ -0,0 +1,69 @@
+use std::path::Path;
+use std::process::{Command, Output};
+
+/// Build the client of `tests/fixtures/env_spec_client.rs` with the given environment
+///
+/// The spec path is read from the environment at compile time, so the client
+/// is built by a separate `cargo` invocation rather than in this test.
+fn build_env_spec_client(envs: &[(&str, &str)]) -> Output {
+    Command::new(env!("CARGO"))
+        .args(["build", "--quiet", "--test", "env_spec_client"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .env_remove("OUT_DIR")
+        .envs(envs.iter().copied())
+        .output()
+        .expect("Failed to run cargo")
+}
+
+#[test]
+fn test_relative_path_without_out_dir() {
+    let output = build_env_spec_client(&[(
+        "OPENAPI_GEN_TEST_SPEC",
+        "tests/fixtures/shared_responses.json",
+    )]);
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn test_relative_path_is_resolved_against_out_dir() {
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("env_spec_out_dir");
+    std::fs::create_dir_all(out_dir.join("generated")).unwrap();
+    std::fs::copy(
+        "tests/fixtures/shared_responses.json",
+        out_dir.join("generated/api.json"),
+    )
+    .unwrap();
+
+    let output = build_env_spec_client(&[
+        ("OPENAPI_GEN_TEST_SPEC", "generated/api.json"),
+        ("OUT_DIR", out_dir.to_str().unwrap()),
+    ]);
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn test_missing_artifact_is_reported() {
+    let output =
+        build_env_spec_client(&[("OPENAPI_GEN_TEST_SPEC", "tests/fixtures/missing_spec.json")]);
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(
+        stderr.contains(
+            "Spec artifact tests/fixtures/missing_spec.json from environment variable \
+             OPENAPI_GEN_TEST_SPEC not found"
+        ),
+        "{}",
+        stderr
+    );
+}
@@ -0,0 +1,116 @@
+use openapi_gen::openapi_client;
+
+openapi_client!("tests/fixtures/error_bodies.json", "AccountsApi");
+
+fn api_error(message: &str) -> ApiError {
+    ApiError::Api {
+        status: 404,
+        message: message.to_string(),
+    }
+}
+
+#[test]
+fn test_operation_error_helper_returns_typed_body() {
+    let error = api_error(r#"{"title": "Not Found", "status": 404, "detail": "No such account"}"#);
+
+    let problem: Option<Problem> = AccountsApi::get_account_error(&error);
+    let problem = problem.unwrap();
+
+    assert_eq!(problem.title, "Not Found");
+    assert_eq!(problem.status, 404);
+    assert_eq!(problem.detail.as_deref(), Some("No such account"));
+}
+
+#[test]
+fn test_mismatching_error_body_is_none() {
+    assert!(AccountsApi::get_account_error(&api_error("Internal Server Error")).is_none());
+    assert!(AccountsApi::get_account_error(&api_error(r#"{"unexpected": true}"#)).is_none());
+}
+
+#[test]
+fn test_generic_error_body() {
+    let error = api_error(r#"{"title": "Not Found", "status": 404}"#);
+
+    let body: Option<serde_json::Value> = error.error_body();
+    assert_eq!(body.unwrap()["title"], "Not Found");
+
+    let error = ApiError::Serialization(serde_json::from_str::<()>("").unwrap_err());
+    assert!(error.error_body::<serde_json::Value>().is_none());
+}
+
+#[test]
+fn test_display_shows_problem_details() {
+    let error = api_error(r#"{"title": "Not Found", "status": 404, "detail": "No such account"}"#);
+
+    assert_eq!(
+        error.to_string(),
+        "API error 404: Not Found: No such account"
+    );
+}
+
+#[test]
+fn test_display_shows_error_message() {
+    let error = api_error(r#"{"message": "Account is locked", "code": 17}"#);
+
+    assert_eq!(error.to_string(), "API error 404: Account is locked");
+}
+
+#[test]
+fn test_display_falls_back_to_raw_body() {
+    assert_eq!(
+        api_error("Internal Server Error").to_string(),
+        "API error 404: Internal Server Error"
+    );
+    assert_eq!(
+        api_error(r#"{"unexpected": true}"#).to_string(),
+        r#"API error 404: {"unexpected": true}"#
+    );
+}
+
+#[test]
+fn test_typed_error_body() {
+    let error = ApiError::ApiTyped {
+        status: 404,
+        body: serde_json::json!({"title": "Not Found", "status": 404, "detail": "No such account"}),
+    };
+
+    let problem: Option<Problem> = AccountsApi::get_account_error(&error);
+    assert_eq!(problem.unwrap().detail.as_deref(), Some("No such account"));
+    assert_eq!(
+        error.to_string(),
+        "API error 404: Not Found: No such account"
+    );
+
+    let error = ApiError::ApiTyped {
+        status: 400,
+        body: serde_json::json!({"unexpected": true}),
+    };
+    assert_eq!(error.to_string(), r#"API error 400: {"unexpected":true}"#);
+}
+
+#[test]
+fn test_status_helpers() {
+    let status_error = |status| ApiError::Api {
+        status,
+        message: String::new(),
+    };
+
+    assert!(status_error(404).is_not_found());
+    assert!(!status_error(410).is_not_found());
+    assert!(status_error(401).is_unauthorized());
+    assert!(!status_error(403).is_unauthorized());
+    assert!(status_error(500).is_server_error());
+    assert!(status_error(503).is_server_error());
+    assert!(!status_error(499).is_server_error());
+
+    let typed_error = ApiError::ApiTyped {
+        status: 404,
+        body: serde_json::Value::Null,
+    };
+    assert!(typed_error.is_not_found());
+    assert_eq!(typed_error.status(), Some(404));
+
+    let error = ApiError::Config("missing".to_string());
+    assert_eq!(error.status(), None);
+    assert!(!error.is_not_found() && !error.is_unauthorized() && !error.is_server_error());
+}
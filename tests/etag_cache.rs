@@ -0,0 +1,91 @@
+#![cfg(not(feature = "ureq"))]
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+use wiremock::matchers::{header, header_exists, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!("openapi.json", "CachingApi", etag_cache = true);
+
+fn user_json() -> serde_json::Value {
+    json!({
+        "id": 42,
+        "username": "john_doe",
+        "email": "john@example.com",
+        "status": "active"
+    })
+}
+
+async fn etag_server() -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/42"))
+        .and(header("if-none-match", "\"v1\""))
+        .respond_with(ResponseTemplate::new(304))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/users/42"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("etag", "\"v1\"")
+                .set_body_json(user_json()),
+        )
+        .mount(&server)
+        .await;
+    server
+}
+
+#[tokio::test]
+async fn test_not_modified_returns_cached_body() {
+    let server = etag_server().await;
+    let client = CachingApi::for_testing(server.uri());
+
+    let first = client.get_user_by_id(42).await.unwrap();
+    let second = client.get_user_by_id(42).await.unwrap();
+
+    assert_eq!(first.username, "john_doe");
+    assert_eq!(second.username, "john_doe");
+
+    let requests = server.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 2);
+    assert!(requests[0].headers.get("if-none-match").is_none());
+    assert_eq!(requests[1].headers.get("if-none-match").unwrap(), "\"v1\"");
+}
+
+#[tokio::test]
+async fn test_cache_is_shared_between_clones_and_can_be_cleared() {
+    let server = etag_server().await;
+    let client = CachingApi::for_testing(server.uri());
+
+    client.get_user_by_id(42).await.unwrap();
+    client.clone().get_user_by_id(42).await.unwrap();
+    client.clear_etag_cache();
+    client.get_user_by_id(42).await.unwrap();
+
+    let requests = server.received_requests().await.unwrap();
+    assert!(requests[1].headers.get("if-none-match").is_some());
+    assert!(requests[2].headers.get("if-none-match").is_none());
+}
+
+#[tokio::test]
+async fn test_responses_without_etag_are_not_cached() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/42"))
+        .and(header_exists("if-none-match"))
+        .respond_with(ResponseTemplate::new(304))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/users/42"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(user_json()))
+        .mount(&server)
+        .await;
+
+    let client = CachingApi::for_testing(server.uri());
+    client.get_user_by_id(42).await.unwrap();
+    let user = client.get_user_by_id(42).await.unwrap();
+
+    assert_eq!(user.id, 42);
+}
@@ -0,0 +1,10 @@
+use openapi_gen::openapi_client;
+
+// Request and response examples, including `components.examples` references,
+// are embedded in the method documentation
+openapi_client!("tests/fixtures/examples.json", "ExamplesApi");
+
+#[test]
+fn test_client_with_referenced_examples_compiles() {
+    let _client = ExamplesApi::new("https://api.example.com");
+}
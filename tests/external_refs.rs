@@ -0,0 +1,67 @@
+#![cfg(not(feature = "ureq"))]
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!("tests/fixtures/split/openapi.yaml", "SplitApi");
+
+fn user_json(id: i64) -> serde_json::Value {
+    json!({"id": id, "name": "john", "address": {"city": "Amsterdam"}})
+}
+
+#[test]
+fn test_external_components_are_imported() {
+    // `Address` is only referenced from within the components file
+    let team = Team {
+        members: vec![User {
+            id: 1,
+            name: "john".to_string(),
+            address: Address {
+                city: "Amsterdam".to_string(),
+            },
+        }],
+    };
+
+    assert_eq!(team.members[0].address.city, "Amsterdam");
+}
+
+#[tokio::test]
+async fn test_external_parameters_and_responses() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/7"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(user_json(7)))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/users/8"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({"message": "No user 8"})))
+        .mount(&server)
+        .await;
+    let client = SplitApi::for_testing(server.uri());
+
+    let user = client.get_user(7).await.unwrap();
+    assert_eq!(user.address.city, "Amsterdam");
+
+    let error = client.get_user(8).await.unwrap_err();
+    assert!(error.is_not_found());
+    assert_eq!(error.to_string(), "API error 404: No user 8");
+}
+
+#[tokio::test]
+async fn test_path_item_file_references_resolve_relative_to_it() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([user_json(1), user_json(2)])))
+        .mount(&server)
+        .await;
+    let client = SplitApi::for_testing(server.uri());
+
+    let users: Vec<User> = client.list_users().await.unwrap();
+
+    assert_eq!(users.len(), 2);
+    assert_eq!(users[1].id, 2);
+}
@@ -12,7 +12,7 @@ fn test_all_crate_features() {
     // 1. Multiple HTTP methods (GET, POST, PUT, DELETE)
     // 2. Path parameters (userId, postId)
     // 3. Query parameters (limit, offset, type, self)
-    // 4. Request bodies (JSON)
+    // 4. Request bodies (JSON, typed from referenced schemas)
     // 5. Various response types
     // 6. Schema references ($ref)
     // 7. Enum types (UserStatus)
@@ -47,10 +47,10 @@ fn test_generated_method_signatures() {
     // Test that methods with the expected signatures are generated:
 
     // Methods with various parameter types
-    // client.list_users(limit: Option<i32>, offset: Option<i64>, r#type: Option<String>)
-    // client.create_user(body: serde_json::Value)
+    // client.list_users(limit: Option<i32>, offset: Option<u64>, r#type: Option<String>)
+    // client.create_user(body: CreateUserRequest)
     // client.get_user_by_id(user_id: i64)
-    // client.update_user(user_id: i64, body: serde_json::Value)
+    // client.update_user(user_id: i64, body: UpdateUserRequest)
     // client.delete_user(user_id: i64)
     // client.get_post_comments(post_id: String, r#self: Option<bool>)
     // client.r#const() // Tests keyword handling in operation ID
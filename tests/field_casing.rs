@@ -0,0 +1,41 @@
+use openapi_gen::openapi_client;
+use serde_json::json;
+
+openapi_client!("tests/fixtures/field_casing.yaml", "CasingApi");
+
+#[test]
+fn test_camel_case_fields_round_trip() {
+    let value = json!({ "userId": 1, "displayName": "Jane", "type": "admin" });
+    let camel: CamelCased = serde_json::from_value(value.clone()).unwrap();
+
+    assert_eq!(camel.user_id, 1);
+    assert_eq!(camel.r#type, "admin");
+    assert_eq!(serde_json::to_value(camel).unwrap(), value);
+}
+
+#[test]
+fn test_kebab_case_fields_round_trip() {
+    let value = json!({ "content-type": "text/plain", "max-age": 60, "self": "/kebab" });
+    let kebab: KebabCased = serde_json::from_value(value.clone()).unwrap();
+
+    assert_eq!(kebab.content_type.as_deref(), Some("text/plain"));
+    assert_eq!(kebab.self_.as_deref(), Some("/kebab"));
+    assert_eq!(serde_json::to_value(kebab).unwrap(), value);
+}
+
+#[test]
+fn test_mixed_case_fields_round_trip() {
+    let value = json!({ "userId": 1, "created_at": "2024-01-01", "ETag": "\"v1\"" });
+    let mixed: Mixed = serde_json::from_value(value.clone()).unwrap();
+
+    assert_eq!(mixed.e_tag.as_deref(), Some("\"v1\""));
+    assert_eq!(serde_json::to_value(mixed).unwrap(), value);
+}
+
+#[test]
+fn test_snake_case_fields_round_trip() {
+    let value = json!({ "user_id": 1, "name": "Jane" });
+    let snake: SnakeCased = serde_json::from_value(value.clone()).unwrap();
+
+    assert_eq!(serde_json::to_value(snake).unwrap(), value);
+}
@@ -0,0 +1,20 @@
+//! Client generated from the spec path in `OPENAPI_GEN_TEST_SPEC`
+//!
+//! Built by `tests/env_spec_path.rs` with the variable set, as it's only read
+//! at compile time.
+
+use openapi_gen::openapi_client;
+
+openapi_client!(env = "OPENAPI_GEN_TEST_SPEC", "EnvSpecApi");
+
+#[allow(dead_code)]
+async fn get_pet(client: &EnvSpecApi) -> ApiResult<Pet> {
+    client.get_pet(1).await
+}
+
+#[test]
+fn test_client_generated_from_env_spec_path() {
+    let client = EnvSpecApi::new("https://api.example.com");
+
+    let _pet = get_pet(&client);
+}
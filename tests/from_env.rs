@@ -0,0 +1,50 @@
+mod with_servers {
+    use openapi_gen::openapi_client;
+
+    openapi_client!(
+        "openapi.json",
+        "ServersApi",
+        base_url_env = "FROM_ENV_SERVERS_API_URL"
+    );
+
+    #[test]
+    fn test_falls_back_to_first_server() {
+        let client = ServersApi::from_env().unwrap();
+
+        assert_eq!(client.base_url, "https://api.test.com/v2");
+    }
+}
+
+mod with_env {
+    use openapi_gen::openapi_client;
+
+    openapi_client!("openapi.json", "EnvConfiguredApi");
+
+    #[test]
+    fn test_reads_base_url_from_conventional_variable() {
+        // SAFETY: no other test reads or writes this variable
+        unsafe { std::env::set_var("ENV_CONFIGURED_API_BASE_URL", "http://127.0.0.1:8080") };
+
+        let client = EnvConfiguredApi::from_env().unwrap();
+
+        assert_eq!(client.base_url, "http://127.0.0.1:8080");
+    }
+}
+
+mod without_servers {
+    use openapi_gen::openapi_client;
+
+    openapi_client!("tests/fixtures/retry.json", "ServerlessApi");
+
+    #[test]
+    fn test_missing_variable_without_servers_is_an_error() {
+        let result = ServerlessApi::from_env();
+
+        match result {
+            Err(ApiError::Config(message)) => {
+                assert!(message.contains("SERVERLESS_API_BASE_URL"));
+            }
+            _ => panic!("expected a configuration error"),
+        }
+    }
+}
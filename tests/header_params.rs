@@ -0,0 +1,84 @@
+#![cfg(not(feature = "ureq"))]
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!("tests/fixtures/header_params.yaml", "OrdersApi");
+
+async fn order_server() -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/orders/o-1"))
+        .and(header("X-Request-Id", "req-42"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"id": "o-1"})))
+        .expect(1)
+        .mount(&server)
+        .await;
+    server
+}
+
+#[tokio::test]
+async fn test_header_parameters_are_sent() {
+    let server = order_server().await;
+    let client = OrdersApi::for_testing(server.uri());
+
+    let order = client
+        .get_order(
+            "o-1",
+            "req-42",
+            Some(vec!["a".to_string(), "b".to_string()]),
+            Some(60),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(order.id, "o-1");
+    let requests = server.received_requests().await.unwrap();
+    assert_eq!(requests[0].headers["x-trace-tags"], "a,b");
+    assert_eq!(requests[0].headers["x-max-age"], "60");
+}
+
+#[tokio::test]
+async fn test_unset_optional_header_parameters_are_not_sent() {
+    let server = order_server().await;
+    let client = OrdersApi::for_testing(server.uri());
+
+    client.get_order("o-1", "req-42", None, None).await.unwrap();
+
+    let requests = server.received_requests().await.unwrap();
+    assert!(!requests[0].headers.contains_key("x-trace-tags"));
+    assert!(!requests[0].headers.contains_key("x-max-age"));
+}
+
+mod param_structs {
+    use super::order_server;
+    use openapi_gen::openapi_client;
+
+    openapi_client!(
+        "tests/fixtures/header_params.yaml",
+        "OrdersApi",
+        use_param_structs = true
+    );
+
+    #[tokio::test]
+    async fn test_header_parameters_are_sent_from_param_structs() {
+        let server = order_server().await;
+        let client = OrdersApi::for_testing(server.uri());
+
+        let order = client
+            .get_order(GetOrderParams {
+                order_id: "o-1".to_string(),
+                x_request_id: "req-42".to_string(),
+                x_trace_tags: None,
+                x_max_age: Some(60),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(order.id, "o-1");
+        let requests = server.received_requests().await.unwrap();
+        assert_eq!(requests[0].headers["x-max-age"], "60");
+    }
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "http")]
+
+use openapi_gen::openapi_client;
+
+openapi_client!("openapi.json", "HttpApi");
+
+#[test]
+fn test_path_parameters_are_substituted() {
+    let client = HttpApi::new("https://api.example.com/v2/");
+
+    let request = client.get_user_by_id_http_request(42).unwrap();
+
+    assert_eq!(request.method(), http::Method::GET);
+    assert_eq!(request.uri(), "https://api.example.com/v2/users/42");
+    assert!(request.body().is_empty());
+}
+
+#[test]
+fn test_query_parameters_are_appended() {
+    let client = HttpApi::new("https://api.example.com");
+
+    let request = client
+        .list_users_http_request(Some(10), None, Some("admin"))
+        .unwrap();
+
+    assert_eq!(
+        request.uri(),
+        "https://api.example.com/users?limit=10&type=admin"
+    );
+}
+
+#[test]
+fn test_json_body_is_serialized() {
+    let client = HttpApi::new("https://api.example.com");
+    let body = CreateUserRequest {
+        username: "john_doe".to_string(),
+        email: "john@example.com".to_string(),
+        first_name: None,
+        last_name: None,
+        r#type: None,
+    };
+
+    let request = client.create_user_http_request(body).unwrap();
+
+    assert_eq!(request.method(), http::Method::POST);
+    assert_eq!(
+        request.headers()[http::header::CONTENT_TYPE],
+        "application/json"
+    );
+    let body: serde_json::Value = serde_json::from_slice(request.body()).unwrap();
+    assert_eq!(body["username"], "john_doe");
+}
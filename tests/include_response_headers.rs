@@ -0,0 +1,116 @@
+#![cfg(not(feature = "ureq"))]
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!(
+    "openapi.json",
+    "HeadersApi",
+    include_response_headers = true
+);
+
+fn user_json() -> serde_json::Value {
+    json!({
+        "id": 42,
+        "username": "john_doe",
+        "email": "john@example.com",
+        "status": "active"
+    })
+}
+
+#[tokio::test]
+async fn test_response_carries_status_and_headers() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/42"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(user_json())
+                .insert_header("X-Request-Id", "abc123")
+                .insert_header("X-Rate-Limit-Remaining", "7"),
+        )
+        .mount(&server)
+        .await;
+
+    let client = HeadersApi::for_testing(server.uri());
+    let response: ApiResponse<User> = client.get_user_by_id(42).await.unwrap();
+
+    assert_eq!(response.status, 200);
+    assert_eq!(response.headers["x-request-id"], "abc123");
+    assert_eq!(response.headers["x-rate-limit-remaining"], "7");
+    assert_eq!(response.body.id, 42);
+    assert_eq!(response.into_body().username, "john_doe");
+}
+
+#[tokio::test]
+async fn test_responses_without_content_are_wrapped() {
+    let server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path("/users/42"))
+        .respond_with(ResponseTemplate::new(204).insert_header("X-Request-Id", "abc123"))
+        .mount(&server)
+        .await;
+
+    let client = HeadersApi::for_testing(server.uri());
+    let response = client.delete_user(42).await.unwrap();
+
+    assert_eq!(response.status, 204);
+    assert_eq!(response.headers["x-request-id"], "abc123");
+}
+
+#[tokio::test]
+async fn test_error_status_is_returned_as_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/42"))
+        .respond_with(ResponseTemplate::new(404).insert_header("X-Request-Id", "abc123"))
+        .mount(&server)
+        .await;
+
+    let client = HeadersApi::for_testing(server.uri());
+    let result = client.get_user_by_id(42).await;
+
+    assert!(matches!(result, Err(ApiError::Api { status: 404, .. })));
+}
+
+mod cached {
+    use super::*;
+
+    openapi_client!(
+        "openapi.json",
+        "CachingApi",
+        etag_cache = true,
+        include_response_headers = true
+    );
+
+    #[tokio::test]
+    async fn test_not_modified_response_carries_its_status() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/users/42"))
+            .and(header("if-none-match", "\"v1\""))
+            .respond_with(ResponseTemplate::new(304))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/users/42"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("etag", "\"v1\"")
+                    .set_body_json(user_json()),
+            )
+            .mount(&server)
+            .await;
+        let client = CachingApi::for_testing(server.uri());
+
+        let first = client.get_user_by_id(42).await.unwrap();
+        let second = client.get_user_by_id(42).await.unwrap();
+
+        assert_eq!(first.status, 200);
+        assert_eq!(first.headers["etag"], "\"v1\"");
+        assert_eq!(second.status, 304);
+        assert_eq!(second.body.id, 42);
+    }
+}
@@ -0,0 +1,29 @@
+#![cfg(not(feature = "ureq"))]
+
+use openapi_gen::openapi_client;
+
+openapi_client!("tests/fixtures/inline_arrays.json", "PetStoreApi");
+
+#[allow(dead_code)]
+async fn list_pets_returns_typed_items(client: &PetStoreApi) -> ApiResult<Vec<Pet>> {
+    // The response schema is an inline array with `$ref` items
+    client.list_pets().await
+}
+
+#[allow(dead_code)]
+async fn list_pet_groups_returns_nested_typed_items(
+    client: &PetStoreApi,
+) -> ApiResult<Vec<Vec<Pet>>> {
+    client.list_pet_groups().await
+}
+
+#[test]
+fn test_inline_array_of_references_is_typed() {
+    let client = PetStoreApi::new("https://api.example.com");
+
+    let _pets = list_pets_returns_typed_items(&client);
+    let _groups = list_pet_groups_returns_nested_typed_items(&client);
+
+    let pets: Vec<Pet> = serde_json::from_str(r#"[{"id": 1, "name": "Rex"}]"#).unwrap();
+    assert_eq!(pets[0].name, "Rex");
+}
@@ -0,0 +1,59 @@
+#![cfg(not(feature = "ureq"))]
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!("tests/fixtures/integer_enums.yaml", "TasksApi");
+
+#[test]
+fn test_integer_enum_serializes_as_number() {
+    let task = Task {
+        priority: Priority::Value2,
+        level: Some(Level::Low),
+    };
+
+    assert_eq!(
+        serde_json::to_value(&task).unwrap(),
+        json!({"priority": 2, "level": -1})
+    );
+}
+
+#[test]
+fn test_integer_enum_deserializes_from_number() {
+    let task: Task = serde_json::from_value(json!({"priority": 3, "level": 0})).unwrap();
+
+    assert!(matches!(task.priority, Priority::Value3));
+    assert!(matches!(task.level, Some(Level::Normal)));
+}
+
+#[test]
+fn test_unknown_integer_value_is_rejected() {
+    let error = serde_json::from_value::<Task>(json!({"priority": 4})).unwrap_err();
+
+    assert!(error.to_string().contains("invalid Priority value: 4"));
+}
+
+#[test]
+fn test_integer_enum_conversions() {
+    assert_eq!(i64::from(Level::High), 1);
+    assert!(matches!(Priority::try_from(1), Ok(Priority::Value1)));
+    assert!(Priority::try_from(0).is_err());
+}
+
+#[tokio::test]
+async fn test_integer_enum_query_parameter() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/tasks"))
+        .and(query_param("priority", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([{"priority": 1}])))
+        .mount(&server)
+        .await;
+    let client = TasksApi::for_testing(server.uri());
+
+    let tasks = client.list_tasks(Some(Priority::Value1)).await.unwrap();
+
+    assert_eq!(tasks.len(), 1);
+}
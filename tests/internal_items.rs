@@ -0,0 +1,54 @@
+use openapi_gen::openapi_client;
+
+openapi_client!("tests/fixtures/internal.yaml", "PublicApi");
+
+/// Would conflict with a generated `UserImport` struct
+#[allow(dead_code)]
+struct UserImport;
+
+#[test]
+fn test_internal_operations_are_skipped() {
+    let operations: Vec<_> = PublicApi::OPERATIONS
+        .iter()
+        .map(|operation| operation.operation_id)
+        .collect();
+
+    assert_eq!(operations, ["reindex", "listUsers"]);
+}
+
+#[test]
+fn test_public_schemas_are_generated() {
+    let user = User {
+        id: 1,
+        name: "Ada".to_string(),
+    };
+
+    assert_eq!(user.id, 1);
+}
+
+mod custom_extension {
+    use openapi_gen::openapi_client;
+
+    openapi_client!(
+        "tests/fixtures/internal.yaml",
+        "PrivateApi",
+        internal_extension = "x-private"
+    );
+
+    /// Would conflict with a generated `AuditEntry` struct
+    #[allow(dead_code)]
+    struct AuditEntry;
+
+    #[test]
+    fn test_items_with_the_extension_are_skipped() {
+        let operations: Vec<_> = PrivateApi::OPERATIONS
+            .iter()
+            .map(|operation| operation.operation_id)
+            .collect();
+
+        assert_eq!(operations, ["listUsers", "importUsers"]);
+        let _ = UserImport {
+            source: Some("backup.csv".to_string()),
+        };
+    }
+}
@@ -0,0 +1,37 @@
+#![cfg(not(feature = "ureq"))]
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!("openapi.json", "ReusableApi");
+
+#[tokio::test]
+async fn test_into_inner_returns_http_client_for_reuse() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/42"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 42,
+            "username": "john_doe",
+            "email": "john@example.com",
+            "status": "active"
+        })))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let http_client = reqwest::Client::builder().no_proxy().build().unwrap();
+    let client = ReusableApi::with_client(server.uri(), http_client);
+    client.get_user_by_id(42).await.unwrap();
+
+    let http_client: reqwest::Client = client.into_inner();
+    let response = http_client
+        .get(format!("{}/users/42", server.uri()))
+        .send()
+        .await
+        .unwrap();
+
+    assert!(response.status().is_success());
+}
@@ -0,0 +1,108 @@
+#![cfg(not(feature = "ureq"))]
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!("tests/fixtures/json_rpc.yaml", "LedgerApi");
+
+#[tokio::test]
+async fn test_method_result_is_unwrapped() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/rpc"))
+        .and(body_partial_json(json!({
+            "jsonrpc": "2.0",
+            "method": "ledger.getBalance",
+            "params": {"account": "acme"}
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": {"amount": 1200},
+            "id": 1
+        })))
+        .mount(&server)
+        .await;
+    let client = LedgerApi::for_testing(server.uri());
+
+    let balance = client
+        .get_balance(BalanceParams {
+            account: "acme".to_string(),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(balance.amount, 1200);
+}
+
+#[tokio::test]
+async fn test_method_error_is_returned() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/rpc"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "error": {"code": -32602, "message": "Unknown account", "data": "acme"},
+            "id": 1
+        })))
+        .mount(&server)
+        .await;
+    let client = LedgerApi::for_testing(server.uri());
+
+    let error = client
+        .get_balance(BalanceParams {
+            account: "acme".to_string(),
+        })
+        .await
+        .unwrap_err();
+
+    match error {
+        ApiError::JsonRpc(error) => {
+            assert_eq!(error.code, -32602);
+            assert_eq!(error.message, "Unknown account");
+            assert_eq!(error.data, Some(json!("acme")));
+        }
+        other => panic!("Unexpected error: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_method_without_params_or_result() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/rpc"))
+        .and(body_partial_json(
+            json!({"jsonrpc": "2.0", "method": "ledger.ping"}),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": null,
+            "id": 2
+        })))
+        .mount(&server)
+        .await;
+    let client = LedgerApi::for_testing(server.uri());
+
+    client.ping().await.unwrap();
+
+    let requests = server.received_requests().await.unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert!(body.get("params").is_none());
+    assert!(body["id"].is_u64());
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn test_http_request_wraps_params() {
+    let request = LedgerApi::new("https://rpc.example.com")
+        .get_balance_http_request(BalanceParams {
+            account: "acme".to_string(),
+        })
+        .unwrap();
+
+    assert_eq!(request.uri().to_string(), "https://rpc.example.com/rpc");
+    let body: serde_json::Value = serde_json::from_slice(request.body()).unwrap();
+    assert_eq!(body["method"], "ledger.getBalance");
+    assert_eq!(body["params"], json!({"account": "acme"}));
+}
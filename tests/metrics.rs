@@ -0,0 +1,117 @@
+#![cfg(feature = "metrics")]
+
+use metrics_util::CompositeKey;
+use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+use openapi_gen::openapi_client;
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!("openapi.json", "MeteredApi");
+
+/// Run a future on the current thread, recording its metrics
+fn record<F: std::future::Future>(future: F) -> Vec<(CompositeKey, DebugValue)> {
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+
+    metrics::with_local_recorder(&recorder, || runtime.block_on(future));
+
+    snapshotter
+        .snapshot()
+        .into_vec()
+        .into_iter()
+        .map(|(key, _, _, value)| (key, value))
+        .collect()
+}
+
+fn labels(key: &CompositeKey) -> Vec<(String, String)> {
+    key.key()
+        .labels()
+        .map(|label| (label.key().to_string(), label.value().to_string()))
+        .collect()
+}
+
+fn counter(metrics: &[(CompositeKey, DebugValue)]) -> (Vec<(String, String)>, u64) {
+    metrics
+        .iter()
+        .find_map(|(key, value)| match value {
+            DebugValue::Counter(count) if key.key().name() == "api_requests_total" => {
+                Some((labels(key), *count))
+            }
+            _ => None,
+        })
+        .unwrap()
+}
+
+#[test]
+fn test_successful_request_is_recorded() {
+    let metrics = record(async {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/users/42"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": 42,
+                "username": "john_doe",
+                "email": "john@example.com",
+                "status": "active"
+            })))
+            .mount(&server)
+            .await;
+
+        let client = MeteredApi::for_testing(server.uri());
+        client.get_user_by_id(42).await.unwrap();
+    });
+
+    let (labels, count) = counter(&metrics);
+    assert_eq!(count, 1);
+    assert!(labels.contains(&("operation".to_string(), "getUserById".to_string())));
+    assert!(labels.contains(&("status".to_string(), "200".to_string())));
+
+    let durations = metrics
+        .iter()
+        .find_map(|(key, value)| match value {
+            DebugValue::Histogram(values) if key.key().name() == "api_request_duration" => {
+                Some(values.len())
+            }
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(durations, 1);
+}
+
+#[test]
+fn test_error_status_is_recorded() {
+    let metrics = record(async {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/users/42"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+
+        let client = MeteredApi::for_testing(server.uri());
+        assert!(client.get_user_by_id(42).await.is_err());
+    });
+
+    let (labels, _) = counter(&metrics);
+    assert!(labels.contains(&("status".to_string(), "404".to_string())));
+}
+
+#[test]
+fn test_connection_failure_is_recorded_by_kind() {
+    let metrics = record(async {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+
+        let client = MeteredApi::for_testing(url);
+        assert!(client.get_user_by_id(42).await.is_err());
+    });
+
+    let (labels, _) = counter(&metrics);
+    assert!(labels.contains(&("status".to_string(), "connect".to_string())));
+}
@@ -55,14 +55,14 @@ fn test_middleware_request_body_compilation() {
     let _client_ref = &client;
 
     // Test compilation of create_user method (has request body)
-    let _create_user_method = |_body: serde_json::Value| async move {
+    let _create_user_method = |_body: CreateUserRequest| async move {
         // This lambda ensures the method signature compiles correctly
         // The actual method would be: client.create_user(body).await
         let _: Result<(), &str> = Err("Compilation test only");
     };
 
     // Test compilation of update_user method (has request body)
-    let _update_user_method = |_user_id: i64, _body: serde_json::Value| async move {
+    let _update_user_method = |_user_id: i64, _body: UpdateUserRequest| async move {
         // This lambda ensures the method signature compiles correctly
         // The actual method would be: client.update_user(user_id, body).await
         let _: Result<(), &str> = Err("Compilation test only");
@@ -0,0 +1,107 @@
+#![cfg(not(feature = "ureq"))]
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!("openapi.json", "MockedApi");
+
+fn user_json(id: i64) -> serde_json::Value {
+    json!({
+        "id": id,
+        "username": "john_doe",
+        "email": "john@example.com",
+        "status": "active"
+    })
+}
+
+#[tokio::test]
+async fn test_path_parameters_against_mock_server() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/42"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(user_json(42)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = MockedApi::for_testing(server.uri());
+    let user = client.get_user_by_id(42).await.unwrap();
+
+    assert_eq!(user.id, 42);
+    assert_eq!(user.username, "john_doe");
+}
+
+#[tokio::test]
+async fn test_query_parameters_against_mock_server() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users"))
+        .and(query_param("limit", "10"))
+        .and(query_param("type", "admin"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "users": [user_json(1), user_json(2)],
+            "total": 2,
+            "page": 1
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // A trailing slash on the base URL doesn't end up in the request path
+    let client = MockedApi::for_testing(format!("{}/", server.uri()));
+    let users = client
+        .list_users(Some(10), None, Some("admin"))
+        .await
+        .unwrap();
+
+    assert_eq!(users.users.len(), 2);
+    assert_eq!(users.total, 2);
+}
+
+#[tokio::test]
+async fn test_error_status_against_mock_server() {
+    let server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path("/users/7"))
+        .respond_with(ResponseTemplate::new(404).set_body_string("User not found"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = MockedApi::for_testing(server.uri());
+    let result = client.delete_user(7).await;
+
+    match result {
+        Err(ApiError::Api { status, message }) => {
+            assert_eq!(status, 404);
+            assert_eq!(message, "User not found");
+        }
+        other => panic!("expected an API error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_json_error_body_against_mock_server() {
+    let server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path("/users/7"))
+        .respond_with(ResponseTemplate::new(422).set_body_json(json!({
+            "message": "User has open orders",
+            "orders": [3]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = MockedApi::for_testing(server.uri());
+    let error = client.delete_user(7).await.unwrap_err();
+
+    assert_eq!(error.status(), Some(422));
+    assert_eq!(error.to_string(), "API error 422: User has open orders");
+    match error {
+        ApiError::ApiTyped { body, .. } => assert_eq!(body["orders"], json!([3])),
+        other => panic!("expected a typed API error, got {:?}", other),
+    }
+}
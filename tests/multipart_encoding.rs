@@ -0,0 +1,85 @@
+#![cfg(feature = "multipart")]
+
+use openapi_gen::openapi_client;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+
+openapi_client!("tests/fixtures/multipart.json", "DocumentsApi");
+
+/// Serve a single request, echoing its body
+fn spawn_echo_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let mut content_length = 0;
+        let mut line = String::new();
+        while reader.read_line(&mut line).unwrap() > 2 {
+            if let Some((name, value)) = line.split_once(':')
+                && name.eq_ignore_ascii_case("content-length")
+            {
+                content_length = value.trim().parse().unwrap();
+            }
+            line.clear();
+        }
+
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).unwrap();
+
+        let header = format!(
+            "HTTP/1.1 200 OK\r\ncontent-length: {}\r\nconnection: close\r\n\r\n",
+            body.len()
+        );
+        stream.write_all(header.as_bytes()).unwrap();
+        stream.write_all(&body).unwrap();
+    });
+
+    url
+}
+
+#[tokio::test]
+async fn test_parts_use_encoding_content_types() {
+    let client = DocumentsApi::new(spawn_echo_server());
+
+    let body = client
+        .upload_document(UploadDocumentMultipartBody {
+            metadata: DocumentMetadata {
+                title: "Report".to_string(),
+            },
+            file: b"PNG".to_vec(),
+            comment: None,
+        })
+        .await
+        .unwrap();
+
+    // The referenced schema defaults to JSON
+    assert!(body.contains(
+        "name=\"metadata\"\r\nContent-Type: application/json\r\n\r\n{\"title\":\"Report\"}"
+    ));
+    // The binary part uses the content type from the encoding object
+    assert!(
+        body.contains("name=\"file\"; filename=\"file\"\r\nContent-Type: image/png\r\n\r\nPNG")
+    );
+    // Optional fields that aren't set are left out
+    assert!(!body.contains("name=\"comment\""));
+}
+
+#[tokio::test]
+async fn test_text_parts_have_no_content_type() {
+    let client = DocumentsApi::new(spawn_echo_server());
+
+    let body = client
+        .upload_document(UploadDocumentMultipartBody {
+            metadata: DocumentMetadata {
+                title: "Report".to_string(),
+            },
+            file: Vec::new(),
+            comment: Some("First draft".to_string()),
+        })
+        .await
+        .unwrap();
+
+    assert!(body.contains("name=\"comment\"\r\n\r\nFirst draft"));
+}
@@ -0,0 +1,57 @@
+use openapi_gen::openapi_client;
+
+openapi_client!("openapi.json", "NestedApi", nested_getters = true);
+
+fn user(preferences: Option<UserPreferences>) -> User {
+    serde_json::from_value(serde_json::json!({
+        "id": 1,
+        "username": "john_doe",
+        "email": "john@example.com",
+        "status": "active",
+        "preferences": preferences,
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_getters_follow_optional_structs() {
+    let user = user(Some(UserPreferences {
+        theme: Some("dark".to_string()),
+        notifications: Some(NotificationSettings {
+            email: Some(true),
+            push: Some(false),
+            frequency: Some("daily".to_string()),
+        }),
+        r#const: None,
+    }));
+
+    assert_eq!(user.theme().map(String::as_str), Some("dark"));
+    assert_eq!(user.frequency().map(String::as_str), Some("daily"));
+    assert_eq!(user.push(), Some(&false));
+}
+
+#[test]
+fn test_getters_return_none_for_missing_structs() {
+    let user = user(None);
+
+    assert_eq!(user.theme(), None);
+    assert_eq!(user.push(), None);
+    assert_eq!(user.bio(), None);
+}
+
+#[test]
+fn test_getters_of_intermediate_structs() {
+    let preferences = UserPreferences {
+        theme: None,
+        notifications: Some(NotificationSettings {
+            email: Some(true),
+            push: None,
+            frequency: None,
+        }),
+        r#const: None,
+    };
+
+    // Only fields behind an optional struct get a getter
+    assert_eq!(preferences.email(), Some(&true));
+    assert_eq!(preferences.push(), None);
+}
@@ -0,0 +1,81 @@
+use openapi_gen::openapi_client;
+use serde_json::json;
+
+openapi_client!("tests/fixtures/nullable_enums.json", "OrdersApi");
+
+#[test]
+fn test_required_nullable_enum_accepts_null() {
+    let order: Order = serde_json::from_value(json!({
+        "id": 1,
+        "status": null,
+        "priority": "high"
+    }))
+    .unwrap();
+
+    assert!(order.status.is_none());
+    assert!(order.previous_status.is_none());
+    assert!(matches!(order.priority, Priority::High));
+}
+
+#[test]
+fn test_required_nullable_enum_round_trips() {
+    let order: Order = serde_json::from_value(json!({
+        "id": 1,
+        "status": "shipped",
+        "priority": "low",
+        "previousStatus": "pending"
+    }))
+    .unwrap();
+
+    assert!(matches!(order.status, Some(OrderStatus::Shipped)));
+    assert!(matches!(order.previous_status, Some(OrderStatus::Pending)));
+
+    let value = serde_json::to_value(&order).unwrap();
+    assert_eq!(value["status"], "shipped");
+
+    let order = Order {
+        status: None,
+        ..order
+    };
+    let value = serde_json::to_value(&order).unwrap();
+    assert_eq!(value["status"], serde_json::Value::Null);
+}
+
+#[test]
+fn test_required_nullable_field_accepts_null() {
+    let order: Order = serde_json::from_value(json!({
+        "id": 1,
+        "status": "pending",
+        "priority": "low",
+        "note": null
+    }))
+    .unwrap();
+
+    let _: Option<String> = order.note;
+}
+
+#[test]
+fn test_enum_with_null_value_accepts_null() {
+    let order: Order = serde_json::from_value(json!({
+        "id": 1,
+        "status": "pending",
+        "priority": "low",
+        "note": null,
+        "shippingMethod": "air",
+        "giftWrap": "box"
+    }))
+    .unwrap();
+
+    assert!(matches!(order.shipping_method, Some(ShippingMethod::Air)));
+    assert_eq!(order.gift_wrap.as_deref(), Some("box"));
+
+    let order = Order {
+        shipping_method: None,
+        ..order
+    };
+    let value = serde_json::to_value(&order).unwrap();
+    assert_eq!(value["shippingMethod"], serde_json::Value::Null);
+
+    let order: Order = serde_json::from_value(value).unwrap();
+    assert!(order.shipping_method.is_none());
+}
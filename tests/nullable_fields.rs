@@ -0,0 +1,35 @@
+use openapi_gen::openapi_client;
+use serde_json::json;
+
+openapi_client!("tests/fixtures/nullable_fields.yaml", "InventoryApi");
+
+#[test]
+fn test_required_nullable_fields_accept_null() {
+    let item: Item = serde_json::from_value(json!({
+        "id": 1,
+        "count": null,
+        "tags": null,
+        "dimensions": null
+    }))
+    .unwrap();
+
+    let _: Option<i32> = item.count;
+    let _: Option<Vec<String>> = item.tags;
+    assert!(item.dimensions.is_none());
+}
+
+#[test]
+fn test_required_nullable_fields_serialize_null() {
+    let item: Item = serde_json::from_value(json!({
+        "id": 1,
+        "count": 3,
+        "tags": null,
+        "dimensions": { "width": 2.5 }
+    }))
+    .unwrap();
+
+    let value = serde_json::to_value(&item).unwrap();
+    assert_eq!(value["count"], 3);
+    assert_eq!(value["tags"], serde_json::Value::Null);
+    assert_eq!(value["dimensions"]["width"], 2.5);
+}
@@ -0,0 +1,48 @@
+use openapi_gen::openapi_client;
+use serde_json::json;
+
+openapi_client!("tests/fixtures/nullable_refs.yaml", "OrdersApi");
+
+#[test]
+fn test_nullable_single_ref_all_of_fields_are_options() {
+    let order: Order = serde_json::from_value(json!({
+        "id": "o-1",
+        "customer": null,
+        "billing": { "name": "Acme" }
+    }))
+    .unwrap();
+
+    let customer: Option<Customer> = order.customer;
+    let billing: Customer = order.billing;
+    assert!(customer.is_none());
+    assert_eq!(billing.name, "Acme");
+}
+
+#[test]
+fn test_single_ref_all_of_resolves_to_referenced_type() {
+    let order: Order = serde_json::from_value(json!({
+        "id": "o-2",
+        "customer": { "name": "Jane" },
+        "billing": { "name": "Jane" },
+        "parent": {
+            "id": "o-1",
+            "customer": null,
+            "billing": { "name": "Acme" }
+        },
+        "previousCustomers": [{ "name": "Acme" }, null]
+    }))
+    .unwrap();
+
+    let parent: Option<Box<Order>> = order.parent;
+    let previous: Option<Vec<Option<Customer>>> = order.previous_customers;
+    assert_eq!(order.customer.unwrap().name, "Jane");
+    assert_eq!(parent.unwrap().id, "o-1");
+    assert!(previous.unwrap()[1].is_none());
+}
+
+#[test]
+fn test_nullable_single_ref_all_of_alias_is_option() {
+    let customer: OptionalCustomer = serde_json::from_value(json!(null)).unwrap();
+
+    assert!(customer.is_none());
+}
@@ -0,0 +1,104 @@
+#![cfg(not(feature = "ureq"))]
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!("tests/fixtures/one_of.yaml", "PetsApi");
+
+#[test]
+fn test_untagged_one_of_picks_matching_variant() {
+    let pet: Pet = serde_json::from_value(json!({"name": "Tom", "lives": 9})).unwrap();
+    assert!(matches!(&pet, Pet::Cat(cat) if cat.lives == 9));
+
+    let pet: Pet = serde_json::from_value(json!({"name": "Rex", "breed": "Boxer"})).unwrap();
+    assert!(matches!(&pet, Pet::Dog(dog) if dog.breed == "Boxer"));
+
+    assert_eq!(
+        serde_json::to_value(&pet).unwrap(),
+        json!({"name": "Rex", "breed": "Boxer"})
+    );
+    assert!(serde_json::from_value::<Pet>(json!({"name": "Nemo"})).is_err());
+}
+
+#[test]
+fn test_discriminator_picks_variant_by_tag() {
+    // The tag is taken from the mapping, or defaults to the schema name
+    let shape: Shape = serde_json::from_value(json!({"kind": "circle", "radius": 2.0})).unwrap();
+    assert!(matches!(&shape, Shape::Circle(circle) if circle.kind == "circle"));
+
+    let shape: Shape = serde_json::from_value(json!({"kind": "Square", "side": 3.0})).unwrap();
+    assert!(matches!(&shape, Shape::Square(square) if square.side == 3.0));
+
+    let error = serde_json::from_value::<Shape>(json!({"kind": "triangle"})).unwrap_err();
+    assert!(error.to_string().contains("unknown variant `triangle`"));
+    let error = serde_json::from_value::<Shape>(json!({"side": 3.0})).unwrap_err();
+    assert!(error.to_string().contains("missing field `kind`"));
+}
+
+#[test]
+fn test_discriminator_is_serialized() {
+    let shape = Shape::Square(Square { side: 3.0 });
+
+    assert_eq!(
+        serde_json::to_value(&shape).unwrap(),
+        json!({"kind": "Square", "side": 3.0})
+    );
+}
+
+#[test]
+fn test_inline_variants_are_named_by_title() {
+    let id: Identifier = serde_json::from_value(json!(42)).unwrap();
+    assert!(matches!(id, Identifier::Variant1(42)));
+
+    let id: Identifier = serde_json::from_value(json!("rex")).unwrap();
+    assert!(matches!(id, Identifier::Name(name) if name == "rex"));
+}
+
+#[tokio::test]
+async fn test_one_of_response() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/pets/1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"name": "Tom", "lives": 7})))
+        .mount(&server)
+        .await;
+    let client = PetsApi::for_testing(server.uri());
+
+    let pet = client.get_pet(1).await.unwrap();
+
+    assert!(matches!(pet, Pet::Cat(cat) if cat.name == "Tom"));
+}
+
+mod accessors {
+    use super::*;
+
+    openapi_client!(
+        "tests/fixtures/one_of.yaml",
+        "AccessorsApi",
+        variant_accessors = true
+    );
+
+    #[test]
+    fn test_variant_accessors() {
+        let pet: Pet = serde_json::from_value(json!({"name": "Tom", "lives": 9})).unwrap();
+
+        assert_eq!(pet.as_cat().map(|cat| cat.lives), Some(9));
+        assert!(pet.as_dog().is_none());
+        assert!(pet.is_cat());
+        assert!(!pet.is_dog());
+    }
+
+    #[test]
+    fn test_discriminated_and_inline_variant_accessors() {
+        let shape = Shape::Square(Square { side: 3.0 });
+        assert_eq!(shape.as_square().map(|square| square.side), Some(3.0));
+        assert!(!shape.is_circle());
+
+        let id = Identifier::Name("rex".to_string());
+        assert_eq!(id.as_name().map(String::as_str), Some("rex"));
+        assert_eq!(id.as_variant1(), None);
+        assert!(Identifier::Variant1(42).is_variant1());
+    }
+}
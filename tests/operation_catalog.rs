@@ -0,0 +1,63 @@
+use openapi_gen::openapi_client;
+
+openapi_client!("tests/fixtures/tagged_operations.yaml", "StoreApi");
+
+#[test]
+fn test_operations_list_spec_metadata() {
+    let operations = StoreApi::OPERATIONS;
+
+    assert_eq!(operations.len(), 4);
+    assert_eq!(
+        operations[2],
+        OperationInfo {
+            operation_id: "createPet",
+            method_name: "create_pet",
+            http_method: "POST",
+            path: "/pets",
+            tags: &["pets", "admin"],
+        }
+    );
+    assert_eq!(operations[0].operation_id, "healthCheck");
+    assert!(operations[0].tags.is_empty());
+}
+
+#[test]
+fn test_operations_without_id_are_listed() {
+    let order = StoreApi::OPERATIONS
+        .iter()
+        .find(|operation| operation.path == "/store/orders/{orderId}")
+        .unwrap();
+
+    assert_eq!(order.tags, ["store"]);
+    assert_eq!(order.http_method, "GET");
+}
+
+#[test]
+fn test_operations_tagged_filters_by_tag() {
+    let pets: Vec<_> = StoreApi::operations_tagged("pets")
+        .map(|operation| operation.operation_id)
+        .collect();
+
+    assert_eq!(pets, ["listPets", "createPet"]);
+    assert_eq!(StoreApi::operations_tagged("admin").count(), 1);
+    assert_eq!(StoreApi::operations_tagged("unknown").count(), 0);
+}
+
+mod operation_info_schema {
+    use openapi_gen::openapi_client;
+
+    openapi_client!("tests/fixtures/metadata_schemas.yaml", "StatusApi");
+
+    #[test]
+    fn test_operation_info_schema_keeps_its_name() {
+        let running = OperationInfo {
+            id: "op-1".to_string(),
+            done: false,
+        };
+        let operations: &[SchemaOperationInfo] = StatusApi::OPERATIONS;
+
+        assert_eq!(running.id, "op-1");
+        assert_eq!(operations[1].operation_id, "listOperations");
+        assert_eq!(operations[1].path, "/operations");
+    }
+}
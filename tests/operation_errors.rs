@@ -0,0 +1,104 @@
+#![cfg(not(feature = "ureq"))]
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!(
+    "tests/fixtures/operation_errors.json",
+    "AccountsApi",
+    operation_errors = true
+);
+
+async fn account_server(response: ResponseTemplate) -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/accounts/a-1"))
+        .respond_with(response)
+        .mount(&server)
+        .await;
+    server
+}
+
+#[tokio::test]
+async fn test_success_returns_typed_body() {
+    let server =
+        account_server(ResponseTemplate::new(200).set_body_json(json!({"id": "a-1"}))).await;
+    let client = AccountsApi::for_testing(server.uri());
+
+    let account: Result<Account, GetAccountError> = client.get_account("a-1").await;
+
+    assert_eq!(account.unwrap().id, "a-1");
+}
+
+#[tokio::test]
+async fn test_documented_error_with_body_is_typed() {
+    let server = account_server(ResponseTemplate::new(404).set_body_json(json!({
+        "title": "Not Found",
+        "status": 404,
+        "detail": "No such account"
+    })))
+    .await;
+    let client = AccountsApi::for_testing(server.uri());
+
+    match client.get_account("a-1").await {
+        Err(GetAccountError::NotFound(problem)) => {
+            assert_eq!(problem.title, "Not Found");
+            assert_eq!(problem.detail.as_deref(), Some("No such account"));
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_documented_error_without_body_is_unit_variant() {
+    let server = account_server(ResponseTemplate::new(401)).await;
+    let client = AccountsApi::for_testing(server.uri());
+
+    let error = client.get_account("a-1").await.unwrap_err();
+
+    assert!(matches!(error, GetAccountError::Unauthorized));
+    assert_eq!(error.to_string(), "API error 401: Unauthorized");
+}
+
+#[tokio::test]
+async fn test_undocumented_error_is_other() {
+    let server =
+        account_server(ResponseTemplate::new(503).set_body_json(json!({"retry": true}))).await;
+    let client = AccountsApi::for_testing(server.uri());
+
+    match client.get_account("a-1").await {
+        Err(GetAccountError::Other(status, body)) => {
+            assert_eq!(status, 503);
+            assert_eq!(body, json!({"retry": true}));
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_mismatching_error_body_is_other() {
+    let server = account_server(ResponseTemplate::new(404).set_body_string("gone")).await;
+    let client = AccountsApi::for_testing(server.uri());
+
+    match client.get_account("a-1").await {
+        Err(GetAccountError::Other(404, body)) => assert_eq!(body, json!("gone")),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_connection_failure_is_client_error() {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    drop(listener);
+    let client = AccountsApi::for_testing(url);
+
+    let error = client.delete_account("a-1").await.unwrap_err();
+
+    assert!(matches!(
+        error,
+        DeleteAccountError::Client(ApiError::Http(_))
+    ));
+}
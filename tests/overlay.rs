@@ -0,0 +1,32 @@
+use openapi_gen::openapi_client;
+
+openapi_client!(
+    "tests/fixtures/vendor.yaml",
+    "VendorApi",
+    overlay = "tests/fixtures/vendor_overlay.yaml"
+);
+
+#[test]
+fn test_overlay_patches_schemas() {
+    // `id` and `name` are required and `legacyCode` is removed by the overlay
+    let product = Product {
+        id: 1,
+        name: "Widget".to_string(),
+    };
+
+    let json = serde_json::to_value(&product).unwrap();
+    assert_eq!(json, serde_json::json!({"id": 1, "name": "Widget"}));
+}
+
+#[test]
+fn test_overlay_adds_schemas_and_paths() {
+    let client = VendorApi::new("https://api.example.com");
+
+    let _product = client.get_product(1);
+    let _categories = client.list_categories();
+
+    let category = Category {
+        slug: "tools".to_string(),
+    };
+    assert_eq!(category.slug, "tools");
+}
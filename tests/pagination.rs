@@ -0,0 +1,142 @@
+#![cfg(feature = "pagination")]
+
+use futures::TryStreamExt;
+use openapi_gen::openapi_client;
+use serde_json::json;
+use wiremock::matchers::{method, path, query_param, query_param_is_missing};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!(
+    "tests/fixtures/pagination.json",
+    "PetsApi",
+    pagination = (
+        cursor_param = "cursor",
+        next_cursor = "/page/next",
+        items = "/items"
+    )
+);
+
+async fn mount_page(server: &MockServer, cursor: Option<&str>, body: serde_json::Value) {
+    let mock = Mock::given(method("GET"))
+        .and(path("/pets"))
+        .and(query_param("limit", "2"));
+    let mock = match cursor {
+        Some(cursor) => mock.and(query_param("cursor", cursor)),
+        None => mock.and(query_param_is_missing("cursor")),
+    };
+    mock.respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_stream_follows_cursor_until_exhausted() {
+    let server = MockServer::start().await;
+    mount_page(
+        &server,
+        None,
+        json!({ "items": [{ "name": "Rex" }, { "name": "Tom" }], "page": { "next": "abc" } }),
+    )
+    .await;
+    mount_page(
+        &server,
+        Some("abc"),
+        json!({ "items": [{ "name": "Kitty" }], "page": { "next": null } }),
+    )
+    .await;
+
+    let client = PetsApi::for_testing(server.uri());
+    let pets: Vec<Pet> = client
+        .list_pets_all(Some(2), None)
+        .try_collect()
+        .await
+        .unwrap();
+
+    let names: Vec<_> = pets.iter().map(|pet| pet.name.as_str()).collect();
+    assert_eq!(names, ["Rex", "Tom", "Kitty"]);
+}
+
+#[tokio::test]
+async fn test_stream_stops_on_error() {
+    let server = MockServer::start().await;
+    mount_page(
+        &server,
+        None,
+        json!({ "items": [{ "name": "Rex" }], "page": { "next": "abc" } }),
+    )
+    .await;
+    Mock::given(method("GET"))
+        .and(path("/pets"))
+        .and(query_param("cursor", "abc"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&server)
+        .await;
+
+    let client = PetsApi::for_testing(server.uri());
+    let result: Result<Vec<Pet>, _> = client.list_pets_all(Some(2), None).try_collect().await;
+
+    assert!(matches!(result, Err(ApiError::Api { status: 500, .. })));
+}
+
+#[cfg(feature = "blocking")]
+#[tokio::test]
+async fn test_blocking_iterator_follows_cursor() {
+    let server = MockServer::start().await;
+    mount_page(
+        &server,
+        None,
+        json!({ "items": [{ "name": "Rex" }, { "name": "Tom" }], "page": { "next": "abc" } }),
+    )
+    .await;
+    mount_page(
+        &server,
+        Some("abc"),
+        json!({ "items": [{ "name": "Kitty" }], "page": {} }),
+    )
+    .await;
+
+    let uri = server.uri();
+    let pets = tokio::task::spawn_blocking(move || {
+        let client = PetsApi::with_client(uri, reqwest::blocking::Client::new());
+        client
+            .list_pets_all(Some(2), None)
+            .collect::<Result<Vec<Pet>, _>>()
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    let names: Vec<_> = pets.iter().map(|pet| pet.name.as_str()).collect();
+    assert_eq!(names, ["Rex", "Tom", "Kitty"]);
+}
+
+#[cfg(feature = "blocking")]
+#[tokio::test]
+async fn test_blocking_iterator_stops_on_error() {
+    let server = MockServer::start().await;
+    mount_page(
+        &server,
+        None,
+        json!({ "items": [{ "name": "Rex" }], "page": { "next": "abc" } }),
+    )
+    .await;
+    Mock::given(method("GET"))
+        .and(path("/pets"))
+        .and(query_param("cursor", "abc"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&server)
+        .await;
+
+    let uri = server.uri();
+    let results: Vec<_> = tokio::task::spawn_blocking(move || {
+        let client = PetsApi::with_client(uri, reqwest::blocking::Client::new());
+        client.list_pets_all(Some(2), None).collect::<Vec<_>>()
+    })
+    .await
+    .unwrap();
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].as_ref().unwrap().name, "Rex");
+    assert!(matches!(results[1], Err(ApiError::Api { status: 500, .. })));
+}
@@ -0,0 +1,24 @@
+mod individual_params {
+    use openapi_gen::openapi_client;
+
+    openapi_client!("openapi.json", "ConstantsApi");
+
+    #[test]
+    fn test_module_constant_lists_enum_values() {
+        assert_eq!(LIST_USERS_TYPE_VALUES, &["admin", "user", "guest"]);
+    }
+}
+
+mod param_structs {
+    use openapi_gen::openapi_client;
+
+    openapi_client!("openapi.json", "ConstantsApi", use_param_structs = true);
+
+    #[test]
+    fn test_param_struct_constant_lists_enum_values() {
+        assert_eq!(ListUsersParams::TYPE_VALUES, &["admin", "user", "guest"]);
+
+        let params = ListUsersParams::new().with_type(ListUsersParams::TYPE_VALUES[0]);
+        assert_eq!(params.r#type.as_deref(), Some("admin"));
+    }
+}
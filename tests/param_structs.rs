@@ -55,6 +55,25 @@ fn test_required_parameters() {
     assert_eq!(params.post_id, "updated-post-id");
 }
 
+#[test]
+fn test_string_parameters_accept_str_and_string() {
+    openapi_client!("openapi.json", use_param_structs = true);
+
+    let params = GetPostCommentsParams::new("borrowed-post");
+    assert_eq!(params.post_id, "borrowed-post");
+
+    // Owned strings are moved into the struct without copying
+    let post_id = "owned-post".to_string();
+    let buffer = post_id.as_ptr();
+    let params = GetPostCommentsParams::new(post_id);
+    assert_eq!(params.post_id.as_ptr(), buffer);
+
+    let kind = "admin".to_string();
+    let buffer = kind.as_ptr();
+    let params = ListUsersParams::new().with_type(kind);
+    assert_eq!(params.r#type.as_deref().map(str::as_ptr), Some(buffer));
+}
+
 #[test]
 fn test_method_integration() {
     openapi_client!("openapi.json", use_param_structs = true);
@@ -72,7 +91,12 @@ fn test_method_integration() {
 
     // Test with request body
     let params = UpdateUserParams::new(456i64);
-    let body = serde_json::json!({"username": "new_username"});
+    let body = UpdateUserRequest {
+        first_name: Some("New".to_string()),
+        last_name: None,
+        email: None,
+        profile: None,
+    };
     let _result = client.update_user(params, body);
 }
 
@@ -95,10 +119,103 @@ fn test_all_parameter_types() {
 
     // Required parameter + request body
     let params = UpdateUserParams::new(999i64);
-    let body = serde_json::json!({"email": "new@example.com"});
+    let body = UpdateUserRequest {
+        first_name: None,
+        last_name: None,
+        email: Some("new@example.com".to_string()),
+        profile: None,
+    };
     let _result = client.update_user(params, body);
 
     // Required parameter for deletion
     let params = DeleteUserParams::new(111i64);
     let _result = client.delete_user(params);
 }
+
+mod param_map {
+    openapi_gen::openapi_client!("tests/fixtures/param_map.yaml", use_param_structs = true);
+
+    fn map(entries: &[(&str, &str)]) -> HashMap<String, String> {
+        entries
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_try_from_map_parses_values() {
+        let params = ListReportsParams::try_from_map(&map(&[
+            ("projectId", "7"),
+            ("ids", "1,2,3"),
+            ("archived", "true"),
+            ("priority", "2"),
+        ]))
+        .unwrap();
+
+        assert_eq!(params.project_id, 7);
+        assert_eq!(params.ids, Some(vec![1, 2, 3]));
+        assert_eq!(params.archived, Some(true));
+        assert!(matches!(params.priority, Some(Priority::Value2)));
+    }
+
+    #[test]
+    fn test_try_from_map_leaves_missing_optional_parameters_unset() {
+        let params = ListReportsParams::try_from_map(&map(&[("projectId", "7")])).unwrap();
+
+        assert_eq!(params.ids, None);
+        assert_eq!(params.archived, None);
+        assert!(params.priority.is_none());
+    }
+
+    #[test]
+    fn test_try_from_map_requires_required_parameters() {
+        let Err(error) = ListReportsParams::try_from_map(&map(&[("ids", "1")])) else {
+            panic!("expected a missing parameter error");
+        };
+
+        assert_eq!(error, ParamParseError::Missing("projectId"));
+    }
+
+    #[test]
+    fn test_try_from_map_rejects_invalid_values() {
+        let Err(error) =
+            ListReportsParams::try_from_map(&map(&[("projectId", "7"), ("ids", "1,x")]))
+        else {
+            panic!("expected an invalid value error");
+        };
+
+        assert!(matches!(
+            error,
+            ParamParseError::Invalid { name: "ids", ref value, .. } if value == "x"
+        ));
+    }
+
+    #[test]
+    fn test_query_params_are_serialized_and_sorted() {
+        let params = ListReportsParams::new(7)
+            .with_priority(Priority::Value2)
+            .with_ids(vec![1, 2, 3])
+            .with_archived(false);
+
+        let query: Vec<_> = params.query_params().into_iter().collect();
+
+        assert_eq!(
+            query,
+            [
+                ("archived".to_string(), "false".to_string()),
+                ("ids".to_string(), "1,2,3".to_string()),
+                ("priority".to_string(), "2".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_query_params_leave_out_unset_parameters() {
+        let params = ListReportsParams::new(7).with_archived(true);
+
+        let query = params.query_params();
+
+        assert_eq!(query.len(), 1);
+        assert_eq!(query["archived"], "true");
+    }
+}
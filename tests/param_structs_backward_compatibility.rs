@@ -11,7 +11,12 @@ fn test_individual_parameters_still_work() {
     let _result = client.get_user_by_id(123i64);
     let _result = client.get_post_comments("test-post", Some(true));
 
-    let body = serde_json::json!({"username": "test"});
+    let body = UpdateUserRequest {
+        first_name: Some("Test".to_string()),
+        last_name: None,
+        email: None,
+        profile: None,
+    };
     let _result = client.update_user(456i64, body);
     let _result = client.delete_user(789i64);
 }
@@ -0,0 +1,46 @@
+#![cfg(not(feature = "ureq"))]
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!("tests/fixtures/parameter_refs.yaml", "ReposApi");
+
+mod with_param_structs {
+    openapi_gen::openapi_client!(
+        "tests/fixtures/parameter_refs.yaml",
+        "ReposApi",
+        use_param_structs = true
+    );
+
+    #[test]
+    fn test_referenced_parameters_are_struct_fields() {
+        let params = ListOrgReposParams::new("acme".to_string()).with_per_page(50);
+
+        assert_eq!(params.org, "acme");
+        assert_eq!(params.per_page, Some(50));
+        assert_eq!(params.page, None);
+    }
+}
+
+#[tokio::test]
+async fn test_referenced_parameters_are_sent() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/orgs/acme/repos"))
+        .and(query_param("per_page", "50"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(["api", "web"])))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = ReposApi::for_testing(server.uri());
+
+    let repos = client
+        .list_org_repos("acme", Some(50), Some(2))
+        .await
+        .unwrap();
+
+    assert_eq!(repos, vec!["api", "web"]);
+}
@@ -0,0 +1,38 @@
+#![cfg(not(feature = "ureq"))]
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!("tests/fixtures/path_refs.yaml", "SharedPathsApi");
+
+#[tokio::test]
+async fn test_chained_internal_path_item_reference() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/health"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = SharedPathsApi::for_testing(server.uri());
+
+    assert_eq!(client.get_health().await.unwrap(), "ok");
+}
+
+#[tokio::test]
+async fn test_path_item_reference_to_another_file() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/projects/3"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"id": 3, "name": "api"})))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = SharedPathsApi::for_testing(server.uri());
+
+    let project = client.get_project(3).await.unwrap();
+
+    assert_eq!(project["name"], "api");
+}
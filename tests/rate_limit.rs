@@ -0,0 +1,59 @@
+#![cfg(feature = "rate-limit")]
+
+use openapi_gen::openapi_client;
+use std::time::{Duration, Instant};
+
+openapi_client!("tests/fixtures/rate_limit.json", "RateLimitedApi");
+
+// Nothing listens on this port, so requests fail fast after being throttled
+const UNREACHABLE_URL: &str = "http://127.0.0.1:9";
+
+#[tokio::test]
+async fn test_annotated_operation_is_throttled() {
+    let client = RateLimitedApi::new(UNREACHABLE_URL);
+
+    let start = Instant::now();
+    for _ in 0..3 {
+        let _ = client.search().await;
+    }
+
+    // Two calls fit in the burst, the third waits for half of the window
+    assert!(start.elapsed() >= Duration::from_millis(400));
+}
+
+#[tokio::test]
+async fn test_unannotated_operation_is_not_throttled() {
+    let client = RateLimitedApi::new(UNREACHABLE_URL);
+
+    let start = Instant::now();
+    for _ in 0..5 {
+        let _ = client.get_status().await;
+    }
+
+    assert!(start.elapsed() < Duration::from_millis(400));
+}
+
+#[tokio::test]
+async fn test_testing_client_is_not_throttled() {
+    let client = RateLimitedApi::for_testing(UNREACHABLE_URL);
+
+    let start = Instant::now();
+    for _ in 0..3 {
+        let _ = client.search().await;
+    }
+
+    assert!(start.elapsed() < Duration::from_millis(400));
+}
+
+#[tokio::test]
+async fn test_clones_share_the_limit() {
+    let client = RateLimitedApi::new(UNREACHABLE_URL);
+    let other = client.clone();
+
+    let start = Instant::now();
+    let _ = client.search().await;
+    let _ = client.search().await;
+    let _ = other.search().await;
+
+    assert!(start.elapsed() >= Duration::from_millis(400));
+}
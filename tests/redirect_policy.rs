@@ -0,0 +1,52 @@
+#![cfg(not(feature = "ureq"))]
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!("tests/fixtures/success_codes.json", "StatusApi");
+
+async fn redirecting_server() -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/status"))
+        .respond_with(ResponseTemplate::new(302).insert_header("location", "/v2/status"))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2/status"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "id": 7 })))
+        .mount(&server)
+        .await;
+    server
+}
+
+#[tokio::test]
+async fn test_disabled_redirects_return_the_location() {
+    let server = redirecting_server().await;
+    let client =
+        StatusApi::with_redirect_policy(server.uri(), reqwest::redirect::Policy::none()).unwrap();
+
+    let error = client.get_status().await.unwrap_err();
+
+    match error {
+        ApiError::Redirect { status, location } => {
+            assert_eq!(status, 302);
+            assert_eq!(location.as_deref(), Some("/v2/status"));
+        }
+        other => panic!("Unexpected error: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_limited_redirects_are_followed() {
+    let server = redirecting_server().await;
+    let client =
+        StatusApi::with_redirect_policy(server.uri(), reqwest::redirect::Policy::limited(1))
+            .unwrap();
+
+    let status = client.get_status().await.unwrap();
+
+    assert_eq!(status.id, 7);
+}
@@ -0,0 +1,157 @@
+#![cfg(not(feature = "ureq"))]
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!("openapi.json", "SignedApi");
+
+fn user_json(id: i64) -> serde_json::Value {
+    json!({
+        "id": id,
+        "username": "john_doe",
+        "email": "john@example.com",
+        "status": "active"
+    })
+}
+
+/// Sign the method, path and body length of a request, standing in for a real signing scheme
+fn sign(request: &mut reqwest::Request) {
+    let body_len = request
+        .body()
+        .and_then(reqwest::Body::as_bytes)
+        .map_or(0, <[u8]>::len);
+    let signature = format!("{} {} {}", request.method(), request.url().path(), body_len);
+    request
+        .headers_mut()
+        .insert("x-signature", signature.parse().unwrap());
+}
+
+#[tokio::test]
+async fn test_signer_sees_the_assembled_request() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/42"))
+        .and(header("x-signature", "GET /users/42 0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(user_json(42)))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = SignedApi::for_testing(server.uri()).with_request_signer(sign);
+
+    let user = client.get_user_by_id(42).await.unwrap();
+
+    assert_eq!(user.id, 42);
+}
+
+#[tokio::test]
+async fn test_signer_sees_the_request_body() {
+    let server = MockServer::start().await;
+    let body = CreateUserRequest {
+        username: "john_doe".to_string(),
+        email: "john@example.com".to_string(),
+        first_name: None,
+        last_name: None,
+        r#type: None,
+    };
+    let signature = format!("POST /users {}", serde_json::to_vec(&body).unwrap().len());
+    Mock::given(method("POST"))
+        .and(path("/users"))
+        .and(header("x-signature", signature.as_str()))
+        .respond_with(ResponseTemplate::new(201).set_body_json(user_json(7)))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = SignedApi::for_testing(server.uri()).with_request_signer(sign);
+
+    let user = client.create_user(body).await.unwrap();
+
+    assert_eq!(user.id, 7);
+}
+
+#[tokio::test]
+async fn test_requests_are_unsigned_by_default() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/42"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(user_json(42)))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = SignedApi::for_testing(server.uri());
+
+    client.get_user_by_id(42).await.unwrap();
+
+    let requests = server.received_requests().await.unwrap();
+    assert!(!requests[0].headers.contains_key("x-signature"));
+}
+
+#[cfg(feature = "blocking")]
+mod blocking {
+    use super::*;
+
+    /// Sign blocking requests like `sign` does
+    fn sign_blocking(request: &mut reqwest::blocking::Request) {
+        let body_len = request
+            .body()
+            .and_then(reqwest::blocking::Body::as_bytes)
+            .map_or(0, <[u8]>::len);
+        let signature = format!("{} {} {}", request.method(), request.url().path(), body_len);
+        request
+            .headers_mut()
+            .insert("x-signature", signature.parse().unwrap());
+    }
+
+    async fn signed_user_server() -> MockServer {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/users/42"))
+            .and(header("x-signature", "GET /users/42 0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(user_json(42)))
+            .expect(1)
+            .mount(&server)
+            .await;
+        server
+    }
+
+    #[tokio::test]
+    async fn test_blocking_signer_sees_the_assembled_request() {
+        let server = signed_user_server().await;
+        let uri = server.uri();
+
+        let user = tokio::task::spawn_blocking(move || {
+            SignedApi::with_client(uri, reqwest::blocking::Client::new())
+                .with_request_signer(sign_blocking)
+                .get_user_by_id(42)
+        })
+        .await
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(user.id, 42);
+    }
+
+    mod dual {
+        use super::*;
+
+        openapi_client!("openapi.json", "DualSignedApi", dual = true);
+
+        #[tokio::test]
+        async fn test_dual_blocking_signer_sees_the_assembled_request() {
+            let server = signed_user_server().await;
+            let uri = server.uri();
+
+            let user = tokio::task::spawn_blocking(move || {
+                DualSignedApiBlocking::new(uri)
+                    .with_request_signer(sign_blocking)
+                    .get_user_by_id(42)
+            })
+            .await
+            .unwrap()
+            .unwrap();
+
+            assert_eq!(user.id, 42);
+        }
+    }
+}
@@ -0,0 +1,68 @@
+#![cfg(not(feature = "ureq"))]
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!("tests/fixtures/response_headers.json", "HeadersApi");
+
+#[tokio::test]
+async fn test_documented_headers_are_parsed() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/pets"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!(["Rex", "Tom"]))
+                .insert_header("X-Total-Count", "42")
+                .insert_header("X-Next-Page", "abc")
+                .insert_header("X-Rate-Limit-Remaining", "7"),
+        )
+        .mount(&server)
+        .await;
+
+    let client = HeadersApi::for_testing(server.uri());
+    let (pets, headers) = client.list_pets_with_headers().await.unwrap();
+
+    assert_eq!(pets, ["Rex", "Tom"]);
+    assert_eq!(headers.x_total_count, Some(42));
+    assert_eq!(headers.x_next_page.as_deref(), Some("abc"));
+    assert_eq!(headers.x_rate_limit_remaining, Some(7));
+}
+
+#[tokio::test]
+async fn test_missing_or_invalid_headers_are_none() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/pets"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!([]))
+                .insert_header("X-Total-Count", "many"),
+        )
+        .mount(&server)
+        .await;
+
+    let client = HeadersApi::for_testing(server.uri());
+    let (_, headers) = client.list_pets_with_headers().await.unwrap();
+
+    assert_eq!(headers.x_total_count, None);
+    assert_eq!(headers.x_next_page, None);
+    assert_eq!(headers.x_rate_limit_remaining, None);
+}
+
+#[tokio::test]
+async fn test_error_status_is_returned_as_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/pets"))
+        .respond_with(ResponseTemplate::new(503).insert_header("X-Total-Count", "1"))
+        .mount(&server)
+        .await;
+
+    let client = HeadersApi::for_testing(server.uri());
+    let result = client.list_pets_with_headers().await;
+
+    assert!(matches!(result, Err(ApiError::Api { status: 503, .. })));
+}
@@ -0,0 +1,49 @@
+#![cfg(not(feature = "ureq"))]
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!("tests/fixtures/shared_responses.json", "SharedResponsesApi");
+
+#[allow(dead_code)]
+async fn get_pet_returns_typed_body(client: &SharedResponsesApi) -> ApiResult<Pet> {
+    // The 200 response is a `$ref` into `components.responses`
+    client.get_pet(1).await
+}
+
+#[allow(dead_code)]
+async fn list_pets_follows_chained_references(client: &SharedResponsesApi) -> ApiResult<Vec<Pet>> {
+    // The 200 response references another response component
+    client.list_pets().await
+}
+
+#[test]
+fn test_referenced_responses_compile() {
+    let client = SharedResponsesApi::new("https://api.example.com");
+
+    let _pet = get_pet_returns_typed_body(&client);
+    let _pets = list_pets_follows_chained_references(&client);
+}
+
+#[tokio::test]
+async fn test_referenced_response_headers_are_parsed() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/pets"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!([{ "id": 1, "name": "Rex" }]))
+                .insert_header("X-Total-Count", "3"),
+        )
+        .mount(&server)
+        .await;
+
+    let client = SharedResponsesApi::for_testing(server.uri());
+    // The header is a `$ref` into `components.headers`, itself referencing another header
+    let (pets, headers) = client.list_pets_with_headers().await.unwrap();
+
+    assert_eq!(pets[0].name, "Rex");
+    assert_eq!(headers.x_total_count, Some(3));
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "response-validation")]
+
+use openapi_gen::openapi_client;
+
+openapi_client!("openapi.json", "ValidationTestApi");
+
+#[test]
+fn test_validated_methods_exist() {
+    let client = ValidationTestApi::new("https://api.example.com");
+
+    // Validated variants share the signature of the regular methods
+    let _user = client.get_user_by_id_validated(123);
+    let _users = client.list_users_validated(None, None, None);
+}
+
+#[test]
+fn test_valid_response_passes() {
+    let user = serde_json::json!({
+        "id": 1,
+        "username": "jdoe",
+        "email": "jdoe@example.com",
+        "status": "active",
+        "tags": ["admin"]
+    });
+
+    let schema = r##"{"$ref": "#/components/schemas/User"}"##;
+    assert!(validate_response_schema(&user, schema).is_ok());
+}
+
+#[test]
+fn test_schema_drift_is_reported() {
+    // `id` has the wrong type, `email` is missing and `status` is not an allowed value
+    let user = serde_json::json!({
+        "id": "1",
+        "username": "jdoe",
+        "status": "unknown",
+        "tags": [1]
+    });
+
+    let schema = r##"{"$ref": "#/components/schemas/User"}"##;
+    let errors = validate_response_schema(&user, schema).unwrap_err();
+
+    assert!(errors.contains(&"$: missing required field `email`".to_string()));
+    assert!(errors.contains(&"$.id: expected integer, found string".to_string()));
+    assert!(errors.contains(&"$.status: \"unknown\" is not an allowed value".to_string()));
+    assert!(errors.contains(&"$.tags[0]: expected string, found number".to_string()));
+}
+
+#[test]
+fn test_component_schemas_are_parsed_once() {
+    let first = response_schema_components();
+    let second = response_schema_components();
+
+    assert!(std::ptr::eq(first, second));
+    assert!(first.get("User").is_some());
+}
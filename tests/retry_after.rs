@@ -0,0 +1,111 @@
+#![cfg(feature = "retry")]
+
+use openapi_gen::openapi_client;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+openapi_client!("tests/fixtures/retry.json", "RetryApi");
+
+/// Serve a 429 with the given `Retry-After` value first, then `ok` for every later request
+fn spawn_server(retry_after: &'static str) -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(AtomicUsize::new(0));
+
+    let counter = requests.clone();
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 2 {
+                line.clear();
+            }
+
+            let response = if counter.fetch_add(1, Ordering::SeqCst) == 0 {
+                format!(
+                    "HTTP/1.1 429 Too Many Requests\r\nRetry-After: {}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+                    retry_after
+                )
+            } else {
+                "HTTP/1.1 200 OK\r\ncontent-length: 2\r\nconnection: close\r\n\r\nok".to_string()
+            };
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+    });
+
+    (url, requests)
+}
+
+#[tokio::test]
+async fn test_idempotent_request_waits_and_retries() {
+    let (url, requests) = spawn_server("1");
+    let client = RetryApi::new(url);
+
+    let start = Instant::now();
+    let result = client.list_items().await.unwrap();
+
+    assert_eq!(result, "ok");
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+    assert!(start.elapsed() >= Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn test_http_date_in_the_past_retries_immediately() {
+    let (url, requests) = spawn_server("Sun, 06 Nov 1994 08:49:37 GMT");
+    let client = RetryApi::new(url);
+
+    let result = client.list_items().await.unwrap();
+
+    assert_eq!(result, "ok");
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_non_idempotent_request_is_not_retried() {
+    let (url, requests) = spawn_server("0");
+    let client = RetryApi::new(url);
+
+    let result = client.create_item().await;
+
+    assert!(matches!(result, Err(ApiError::Api { status: 429, .. })));
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_testing_client_does_not_retry() {
+    let (url, requests) = spawn_server("0");
+    let client = RetryApi::for_testing(url);
+
+    let result = client.list_items().await;
+
+    assert!(matches!(result, Err(ApiError::Api { status: 429, .. })));
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_retry_after_parsing() {
+    let headers = |value: &str| {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(reqwest::header::RETRY_AFTER, value.parse().unwrap());
+        headers
+    };
+
+    assert_eq!(
+        retry_after_delay(&headers("3")),
+        Some(Duration::from_secs(3))
+    );
+    assert_eq!(retry_after_delay(&headers("86400")), Some(MAX_RETRY_AFTER));
+    assert_eq!(
+        retry_after_delay(&headers("Sun, 06 Nov 1994 08:49:37 GMT")),
+        Some(Duration::ZERO)
+    );
+    assert_eq!(retry_after_delay(&headers("soon")), None);
+    assert_eq!(
+        parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT"),
+        Some(std::time::UNIX_EPOCH + Duration::from_secs(784_111_777))
+    );
+}
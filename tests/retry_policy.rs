@@ -0,0 +1,105 @@
+#![cfg(not(feature = "ureq"))]
+
+use openapi_gen::openapi_client;
+use std::time::Duration;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!("tests/fixtures/retry.json", "RetryingApi", retry = 2);
+
+const FAST_RETRIES: RetryPolicy = RetryPolicy {
+    max_retries: 2,
+    initial_backoff: Duration::from_millis(1),
+};
+
+/// Answer `failures` requests with a 503, and every later request with `ok`
+async fn flaky_server(http_method: &str, failures: u64) -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method(http_method))
+        .and(path("/items"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(failures)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method(http_method))
+        .and(path("/items"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+        .mount(&server)
+        .await;
+    server
+}
+
+async fn request_count(server: &MockServer) -> usize {
+    server.received_requests().await.unwrap().len()
+}
+
+#[tokio::test]
+async fn test_get_is_retried_on_server_errors() {
+    let server = flaky_server("GET", 2).await;
+    let client = RetryingApi::new(server.uri()).with_retry_policy(FAST_RETRIES);
+
+    let result = client.list_items().await.unwrap();
+
+    assert_eq!(result, "ok");
+    assert_eq!(request_count(&server).await, 3);
+}
+
+#[tokio::test]
+async fn test_retries_stop_after_max_retries() {
+    let server = flaky_server("GET", 3).await;
+    let client = RetryingApi::new(server.uri()).with_retry_policy(FAST_RETRIES);
+
+    let error = client.list_items().await.unwrap_err();
+
+    assert_eq!(error.status(), Some(503));
+    assert_eq!(request_count(&server).await, 3);
+}
+
+#[tokio::test]
+async fn test_post_is_never_retried() {
+    let server = flaky_server("POST", 1).await;
+    let client = RetryingApi::new(server.uri()).with_retry_policy(FAST_RETRIES);
+
+    let error = client.create_item().await.unwrap_err();
+
+    assert_eq!(error.status(), Some(503));
+    assert_eq!(request_count(&server).await, 1);
+}
+
+#[tokio::test]
+async fn test_testing_clients_do_not_retry() {
+    let server = flaky_server("GET", 1).await;
+    let client = RetryingApi::for_testing(server.uri());
+
+    let error = client.list_items().await.unwrap_err();
+
+    assert_eq!(error.status(), Some(503));
+    assert_eq!(request_count(&server).await, 1);
+}
+
+#[test]
+fn test_retry_policy_defaults_to_the_option() {
+    let client = RetryingApi::new("https://api.example.com");
+
+    assert_eq!(client.retry_policy.max_retries, 2);
+}
+
+#[cfg(feature = "blocking")]
+#[tokio::test]
+async fn test_blocking_get_is_retried_on_server_errors() {
+    let server = flaky_server("GET", 1).await;
+    let uri = server.uri();
+
+    let result = tokio::task::spawn_blocking(move || {
+        RetryingApi::with_client(uri, reqwest::blocking::Client::new())
+            .with_retry_policy(FAST_RETRIES)
+            .list_items()
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    assert_eq!(result, "ok");
+    assert_eq!(request_count(&server).await, 2);
+}
@@ -0,0 +1,35 @@
+use openapi_gen::openapi_client;
+use std::collections::HashSet;
+
+openapi_client!("tests/fixtures/rust_derives.json", "DerivesApi");
+
+#[test]
+fn test_struct_gets_extension_derives() {
+    let key = ResourceKey {
+        namespace: "default".to_string(),
+        name: "web".to_string(),
+    };
+
+    let mut keys = HashSet::new();
+    keys.insert(key.clone());
+    keys.insert(key);
+
+    assert_eq!(keys.len(), 1);
+}
+
+#[test]
+fn test_default_derive() {
+    let request = CreateResourceRequest::default();
+
+    assert!(request.name.is_none());
+    assert!(request.labels.is_none());
+}
+
+#[test]
+fn test_enum_gets_extension_derives() {
+    let visibility = Visibility::Public;
+    let copied = visibility;
+
+    assert_eq!(visibility, copied);
+    assert_ne!(visibility, Visibility::Private);
+}
@@ -0,0 +1,68 @@
+use openapi_gen::openapi_client;
+use serde_json::json;
+
+openapi_client!("tests/fixtures/serde_with.json", "SerdeWithApi");
+
+mod string_encoded {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &i64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&value.to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i64, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+mod optional_string_encoded {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &Option<i64>, serializer: S) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => super::string_encoded::serialize(value, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<i64>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|value| value.parse().map_err(serde::de::Error::custom))
+            .transpose()
+    }
+}
+
+#[test]
+fn test_fields_use_the_serde_with_module() {
+    let measurement: Measurement = serde_json::from_value(json!({
+        "value": "9007199254740993",
+        "previousValue": "12",
+        "unit": "bytes"
+    }))
+    .unwrap();
+
+    assert_eq!(measurement.value, 9007199254740993);
+    assert_eq!(measurement.previous_value, Some(12));
+    assert_eq!(
+        serde_json::to_value(&measurement).unwrap(),
+        json!({ "value": "9007199254740993", "previousValue": "12", "unit": "bytes" })
+    );
+}
+
+#[test]
+fn test_missing_optional_field_defaults_to_none() {
+    let measurement: Measurement = serde_json::from_value(json!({ "value": "1" })).unwrap();
+
+    assert_eq!(measurement.previous_value, None);
+}
+
+#[test]
+fn test_plain_integer_is_rejected() {
+    let result = serde_json::from_value::<Measurement>(json!({ "value": 1 }));
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,120 @@
+#![cfg(not(feature = "ureq"))]
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!(
+    "openapi.json",
+    "SharedApi",
+    auth = "bearer",
+    shared_auth = true
+);
+
+async fn user_server() -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/42"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 42,
+            "username": "john_doe",
+            "email": "john@example.com",
+            "status": "active"
+        })))
+        .mount(&server)
+        .await;
+    server
+}
+
+async fn authorization_headers(server: &MockServer) -> Vec<Option<String>> {
+    server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .map(|request| {
+            request
+                .headers
+                .get("authorization")
+                .map(|value| value.to_str().unwrap().to_string())
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_refreshed_token_is_used_by_clones() {
+    let server = user_server().await;
+    let client = SharedApi::for_testing(server.uri()).with_bearer_token("expired-token");
+    let clone = client.clone();
+
+    clone.get_user_by_id(42).await.unwrap();
+    client.set_bearer_token("fresh-token");
+    clone.get_user_by_id(42).await.unwrap();
+
+    assert_eq!(
+        authorization_headers(&server).await,
+        [
+            Some("Bearer expired-token".to_string()),
+            Some("Bearer fresh-token".to_string())
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_token_is_refreshed_from_another_task() {
+    let server = user_server().await;
+    let client = SharedApi::for_testing(server.uri());
+
+    let refresher = client.clone();
+    tokio::spawn(async move { refresher.set_bearer_token("background-token") })
+        .await
+        .unwrap();
+    client.get_user_by_id(42).await.unwrap();
+
+    assert_eq!(
+        authorization_headers(&server).await,
+        [Some("Bearer background-token".to_string())]
+    );
+}
+
+#[tokio::test]
+async fn test_clear_auth_applies_to_clones() {
+    let server = user_server().await;
+    let client = SharedApi::for_testing(server.uri()).with_bearer_token("secret-token");
+    let clone = client.clone();
+
+    clone.clear_auth();
+    client.get_user_by_id(42).await.unwrap();
+
+    assert_eq!(authorization_headers(&server).await, [None]);
+}
+
+mod api_key {
+    use super::*;
+    use wiremock::matchers::header;
+
+    openapi_client!(
+        "tests/fixtures/api_key.yaml",
+        "SharedKeyApi",
+        auth = "api_key",
+        shared_auth = true
+    );
+
+    #[tokio::test]
+    async fn test_api_key_is_shared_between_clones() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/forecast"))
+            .and(header("x-api-key", "new-key"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"summary": "sunny"})))
+            .mount(&server)
+            .await;
+        let client = SharedKeyApi::for_testing(server.uri()).with_api_key("old-key");
+
+        client.clone().set_api_key("new-key");
+        let forecast = client.get_forecast().await.unwrap();
+
+        assert_eq!(forecast.summary, "sunny");
+    }
+}
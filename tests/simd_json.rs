@@ -0,0 +1,44 @@
+#![cfg(feature = "simd-json")]
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!("openapi.json", "SimdApi");
+
+#[tokio::test]
+async fn test_response_is_parsed_with_simd_json() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/42"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 42,
+            "username": "john_doe",
+            "email": "john@example.com",
+            "status": "active"
+        })))
+        .mount(&server)
+        .await;
+
+    let client = SimdApi::for_testing(server.uri());
+    let user = client.get_user_by_id(42).await.unwrap();
+
+    assert_eq!(user.id, 42);
+    assert_eq!(user.username, "john_doe");
+}
+
+#[tokio::test]
+async fn test_invalid_json_is_a_serialization_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/42"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("{\"id\": "))
+        .mount(&server)
+        .await;
+
+    let client = SimdApi::for_testing(server.uri());
+    let error = client.get_user_by_id(42).await.unwrap_err();
+
+    assert!(matches!(error, ApiError::Serialization(_)));
+}
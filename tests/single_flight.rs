@@ -0,0 +1,169 @@
+#![cfg(feature = "single-flight")]
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+use std::sync::Arc;
+use std::time::Duration;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!("openapi.json", "CoalescingApi");
+
+mod auth {
+    use openapi_gen::openapi_client;
+
+    openapi_client!(
+        "openapi.json",
+        "SharedAuthApi",
+        auth = "bearer",
+        shared_auth = true
+    );
+}
+
+mod headers {
+    use openapi_gen::openapi_client;
+
+    openapi_client!("tests/fixtures/header_params.yaml", "OrdersApi");
+}
+
+async fn user_server(response: ResponseTemplate) -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/42"))
+        .respond_with(response.set_delay(Duration::from_millis(200)))
+        .mount(&server)
+        .await;
+    server
+}
+
+fn user_response() -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({
+        "id": 42,
+        "username": "john_doe",
+        "email": "john@example.com",
+        "status": "active"
+    }))
+}
+
+#[tokio::test]
+async fn test_concurrent_identical_gets_share_one_request() {
+    let server = user_server(user_response()).await;
+    let client = CoalescingApi::for_testing(server.uri());
+
+    let users = futures::future::join_all((0..5).map(|_| client.get_user_by_id(42))).await;
+
+    assert!(users.iter().all(|user| user.as_ref().unwrap().id == 42));
+    assert_eq!(server.received_requests().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_error_responses_are_shared() {
+    let server = user_server(ResponseTemplate::new(404).set_body_string("Not found")).await;
+    let client = CoalescingApi::for_testing(server.uri());
+
+    let results = futures::future::join_all((0..3).map(|_| client.get_user_by_id(42))).await;
+
+    for result in results {
+        assert!(matches!(result, Err(ApiError::Api { status: 404, .. })));
+    }
+    assert_eq!(server.received_requests().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_completed_requests_are_not_reused() {
+    let server = user_server(user_response()).await;
+    let client = CoalescingApi::for_testing(server.uri());
+
+    client.get_user_by_id(42).await.unwrap();
+    client.get_user_by_id(42).await.unwrap();
+
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_waiters_send_their_own_request_when_leader_is_dropped() {
+    let server = user_server(user_response()).await;
+    let client = Arc::new(CoalescingApi::for_testing(server.uri()));
+
+    let leader = tokio::spawn({
+        let client = client.clone();
+        async move { client.get_user_by_id(42).await }
+    });
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    let waiter = tokio::spawn({
+        let client = client.clone();
+        async move { client.get_user_by_id(42).await }
+    });
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    leader.abort();
+
+    assert_eq!(waiter.await.unwrap().unwrap().id, 42);
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_clones_do_not_share_requests() {
+    let server = user_server(user_response()).await;
+    let client = CoalescingApi::for_testing(server.uri());
+    let clone = client.clone();
+
+    let (a, b) = tokio::join!(client.get_user_by_id(42), clone.get_user_by_id(42));
+
+    assert_eq!(a.unwrap().id, b.unwrap().id);
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_requests_with_other_credentials_are_not_shared() {
+    let server = user_server(user_response()).await;
+    let client =
+        Arc::new(auth::SharedAuthApi::for_testing(server.uri()).with_bearer_token("alice"));
+
+    let alice = tokio::spawn({
+        let client = client.clone();
+        async move { client.get_user_by_id(42).await }
+    });
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    client.set_bearer_token("bob");
+    let bob = client.get_user_by_id(42).await;
+
+    assert!(alice.await.unwrap().is_ok());
+    assert!(bob.is_ok());
+    let mut tokens: Vec<_> = server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .map(|request| {
+            request.headers["authorization"]
+                .to_str()
+                .unwrap()
+                .to_string()
+        })
+        .collect();
+    tokens.sort();
+    assert_eq!(tokens, ["Bearer alice", "Bearer bob"]);
+}
+
+#[tokio::test]
+async fn test_requests_with_other_header_parameters_are_not_shared() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/orders/o-1"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({"id": "o-1"}))
+                .set_delay(Duration::from_millis(200)),
+        )
+        .mount(&server)
+        .await;
+    let client = headers::OrdersApi::for_testing(server.uri());
+
+    let (a, b) = tokio::join!(
+        client.get_order("o-1", "req-1", None, None),
+        client.get_order("o-1", "req-2", None, None),
+    );
+
+    assert!(a.is_ok() && b.is_ok());
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+}
@@ -0,0 +1,64 @@
+use serde_json::json;
+
+mod skipping {
+    use openapi_gen::openapi_client;
+
+    openapi_client!(
+        "tests/fixtures/collections.yaml",
+        "OrdersApi",
+        skip_empty_collections = true
+    );
+}
+
+mod keeping {
+    use openapi_gen::openapi_client;
+
+    openapi_client!("tests/fixtures/collections.yaml", "OrdersApi");
+}
+
+#[test]
+fn test_empty_collections_are_skipped() {
+    let order = skipping::Order {
+        id: 1,
+        items: Vec::new(),
+        labels: Default::default(),
+    };
+
+    assert_eq!(serde_json::to_value(&order).unwrap(), json!({"id": 1}));
+}
+
+#[test]
+fn test_non_empty_collections_are_serialized() {
+    let order = skipping::Order {
+        id: 1,
+        items: vec!["book".to_string()],
+        labels: [("gift".to_string(), json!(true))].into_iter().collect(),
+    };
+
+    assert_eq!(
+        serde_json::to_value(&order).unwrap(),
+        json!({"id": 1, "items": ["book"], "labels": {"gift": true}})
+    );
+}
+
+#[test]
+fn test_missing_collections_deserialize_as_empty() {
+    let order: skipping::Order = serde_json::from_value(json!({"id": 1})).unwrap();
+
+    assert!(order.items.is_empty());
+    assert!(order.labels.is_empty());
+}
+
+#[test]
+fn test_empty_collections_are_kept_by_default() {
+    let order = keeping::Order {
+        id: 1,
+        items: Vec::new(),
+        labels: Default::default(),
+    };
+
+    assert_eq!(
+        serde_json::to_value(&order).unwrap(),
+        json!({"id": 1, "items": [], "labels": {}})
+    );
+}
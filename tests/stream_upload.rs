@@ -0,0 +1,71 @@
+#![cfg(feature = "stream-upload")]
+
+use bytes::Bytes;
+use openapi_gen::openapi_client;
+use serde_json::json;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!("tests/fixtures/stream_upload.json", "FilesApi");
+
+fn chunks(parts: &[&'static str]) -> impl futures::Stream<Item = Result<Bytes, std::io::Error>> {
+    futures::stream::iter(
+        parts
+            .iter()
+            .map(|part| Ok(Bytes::from_static(part.as_bytes())))
+            .collect::<Vec<_>>(),
+    )
+}
+
+#[tokio::test]
+async fn test_body_is_streamed_with_declared_content_type() {
+    let server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path("/files/notes.txt"))
+        .and(header("content-type", "application/octet-stream"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "size": 11 })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = FilesApi::for_testing(server.uri());
+    let stored = client
+        .upload_file_stream("notes.txt", chunks(&["hello", " ", "world"]))
+        .await
+        .unwrap();
+
+    assert_eq!(stored.size, 11);
+    let requests = server.received_requests().await.unwrap();
+    assert_eq!(requests[0].body, b"hello world");
+}
+
+#[tokio::test]
+async fn test_binary_schema_uses_its_media_type() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/images"))
+        .and(header("content-type", "image/png"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "size": 4 })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = FilesApi::for_testing(server.uri());
+    let stored = client.upload_image_stream(chunks(&["png!"])).await.unwrap();
+
+    assert_eq!(stored.size, 4);
+}
+
+#[tokio::test]
+async fn test_stream_error_fails_the_request() {
+    let server = MockServer::start().await;
+    let client = FilesApi::for_testing(server.uri());
+
+    let body = futures::stream::iter(vec![
+        Ok(Bytes::from_static(b"partial")),
+        Err(std::io::Error::other("disk read failed")),
+    ]);
+    let result = client.upload_file_stream("broken.bin", body).await;
+
+    assert!(matches!(result, Err(ApiError::Http(_))));
+}
@@ -0,0 +1,32 @@
+use openapi_gen::openapi_client;
+use serde_json::json;
+
+openapi_client!("tests/fixtures/strict_objects.yaml", "SettingsApi");
+
+#[test]
+fn test_additional_properties_false_rejects_unknown_fields() {
+    let settings: Settings = serde_json::from_value(json!({
+        "theme": "dark",
+        "fontSize": 14
+    }))
+    .unwrap();
+    assert_eq!(settings.font_size, Some(14));
+
+    let error = serde_json::from_value::<Settings>(json!({
+        "theme": "dark",
+        "fontWeight": "bold"
+    }))
+    .unwrap_err();
+    assert!(error.to_string().contains("unknown field `fontWeight`"));
+}
+
+#[test]
+fn test_other_objects_ignore_unknown_fields() {
+    let profile: Profile = serde_json::from_value(json!({
+        "name": "Jane",
+        "nickname": "J"
+    }))
+    .unwrap();
+
+    assert_eq!(profile.name, "Jane");
+}
@@ -0,0 +1,69 @@
+#![cfg(not(feature = "ureq"))]
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!("tests/fixtures/success_codes.json", "BatchApi");
+
+#[tokio::test]
+async fn test_unlisted_2xx_status_is_an_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/batches"))
+        .respond_with(ResponseTemplate::new(207).set_body_json(json!({"failed": [3]})))
+        .mount(&server)
+        .await;
+    let client = BatchApi::for_testing(server.uri());
+
+    let error = client.create_batch().await.unwrap_err();
+
+    match error {
+        ApiError::ApiTyped { status, body } => {
+            assert_eq!(status, 207);
+            assert_eq!(body, json!({"failed": [3]}));
+        }
+        other => panic!("Unexpected error: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_listed_non_standard_status_is_a_success() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/batches/7"))
+        .respond_with(ResponseTemplate::new(299).set_body_json(json!({"id": 7})))
+        .mount(&server)
+        .await;
+    let client = BatchApi::for_testing(server.uri());
+
+    let batch = client.get_batch(7).await.unwrap();
+
+    assert_eq!(batch.id, 7);
+}
+
+#[tokio::test]
+async fn test_unfollowed_redirect_is_a_redirect_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/status"))
+        .respond_with(ResponseTemplate::new(302).insert_header("location", "/v2/status"))
+        .mount(&server)
+        .await;
+    let http = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .unwrap();
+    let client = BatchApi::with_client(server.uri(), http);
+
+    let error = client.get_status().await.unwrap_err();
+
+    match error {
+        ApiError::Redirect { status, location } => {
+            assert_eq!(status, 302);
+            assert_eq!(location.as_deref(), Some("/v2/status"));
+        }
+        other => panic!("Unexpected error: {:?}", other),
+    }
+}
@@ -0,0 +1,52 @@
+#![cfg(not(feature = "ureq"))]
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!("openapi.json", "UsersApi");
+
+#[tokio::test]
+async fn test_created_response_is_returned() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/users"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "id": 7,
+            "username": "jane_doe",
+            "email": "jane@example.com",
+            "status": "active"
+        })))
+        .mount(&server)
+        .await;
+    let client = UsersApi::for_testing(server.uri());
+
+    let user: User = client
+        .create_user(CreateUserRequest {
+            username: "jane_doe".to_string(),
+            email: "jane@example.com".to_string(),
+            first_name: None,
+            last_name: None,
+            r#type: None,
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(user.id, 7);
+    assert_eq!(user.username, "jane_doe");
+}
+
+#[tokio::test]
+async fn test_no_content_response_returns_unit() {
+    let server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path("/users/7"))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&server)
+        .await;
+    let client = UsersApi::for_testing(server.uri());
+
+    // The empty body isn't parsed, so this doesn't fail on a missing JSON value
+    let () = client.delete_user(7).await.unwrap();
+}
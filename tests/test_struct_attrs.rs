@@ -7,6 +7,7 @@ openapi_client!(
     struct_attrs = (derive(PartialEq))
 );
 
+#[cfg(not(feature = "chrono"))]
 #[test]
 fn test_struct_with_partial_eq() {
     // Test that generated structs have PartialEq
@@ -54,6 +55,40 @@ fn test_struct_with_partial_eq() {
     assert_eq!(user1, user2);
 }
 
+#[cfg(feature = "chrono")]
+#[test]
+fn test_struct_with_chrono_fields_and_partial_eq() {
+    let created_at: chrono::DateTime<chrono::Utc> = "2024-01-01T00:00:00Z".parse().unwrap();
+    let user1 = User {
+        id: 1,
+        username: "test".to_string(),
+        email: "test@example.com".to_string(),
+        status: UserStatus::Active,
+        first_name: Some("Test".to_string()),
+        last_name: Some("User".to_string()),
+        age: Some(25),
+        height: None,
+        weight: None,
+        is_active: Some(true),
+        r#type: Some("user".to_string()),
+        tags: Some(vec!["developer".to_string()]),
+        metadata: None,
+        profile: None,
+        preferences: None,
+        created_at: Some(created_at),
+        last_login: None,
+    };
+    let user2 = user1.clone();
+    let user3 = User {
+        created_at: Some(created_at + chrono::Duration::seconds(1)),
+        ..user1.clone()
+    };
+
+    // Date-time fields take part in the derived PartialEq
+    assert_eq!(user1, user2);
+    assert_ne!(user1, user3);
+}
+
 #[test]
 fn test_enum_with_partial_eq() {
     let status1 = UserStatus::Active;
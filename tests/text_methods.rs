@@ -0,0 +1,61 @@
+#![cfg(not(feature = "ureq"))]
+
+use openapi_gen::openapi_client;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!("openapi.json", "TextApi", text_methods = true);
+
+const MALFORMED_USER: &str = r#"{"id": 42, "username": "john_doe""#;
+
+async fn user_server(response: ResponseTemplate) -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/42"))
+        .respond_with(response)
+        .mount(&server)
+        .await;
+    server
+}
+
+#[tokio::test]
+async fn test_text_method_returns_body_that_does_not_parse() {
+    let server =
+        user_server(ResponseTemplate::new(200).set_body_raw(MALFORMED_USER, "application/json"))
+            .await;
+    let client = TextApi::for_testing(server.uri());
+
+    let parsed = client.get_user_by_id(42).await;
+    let text = client.get_user_by_id_text(42).await.unwrap();
+
+    assert!(parsed.is_err());
+    assert_eq!(text, MALFORMED_USER);
+}
+
+#[tokio::test]
+async fn test_text_method_returns_error_responses_as_errors() {
+    let server = user_server(ResponseTemplate::new(404).set_body_string("no such user")).await;
+    let client = TextApi::for_testing(server.uri());
+
+    let result = client.get_user_by_id_text(42).await;
+
+    assert!(matches!(
+        result,
+        Err(ApiError::Api { status: 404, message }) if message == "no such user"
+    ));
+}
+
+#[tokio::test]
+async fn test_operations_without_content_have_text_methods() {
+    let server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path("/users/42"))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&server)
+        .await;
+    let client = TextApi::for_testing(server.uri());
+
+    let text = client.delete_user_text(42).await.unwrap();
+
+    assert_eq!(text, "");
+}
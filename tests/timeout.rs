@@ -0,0 +1,76 @@
+#![cfg(not(feature = "ureq"))]
+
+use std::time::Duration;
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!("openapi.json", "TimeoutApi");
+
+async fn slow_server() -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/42"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_delay(Duration::from_millis(500))
+                .set_body_json(json!({
+                    "id": 42,
+                    "username": "john_doe",
+                    "email": "john@example.com",
+                    "status": "active"
+                })),
+        )
+        .mount(&server)
+        .await;
+    server
+}
+
+#[tokio::test]
+async fn test_request_exceeding_timeout_fails() {
+    let server = slow_server().await;
+    let client = TimeoutApi::for_testing(server.uri()).with_timeout(Duration::from_millis(50));
+
+    let error = client.get_user_by_id(42).await.unwrap_err();
+
+    assert!(matches!(error, ApiError::Http(e) if e.is_timeout()));
+}
+
+#[tokio::test]
+async fn test_requests_without_timeout_wait_for_response() {
+    let server = slow_server().await;
+    let client = TimeoutApi::for_testing(server.uri());
+
+    let user = client.get_user_by_id(42).await.unwrap();
+
+    assert_eq!(user.id, 42);
+}
+
+#[test]
+fn test_all_methods_are_available_with_timeout() {
+    let client = TimeoutApi::new("https://api.example.com").with_timeout(Duration::from_secs(5));
+
+    let _user = client.get_user_by_id(1);
+    let _deleted = client.delete_user(1);
+    let _users = client.list_users(None, None, None);
+}
+
+#[cfg(feature = "blocking")]
+#[tokio::test]
+async fn test_blocking_request_exceeding_timeout_fails() {
+    let server = slow_server().await;
+    let uri = server.uri();
+
+    // The blocking client runs its own runtime, so it must live outside the async context
+    let result = tokio::task::spawn_blocking(move || {
+        TimeoutApi::with_client(uri, reqwest::blocking::Client::new())
+            .with_timeout(Duration::from_millis(50))
+            .get_user_by_id(42)
+    })
+    .await
+    .unwrap();
+
+    assert!(matches!(result, Err(ApiError::Http(e)) if e.is_timeout()));
+}
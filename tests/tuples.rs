@@ -0,0 +1,53 @@
+use openapi_gen::openapi_client;
+use serde_json::json;
+
+openapi_client!("tests/fixtures/tuples.yaml", "TuplesApi");
+
+#[test]
+fn test_prefix_items_are_tuples() {
+    let coordinate: Coordinate = (52.37, 4.89);
+    let label: Label = ("Amsterdam".to_string(), coordinate);
+
+    assert_eq!(
+        serde_json::to_value(label).unwrap(),
+        json!(["Amsterdam", [52.37, 4.89]])
+    );
+}
+
+#[test]
+fn test_tuples_deserialize_from_arrays() {
+    let route: Route = serde_json::from_value(json!({
+        "points": [[52.37, 4.89], [51.92, 4.48]],
+        "labels": [["Rotterdam", [51.92, 4.48]]],
+        "extra": ["anything", 1, 2],
+        "prefixItems": "kept as a regular property"
+    }))
+    .unwrap();
+
+    assert_eq!(route.points[1], (51.92, 4.48));
+    assert_eq!(route.labels[0].0, "Rotterdam");
+    assert_eq!(
+        route.prefix_items.as_deref(),
+        Some("kept as a regular property")
+    );
+}
+
+#[test]
+fn test_additional_items_fall_back_to_values() {
+    let extra: Vec<serde_json::Value> = vec![json!("anything"), json!(1)];
+    let route = Route {
+        points: Vec::new(),
+        labels: Vec::new(),
+        extra: Some(extra),
+        prefix_items: None,
+    };
+
+    assert_eq!(route.extra.unwrap().len(), 2);
+}
+
+#[test]
+fn test_wrong_length_is_rejected() {
+    let result = serde_json::from_value::<Coordinate>(json!([52.37]));
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,66 @@
+use openapi_gen::openapi_client;
+
+openapi_client!("tests/fixtures/request_bodies.json", "WidgetApi");
+
+#[test]
+fn test_referenced_body_uses_component_type() {
+    let client = WidgetApi::new("https://api.example.com");
+
+    let body = Widget {
+        id: None,
+        name: "sprocket".to_string(),
+    };
+    let _result = client.create_widget(body);
+}
+
+#[test]
+fn test_inline_object_body_uses_generated_struct() {
+    let client = WidgetApi::new("https://api.example.com");
+
+    let body = UpdateWidgetSettingsRequestBody {
+        color: "blue".to_string(),
+        size_in_cm: Some(12),
+    };
+    let _result = client.update_widget_settings(1, body);
+}
+
+#[test]
+fn test_untyped_body_falls_back_to_value() {
+    let client = WidgetApi::new("https://api.example.com");
+
+    let body = serde_json::json!({"owner": "team-a", "tags": ["beta"]});
+    let _result = client.set_widget_metadata(1, body);
+}
+
+#[test]
+fn test_typed_body_with_param_structs() {
+    openapi_client!(
+        "tests/fixtures/request_bodies.json",
+        "WidgetParamsApi",
+        use_param_structs = true
+    );
+
+    let client = WidgetParamsApi::new("https://api.example.com");
+
+    let body = UpdateWidgetSettingsRequestBody {
+        color: "red".to_string(),
+        size_in_cm: None,
+    };
+    let _result = client.update_widget_settings(UpdateWidgetSettingsParams::new(1), body);
+}
+
+#[test]
+fn test_create_user_accepts_create_user_request() {
+    openapi_client!("openapi.json", "UserApi");
+
+    let client = UserApi::new("https://api.example.com");
+
+    let body = CreateUserRequest {
+        username: "john_doe".to_string(),
+        email: "john@example.com".to_string(),
+        first_name: None,
+        last_name: None,
+        r#type: None,
+    };
+    let _result = client.create_user(body);
+}
@@ -0,0 +1,54 @@
+#![cfg(all(feature = "unix-socket", unix))]
+
+use openapi_gen::openapi_client;
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::net::UnixListener;
+use std::path::PathBuf;
+
+openapi_client!("openapi.json", "DaemonApi");
+
+/// Serve a single user over a Unix socket, answering with the requested path
+fn spawn_socket_server(name: &str) -> PathBuf {
+    let socket_path = std::env::temp_dir().join(format!("{}-{}.sock", name, std::process::id()));
+    let _ = std::fs::remove_file(&socket_path);
+    let listener = UnixListener::bind(&socket_path).unwrap();
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 2 {
+                line.clear();
+            }
+
+            let path = request_line.split_whitespace().nth(1).unwrap_or_default();
+            let body = format!(
+                r#"{{"id":42,"username":"{}","email":"john@example.com","status":"active"}}"#,
+                path
+            );
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+    });
+
+    socket_path
+}
+
+#[tokio::test]
+async fn test_requests_are_sent_over_the_socket() {
+    let socket_path = spawn_socket_server("openapi-gen-unix-socket");
+    let client = DaemonApi::with_unix_socket(&socket_path, "http://localhost").unwrap();
+
+    let user = client.get_user_by_id(42).await.unwrap();
+
+    assert_eq!(user.id, 42);
+    assert_eq!(user.username, "/users/42");
+    std::fs::remove_file(socket_path).unwrap();
+}
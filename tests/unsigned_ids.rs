@@ -0,0 +1,43 @@
+#![cfg(not(feature = "ureq"))]
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!("tests/fixtures/unsigned_ids.json", "UnsignedIdsApi");
+
+#[test]
+fn test_non_negative_int64_fields_are_u64() {
+    let object = StoredObject {
+        id: u64::MAX,
+        offset: -1i64,
+    };
+
+    assert_eq!(
+        serde_json::to_value(object).unwrap(),
+        json!({ "id": 18446744073709551615u64, "offset": -1 })
+    );
+}
+
+#[tokio::test]
+async fn test_unsigned_ids_round_trip() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/objects/18446744073709551615"))
+        .and(query_param("after", "9223372036854775808"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 18446744073709551615u64,
+            "offset": 0
+        })))
+        .mount(&server)
+        .await;
+
+    let client = UnsignedIdsApi::for_testing(server.uri());
+    let object = client
+        .get_object(u64::MAX, Some(i64::MAX as u64 + 1))
+        .await
+        .unwrap();
+
+    assert_eq!(object.id, u64::MAX);
+}
@@ -0,0 +1,145 @@
+#![cfg(feature = "ureq")]
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+use wiremock::matchers::{body_partial_json, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+openapi_client!("openapi.json", "UreqApi", auth = "bearer");
+
+fn user_json(id: i64) -> serde_json::Value {
+    json!({
+        "id": id,
+        "username": "john_doe",
+        "email": "john@example.com",
+        "status": "active"
+    })
+}
+
+/// Run a blocking call outside the async context of the mock server
+async fn blocking<T: Send + 'static>(call: impl FnOnce() -> T + Send + 'static) -> T {
+    tokio::task::spawn_blocking(call).await.unwrap()
+}
+
+#[test]
+fn test_client_defaults_to_ureq_agent() {
+    let client = UreqApi::new("https://api.example.com");
+    let _: UreqApi<ureq::Agent> = client;
+
+    let custom = UreqApi::with_client("https://api.example.com", ureq::AgentBuilder::new().build())
+        .with_timeout(std::time::Duration::from_secs(5));
+    let _: ureq::Agent = custom.into_inner();
+}
+
+#[tokio::test]
+async fn test_query_parameters_and_auth() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users"))
+        .and(query_param("limit", "10"))
+        .and(query_param("type", "admin"))
+        .and(wiremock::matchers::header("authorization", "Bearer secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "users": [user_json(1)],
+            "total": 1,
+            "page": 1
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = UreqApi::for_testing(server.uri()).with_bearer_token("secret");
+
+    let users = blocking(move || client.list_users(Some(10), None, Some("admin")))
+        .await
+        .unwrap();
+
+    assert_eq!(users.total, 1);
+    assert_eq!(users.users[0].id, 1);
+}
+
+#[tokio::test]
+async fn test_json_body_is_sent() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/users"))
+        .and(body_partial_json(json!({
+            "username": "john_doe",
+            "email": "john@example.com"
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(user_json(7)))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = UreqApi::for_testing(server.uri());
+
+    let user = blocking(move || {
+        client.create_user(CreateUserRequest {
+            username: "john_doe".to_string(),
+            email: "john@example.com".to_string(),
+            first_name: None,
+            last_name: None,
+            r#type: None,
+        })
+    })
+    .await
+    .unwrap();
+
+    assert_eq!(user.id, 7);
+}
+
+#[tokio::test]
+async fn test_error_status_is_an_api_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path("/users/7"))
+        .respond_with(ResponseTemplate::new(404).set_body_string("User not found"))
+        .mount(&server)
+        .await;
+    let client = UreqApi::for_testing(server.uri());
+
+    let result = blocking(move || client.delete_user(7)).await;
+
+    match result {
+        Err(ApiError::Api { status, message }) => {
+            assert_eq!(status, 404);
+            assert_eq!(message, "User not found");
+        }
+        other => panic!("expected an API error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_connection_failure_is_an_http_error() {
+    let client = UreqApi::for_testing("http://127.0.0.1:1");
+
+    let result = client.get_user_by_id(1);
+
+    assert!(matches!(result, Err(ApiError::Http(_))));
+}
+
+mod basic_auth {
+    use super::*;
+
+    openapi_client!("openapi.json", "UreqBasicApi", auth = "basic");
+
+    #[tokio::test]
+    async fn test_basic_auth_is_sent() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/users/42"))
+            .and(wiremock::matchers::header(
+                "authorization",
+                "Basic QWxhZGRpbjpvcGVuIHNlc2FtZQ==",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(user_json(42)))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client =
+            UreqBasicApi::for_testing(server.uri()).with_basic_auth("Aladdin", "open sesame");
+
+        let user = blocking(move || client.get_user_by_id(42)).await.unwrap();
+
+        assert_eq!(user.id, 42);
+    }
+}
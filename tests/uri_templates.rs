@@ -0,0 +1,62 @@
+#![cfg(feature = "uri-template")]
+
+use openapi_gen::openapi_client;
+use serde_json::json;
+
+openapi_client!("tests/fixtures/uri_templates.json", "HypermediaApi");
+
+fn vars(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_uri_template_fields_are_typed() {
+    let order: Order = serde_json::from_value(json!({
+        "id": "o-1",
+        "itemsLink": "/orders/{orderId}/items{?page}",
+        "href": "/orders/o-1"
+    }))
+    .unwrap();
+
+    let template: UriTemplate = order.items_link;
+    let link: Option<String> = order.href;
+    assert_eq!(template.to_string(), "/orders/{orderId}/items{?page}");
+    assert_eq!(link.as_deref(), Some("/orders/o-1"));
+}
+
+#[test]
+fn test_expand_substitutes_variables() {
+    let template = UriTemplate::from("/orders/{orderId}/items/{itemId}".to_string());
+
+    assert_eq!(
+        template.expand(&vars(&[("orderId", "o-1"), ("itemId", "42")])),
+        "/orders/o-1/items/42"
+    );
+}
+
+#[test]
+fn test_expand_encodes_values_and_skips_undefined_variables() {
+    let template = UriTemplate("/search/{term}/{missing}/{x,y}".to_string());
+
+    assert_eq!(
+        template.expand(&vars(&[("term", "red shoes/50%"), ("x", "1"), ("y", "2")])),
+        "/search/red%20shoes%2F50%25//1,2"
+    );
+}
+
+#[test]
+fn test_uri_template_serializes_as_string() {
+    let order = Order {
+        id: "o-1".to_string(),
+        items_link: UriTemplate("/orders/o-1/items".to_string()),
+        href: None,
+    };
+
+    assert_eq!(
+        serde_json::to_value(order).unwrap()["itemsLink"],
+        json!("/orders/o-1/items")
+    );
+}
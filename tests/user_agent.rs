@@ -0,0 +1,53 @@
+#![cfg(not(feature = "ureq"))]
+
+use openapi_gen::openapi_client;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+
+openapi_client!("tests/fixtures/retry.json", "ItemsApi");
+
+/// Serve a single request, echoing its `User-Agent` header as the body
+fn spawn_echo_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let mut user_agent = String::new();
+        let mut line = String::new();
+        while reader.read_line(&mut line).unwrap() > 2 {
+            if let Some((name, value)) = line.split_once(':')
+                && name.eq_ignore_ascii_case("user-agent")
+            {
+                user_agent = value.trim().to_string();
+            }
+            line.clear();
+        }
+
+        let response = format!(
+            "HTTP/1.1 200 OK\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+            user_agent.len(),
+            user_agent
+        );
+        stream.write_all(response.as_bytes()).unwrap();
+    });
+
+    url
+}
+
+#[tokio::test]
+async fn test_with_user_agent_sends_header() {
+    let client = ItemsApi::with_user_agent(spawn_echo_server(), "my-app/1.0").unwrap();
+
+    let user_agent = client.list_items().await.unwrap();
+
+    assert_eq!(user_agent, "my-app/1.0");
+}
+
+#[test]
+fn test_invalid_user_agent_is_an_error() {
+    let result = ItemsApi::with_user_agent("https://api.example.com", "bad\nagent");
+
+    assert!(matches!(result, Err(ApiError::Http(_))));
+}
@@ -0,0 +1,57 @@
+use openapi_gen::openapi_client;
+
+openapi_client!("tests/fixtures/uuids.json", "OrdersApi");
+
+#[cfg(feature = "uuid")]
+#[test]
+fn test_uuid_fields_use_uuid_type() {
+    use uuid::Uuid;
+
+    let order: Order = serde_json::from_value(serde_json::json!({
+        "id": "67e55044-10b1-426f-9247-bb680e5fe0c8"
+    }))
+    .unwrap();
+
+    let id: Uuid = order.id;
+    assert_eq!(id.to_string(), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+    assert_eq!(
+        serde_json::to_value(&order).unwrap()["id"],
+        "67e55044-10b1-426f-9247-bb680e5fe0c8"
+    );
+    assert!(serde_json::from_value::<Order>(serde_json::json!({ "id": "not-a-uuid" })).is_err());
+}
+
+#[cfg(feature = "uuid")]
+#[tokio::test]
+async fn test_uuid_path_parameter_is_typed() {
+    use uuid::Uuid;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let id = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/orders/67e55044-10b1-426f-9247-bb680e5fe0c8"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({ "id": "67e55044-10b1-426f-9247-bb680e5fe0c8" })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = OrdersApi::new(server.uri());
+
+    let order = client.get_order(id).await.unwrap();
+
+    let order_id: Uuid = order.id;
+    assert_eq!(order_id, id);
+}
+
+#[cfg(not(feature = "uuid"))]
+#[test]
+fn test_uuid_falls_back_to_strings() {
+    let client = OrdersApi::new("https://api.example.com");
+
+    let _future = client.get_order("67e55044-10b1-426f-9247-bb680e5fe0c8");
+    let _: String = Order { id: String::new() }.id;
+}
@@ -0,0 +1,131 @@
+use openapi_gen::openapi_client;
+
+openapi_client!(
+    "tests/fixtures/validation.yaml",
+    "SignupApi",
+    validate = true
+);
+
+fn signup() -> Signup {
+    Signup {
+        username: "ada_l".to_string(),
+        age: 36,
+        score: Some(0.5),
+        address: Some(Address {
+            zip: "12345".to_string(),
+        }),
+        tags: Some(vec![Tag {
+            name: "math".to_string(),
+        }]),
+    }
+}
+
+fn violation(signup: &Signup) -> (String, String) {
+    let error = signup.validate().unwrap_err();
+    (error.field, error.message)
+}
+
+#[test]
+fn test_valid_struct_passes() {
+    assert_eq!(signup().validate(), Ok(()));
+    assert_eq!(
+        Signup {
+            score: None,
+            address: None,
+            tags: None,
+            ..signup()
+        }
+        .validate(),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_string_constraints() {
+    let short = Signup {
+        username: "ad".to_string(),
+        ..signup()
+    };
+    assert_eq!(
+        violation(&short),
+        (
+            "username".to_string(),
+            "must be at least 3 characters long".to_string()
+        )
+    );
+
+    let long = Signup {
+        username: "a".repeat(17),
+        ..signup()
+    };
+    assert_eq!(
+        violation(&long).1,
+        "must be at most 16 characters long".to_string()
+    );
+
+    let invalid = Signup {
+        username: "Ada Lovelace".to_string(),
+        ..signup()
+    };
+    assert_eq!(
+        invalid.validate().unwrap_err().to_string(),
+        "username: must match the pattern `^[a-z0-9_]+$`"
+    );
+}
+
+#[test]
+fn test_out_of_range_numbers_fail() {
+    let young = Signup {
+        age: 12,
+        ..signup()
+    };
+    assert_eq!(
+        violation(&young),
+        ("age".to_string(), "must be at least 13".to_string())
+    );
+
+    let old = Signup {
+        age: 131,
+        ..signup()
+    };
+    assert_eq!(violation(&old).1, "must be at most 130");
+
+    let zero = Signup {
+        score: Some(0.0),
+        ..signup()
+    };
+    assert_eq!(
+        violation(&zero),
+        ("score".to_string(), "must be greater than 0".to_string())
+    );
+}
+
+#[test]
+fn test_nested_structs_are_validated() {
+    let address = Signup {
+        address: Some(Address {
+            zip: "1234a".to_string(),
+        }),
+        ..signup()
+    };
+    assert_eq!(violation(&address).0, "address.zip");
+
+    let tags = Signup {
+        tags: Some(vec![
+            Tag {
+                name: "math".to_string(),
+            },
+            Tag {
+                name: "computing-history".to_string(),
+            },
+        ]),
+        ..signup()
+    };
+    assert_eq!(
+        violation(&tags),
+        (
+            "tags[1].name".to_string(),
+            "must be at most 10 characters long".to_string()
+        )
+    );
+}
@@ -0,0 +1,47 @@
+use openapi_gen::openapi_client;
+
+openapi_client!("openapi.json", "ConversionApi", value_conversions = true);
+
+#[test]
+fn test_struct_try_from_value() {
+    let value = serde_json::json!({
+        "field": "email",
+        "message": "must be a valid email address"
+    });
+
+    let error = FieldError::try_from(value).unwrap();
+    assert_eq!(error.field, "email");
+    assert_eq!(error.code, None);
+}
+
+#[test]
+fn test_enum_try_from_value() {
+    let status: UserStatus = serde_json::json!("suspended").try_into().unwrap();
+    assert!(matches!(status, UserStatus::Suspended));
+}
+
+#[test]
+fn test_invalid_value_is_rejected() {
+    let result = FieldError::try_from(serde_json::json!({"field": 42}));
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_struct_into_value_by_reference() {
+    let error = FieldError::try_from(serde_json::json!({
+        "field": "email",
+        "message": "must be a valid email address"
+    }))
+    .unwrap();
+
+    let value = serde_json::Value::from(&error);
+
+    assert_eq!(value["field"], "email");
+    assert_eq!(value, error.as_json_value());
+}
+
+#[test]
+fn test_enum_as_json_value() {
+    let status: UserStatus = serde_json::json!("suspended").try_into().unwrap();
+    assert_eq!(status.as_json_value(), serde_json::json!("suspended"));
+}